@@ -1,24 +1,167 @@
 use std::env;
+use std::fmt::Display;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 fn main() {
     // Put the linker script somewhere the linker can find it
     let out = &PathBuf::from(env::var_os("OUT_DIR").unwrap());
-    File::create(out.join("rom_functions.x"))
-        .unwrap()
-        .write_all(include_bytes!("rom_functions.x"))
-        .unwrap();
-
     File::create(out.join("esp32c3-wifi-link.x"))
         .unwrap()
         .write_all(include_bytes!("esp32c3-wifi-link.x"))
         .unwrap();
 
+    // The memory region the large driver buffers (task stacks, tx buffer) are placed in.
+    // Can be any region known to the linker - including ones defined in an additional
+    // user provided linker script.
+    let buffers_region = config_string("ESP32C3_WIFI_BUFFERS_REGION", "DRAM", |v| {
+        !v.is_empty() && v.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+
+    // the low-power preset only changes the defaults - explicitly set values still win
+    let preset = config_string("ESP32C3_WIFI_PRESET", "default", |v| {
+        v == "default" || v == "low-power"
+    });
+    let low_power = preset == "low-power";
+
+    let static_rx_buf_num = config_value(
+        "ESP32C3_WIFI_STATIC_RX_BUF_NUM",
+        if low_power { 4 } else { 10 },
+        2,
+        25,
+    );
+    let dynamic_rx_buf_num = config_value(
+        "ESP32C3_WIFI_DYNAMIC_RX_BUF_NUM",
+        if low_power { 8 } else { 32 },
+        0,
+        1024,
+    );
+    let dynamic_tx_buf_num = config_value(
+        "ESP32C3_WIFI_DYNAMIC_TX_BUF_NUM",
+        if low_power { 8 } else { 32 },
+        1,
+        64,
+    );
+    let rx_queue_size = config_value("ESP32C3_WIFI_RX_QUEUE_SIZE", 3, 2, 64);
+    let tick_period_us = config_value(
+        "ESP32C3_WIFI_TICK_PERIOD_US",
+        if low_power { 10_000 } else { 1_250 },
+        500,
+        100_000,
+    );
+    // the scheduler's tasks - the driver task runs the blob's WiFi task and queued work,
+    // the timer task runs the timer callbacks and sends queued frames
+    let driver_task_stack_size = config_value(
+        "ESP32C3_WIFI_DRIVER_TASK_STACK_SIZE",
+        16 * 1024,
+        4 * 1024,
+        64 * 1024,
+    );
+    let timer_task_stack_size = config_value(
+        "ESP32C3_WIFI_TIMER_TASK_STACK_SIZE",
+        16 * 1024,
+        4 * 1024,
+        64 * 1024,
+    );
+    for (name, size) in [
+        (
+            "ESP32C3_WIFI_DRIVER_TASK_STACK_SIZE",
+            driver_task_stack_size,
+        ),
+        ("ESP32C3_WIFI_TIMER_TASK_STACK_SIZE", timer_task_stack_size),
+    ] {
+        if size % 16 != 0 {
+            panic!("{} has to be a multiple of 16 (got {})", name, size);
+        }
+    }
+    let max_connections = config_value("ESP32C3_WIFI_MAX_CONNECTIONS", 4, 1, 10);
+    let heap_size = config_value("ESP32C3_WIFI_HEAP_SIZE", 64 * 1024, 8 * 1024, 256 * 1024);
+    let country = config_string("ESP32C3_WIFI_COUNTRY", "CN", |v| {
+        v.len() == 2 && v.chars().all(|c| c.is_ascii_uppercase())
+    });
+    let country_policy = config_string("ESP32C3_WIFI_COUNTRY_POLICY", "manual", |v| {
+        v == "manual" || v == "auto"
+    });
+
+    writeln!(
+        File::create(out.join("wifi_config.x")).unwrap(),
+        "REGION_ALIAS(\"REGION_WIFI_BUFFERS\", {});\n\
+         _heap_size = {};",
+        buffers_region,
+        heap_size
+    )
+    .unwrap();
+
+    let country = country.as_bytes();
+    writeln!(
+        File::create(out.join("config.rs")).unwrap(),
+        "pub const STATIC_RX_BUF_NUM: usize = {};\n\
+         pub const DYNAMIC_RX_BUF_NUM: usize = {};\n\
+         pub const DYNAMIC_TX_BUF_NUM: usize = {};\n\
+         pub const RX_QUEUE_SIZE: usize = {};\n\
+         pub const MAX_CONNECTIONS: usize = {};\n\
+         pub const HEAP_SIZE: usize = {};\n\
+         pub const COUNTRY: [u8; 2] = [{}, {}];\n\
+         pub const COUNTRY_POLICY_AUTO: bool = {};\n\
+         pub const TICK_PERIOD_US: u32 = {};\n\
+         pub const LOW_POWER_PRESET: bool = {};\n\
+         pub const DRIVER_TASK_STACK_SIZE: usize = {};\n\
+         pub const TIMER_TASK_STACK_SIZE: usize = {};",
+        static_rx_buf_num,
+        dynamic_rx_buf_num,
+        dynamic_tx_buf_num,
+        rx_queue_size,
+        max_connections,
+        heap_size,
+        country[0],
+        country[1],
+        country_policy == "auto",
+        tick_period_us,
+        low_power,
+        driver_task_stack_size,
+        timer_task_stack_size,
+    )
+    .unwrap();
+
     println!("cargo:rustc-link-search={}", out.display());
 
     // Only re-run the build script when memory.x is changed,
     // instead of when any part of the source code changes.
     println!("cargo:rerun-if-changed=memory.x");
 }
+
+fn config_string(name: &str, default: &str, valid: impl Fn(&str) -> bool) -> String {
+    println!("cargo:rerun-if-env-changed={}", name);
+
+    let value = env::var(name).unwrap_or_else(|_| String::from(default));
+    if !valid(&value) {
+        panic!("invalid value '{}' for {}", value, name);
+    }
+
+    value
+}
+
+fn config_value<T>(name: &str, default: T, min: T, max: T) -> T
+where
+    T: FromStr + PartialOrd + Display,
+{
+    println!("cargo:rerun-if-env-changed={}", name);
+
+    let value = match env::var(name) {
+        Ok(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("{} is not a valid number: '{}'", name, value)),
+        Err(_) => default,
+    };
+
+    if value < min || value > max {
+        panic!(
+            "{} must be between {} and {}, got {}",
+            name, min, max, value
+        );
+    }
+
+    value
+}
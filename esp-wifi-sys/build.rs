@@ -0,0 +1,62 @@
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+include!("src/header_md5.rs");
+
+/// The driver blobs in `libs` - all of them get linked
+const LIBS: &[&str] = &[
+    "btbb",
+    "btdm_app",
+    "coexist",
+    "core",
+    "espnow",
+    "mesh",
+    "net80211",
+    "phy",
+    "pp",
+    "smartconfig",
+    "wapi",
+    "wpa_supplicant",
+];
+
+fn main() {
+    // Put the linker script somewhere the linker can find it
+    let out = &PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    File::create(out.join("rom_functions.x"))
+        .unwrap()
+        .write_all(include_bytes!("rom_functions.x"))
+        .unwrap();
+    println!("cargo:rustc-link-search={}", out.display());
+    println!("cargo:rerun-if-changed=rom_functions.x");
+
+    // host builds (e.g. with the `mock` feature) only use the bindings
+    if env::var("TARGET").unwrap().starts_with("riscv32") {
+        let libs = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("libs");
+        println!("cargo:rustc-link-search={}", libs.display());
+        for lib in LIBS {
+            println!("cargo:rustc-link-lib={}", lib);
+            println!("cargo:rerun-if-changed=libs/lib{}.a", lib);
+        }
+    }
+
+    check_header_md5("headers/esp_private/wifi_os_adapter.h", WIFI_OS_ADAPTER_MD5);
+    check_header_md5("headers/esp_wifi_crypto_types.h", WIFI_CRYPTO_TYPES_MD5);
+    check_header_md5("headers/esp_wifi_types.h", WIFI_TYPES_MD5);
+    check_header_md5("headers/esp_wifi.h", ESP_WIFI_MD5);
+}
+
+// the bindings and the values checked against the blob must match the headers
+fn check_header_md5(path: &str, expected: &str) {
+    println!("cargo:rerun-if-changed={}", path);
+    println!("cargo:rerun-if-changed=src/header_md5.rs");
+
+    let digest = format!("{:x}", md5::compute(std::fs::read(path).unwrap()));
+    if digest != expected.trim_end_matches('\0') {
+        panic!(
+            "{} changed (md5 {}, expected {}) - regenerate the bindings and update src/header_md5.rs",
+            path, digest, expected.trim_end_matches('\0')
+        );
+    }
+}
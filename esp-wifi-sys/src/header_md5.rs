@@ -0,0 +1,9 @@
+// MD5 of the driver headers `include.rs` was generated from.
+// The WiFi blob embeds the same values - they are compared at init to detect a blob which
+// doesn't match the bindings. When updating the headers regenerate the bindings and update
+// these values (the build script refuses to build otherwise).
+
+pub const WIFI_OS_ADAPTER_MD5: &str = "3bc97bed06dbad60503740aaa368388b\0";
+pub const WIFI_CRYPTO_TYPES_MD5: &str = "93def8e211a48bba57efdd44a210e2b7\0";
+pub const WIFI_TYPES_MD5: &str = "fce3f2ca0e82f9c6b5c6fe04b9fea5e9\0";
+pub const ESP_WIFI_MD5: &str = "4c0dfdda4a2b1c1cd559e2f9c44930d9\0";
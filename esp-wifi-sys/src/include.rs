@@ -1007,26 +1007,26 @@ pub const ESP_TASK_BT_CONTROLLER_PRIO: u32 = 0;
 pub const PHY_INIT_MAGIC: &[u8; 8usize] = b"PHYINIT\0";
 pub const PHY_TX_POWER_OFFSET: u32 = 44;
 pub const PHY_TX_POWER_NUM: u32 = 5;
-pub type __int8_t = crate::binary::c_types::c_schar;
-pub type __uint8_t = crate::binary::c_types::c_uchar;
-pub type __int16_t = crate::binary::c_types::c_short;
-pub type __uint16_t = crate::binary::c_types::c_ushort;
-pub type __int32_t = crate::binary::c_types::c_int;
-pub type __uint32_t = crate::binary::c_types::c_uint;
-pub type __int64_t = crate::binary::c_types::c_longlong;
-pub type __uint64_t = crate::binary::c_types::c_ulonglong;
-pub type __int_least8_t = crate::binary::c_types::c_schar;
-pub type __uint_least8_t = crate::binary::c_types::c_uchar;
-pub type __int_least16_t = crate::binary::c_types::c_short;
-pub type __uint_least16_t = crate::binary::c_types::c_ushort;
-pub type __int_least32_t = crate::binary::c_types::c_int;
-pub type __uint_least32_t = crate::binary::c_types::c_uint;
-pub type __int_least64_t = crate::binary::c_types::c_longlong;
-pub type __uint_least64_t = crate::binary::c_types::c_ulonglong;
-pub type __intmax_t = crate::binary::c_types::c_longlong;
-pub type __uintmax_t = crate::binary::c_types::c_ulonglong;
-pub type __intptr_t = crate::binary::c_types::c_longlong;
-pub type __uintptr_t = crate::binary::c_types::c_ulonglong;
+pub type __int8_t = crate::c_types::c_schar;
+pub type __uint8_t = crate::c_types::c_uchar;
+pub type __int16_t = crate::c_types::c_short;
+pub type __uint16_t = crate::c_types::c_ushort;
+pub type __int32_t = crate::c_types::c_int;
+pub type __uint32_t = crate::c_types::c_uint;
+pub type __int64_t = crate::c_types::c_longlong;
+pub type __uint64_t = crate::c_types::c_ulonglong;
+pub type __int_least8_t = crate::c_types::c_schar;
+pub type __uint_least8_t = crate::c_types::c_uchar;
+pub type __int_least16_t = crate::c_types::c_short;
+pub type __uint_least16_t = crate::c_types::c_ushort;
+pub type __int_least32_t = crate::c_types::c_int;
+pub type __uint_least32_t = crate::c_types::c_uint;
+pub type __int_least64_t = crate::c_types::c_longlong;
+pub type __uint_least64_t = crate::c_types::c_ulonglong;
+pub type __intmax_t = crate::c_types::c_longlong;
+pub type __uintmax_t = crate::c_types::c_ulonglong;
+pub type __intptr_t = crate::c_types::c_longlong;
+pub type __uintptr_t = crate::c_types::c_ulonglong;
 pub type intmax_t = __intmax_t;
 pub type uintmax_t = __uintmax_t;
 pub type int_least8_t = __int_least8_t;
@@ -1037,27 +1037,27 @@ pub type int_least32_t = __int_least32_t;
 pub type uint_least32_t = __uint_least32_t;
 pub type int_least64_t = __int_least64_t;
 pub type uint_least64_t = __uint_least64_t;
-pub type int_fast8_t = crate::binary::c_types::c_schar;
-pub type uint_fast8_t = crate::binary::c_types::c_uchar;
-pub type int_fast16_t = crate::binary::c_types::c_short;
-pub type uint_fast16_t = crate::binary::c_types::c_ushort;
-pub type int_fast32_t = crate::binary::c_types::c_int;
-pub type uint_fast32_t = crate::binary::c_types::c_uint;
-pub type int_fast64_t = crate::binary::c_types::c_longlong;
-pub type uint_fast64_t = crate::binary::c_types::c_ulonglong;
+pub type int_fast8_t = crate::c_types::c_schar;
+pub type uint_fast8_t = crate::c_types::c_uchar;
+pub type int_fast16_t = crate::c_types::c_short;
+pub type uint_fast16_t = crate::c_types::c_ushort;
+pub type int_fast32_t = crate::c_types::c_int;
+pub type uint_fast32_t = crate::c_types::c_uint;
+pub type int_fast64_t = crate::c_types::c_longlong;
+pub type uint_fast64_t = crate::c_types::c_ulonglong;
 pub const esp_log_level_e_ESP_LOG_NONE: esp_log_level_e = 0;
 pub const esp_log_level_e_ESP_LOG_ERROR: esp_log_level_e = 1;
 pub const esp_log_level_e_ESP_LOG_WARN: esp_log_level_e = 2;
 pub const esp_log_level_e_ESP_LOG_INFO: esp_log_level_e = 3;
 pub const esp_log_level_e_ESP_LOG_DEBUG: esp_log_level_e = 4;
 pub const esp_log_level_e_ESP_LOG_VERBOSE: esp_log_level_e = 5;
-pub type esp_log_level_e = crate::binary::c_types::c_uint;
+pub type esp_log_level_e = crate::c_types::c_uint;
 pub type TickType_t = u32;
 pub type UBaseType_t = u32;
 pub type BaseType_t = i32;
-pub type QueueHandle_t = *mut crate::binary::c_types::c_void;
-pub type esp_netif_t = *mut crate::binary::c_types::c_void;
-pub type esp_netif_inherent_config_t = *mut crate::binary::c_types::c_void;
+pub type QueueHandle_t = *mut crate::c_types::c_void;
+pub type esp_netif_t = *mut crate::c_types::c_void;
+pub type esp_netif_inherent_config_t = *mut crate::c_types::c_void;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ets_timer {
@@ -1065,67 +1065,67 @@ pub struct ets_timer {
     pub expire: u32,
     pub period: u32,
     pub func:
-        ::core::option::Option<unsafe extern "C" fn(priv_: *mut crate::binary::c_types::c_void)>,
-    pub priv_: *mut crate::binary::c_types::c_void,
+        ::core::option::Option<unsafe extern "C" fn(priv_: *mut crate::c_types::c_void)>,
+    pub priv_: *mut crate::c_types::c_void,
 }
-pub type size_t = crate::binary::c_types::c_ulonglong;
-pub type wchar_t = crate::binary::c_types::c_ushort;
+pub type size_t = crate::c_types::c_ulonglong;
+pub type wchar_t = crate::c_types::c_ushort;
 #[repr(C)]
 #[repr(align(16))]
 #[derive(Copy, Clone)]
 pub struct max_align_t {
-    pub __clang_max_align_nonce1: crate::binary::c_types::c_longlong,
+    pub __clang_max_align_nonce1: crate::c_types::c_longlong,
     pub __bindgen_padding_0: u64,
     pub __clang_max_align_nonce2: u128,
 }
 pub type va_list = __builtin_va_list;
 pub type __gnuc_va_list = __builtin_va_list;
-pub type wint_t = crate::binary::c_types::c_ushort;
-pub type __blkcnt_t = crate::binary::c_types::c_long;
-pub type __blksize_t = crate::binary::c_types::c_long;
+pub type wint_t = crate::c_types::c_ushort;
+pub type __blkcnt_t = crate::c_types::c_long;
+pub type __blksize_t = crate::c_types::c_long;
 pub type __fsblkcnt_t = __uint64_t;
 pub type __fsfilcnt_t = __uint32_t;
-pub type _off_t = crate::binary::c_types::c_long;
-pub type __pid_t = crate::binary::c_types::c_int;
-pub type __dev_t = crate::binary::c_types::c_short;
-pub type __uid_t = crate::binary::c_types::c_ushort;
-pub type __gid_t = crate::binary::c_types::c_ushort;
+pub type _off_t = crate::c_types::c_long;
+pub type __pid_t = crate::c_types::c_int;
+pub type __dev_t = crate::c_types::c_short;
+pub type __uid_t = crate::c_types::c_ushort;
+pub type __gid_t = crate::c_types::c_ushort;
 pub type __id_t = __uint32_t;
-pub type __ino_t = crate::binary::c_types::c_ushort;
+pub type __ino_t = crate::c_types::c_ushort;
 pub type __mode_t = __uint32_t;
-pub type _off64_t = crate::binary::c_types::c_longlong;
+pub type _off64_t = crate::c_types::c_longlong;
 pub type __off_t = _off_t;
 pub type __loff_t = _off64_t;
-pub type __key_t = crate::binary::c_types::c_long;
-pub type _fpos_t = crate::binary::c_types::c_long;
-pub type __size_t = crate::binary::c_types::c_ulonglong;
-pub type _ssize_t = crate::binary::c_types::c_longlong;
+pub type __key_t = crate::c_types::c_long;
+pub type _fpos_t = crate::c_types::c_long;
+pub type __size_t = crate::c_types::c_ulonglong;
+pub type _ssize_t = crate::c_types::c_longlong;
 pub type __ssize_t = _ssize_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _mbstate_t {
-    pub __count: crate::binary::c_types::c_int,
+    pub __count: crate::c_types::c_int,
     pub __value: _mbstate_t__bindgen_ty_1,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union _mbstate_t__bindgen_ty_1 {
     pub __wch: wint_t,
-    pub __wchb: [crate::binary::c_types::c_uchar; 4usize],
+    pub __wchb: [crate::c_types::c_uchar; 4usize],
 }
-pub type _iconv_t = *mut crate::binary::c_types::c_void;
-pub type __clock_t = crate::binary::c_types::c_ulong;
-pub type __time_t = crate::binary::c_types::c_long;
-pub type __clockid_t = crate::binary::c_types::c_ulong;
-pub type __timer_t = crate::binary::c_types::c_ulong;
+pub type _iconv_t = *mut crate::c_types::c_void;
+pub type __clock_t = crate::c_types::c_ulong;
+pub type __time_t = crate::c_types::c_long;
+pub type __clockid_t = crate::c_types::c_ulong;
+pub type __timer_t = crate::c_types::c_ulong;
 pub type __sa_family_t = __uint8_t;
 pub type __socklen_t = __uint32_t;
-pub type __nl_item = crate::binary::c_types::c_int;
-pub type __nlink_t = crate::binary::c_types::c_ushort;
-pub type __suseconds_t = crate::binary::c_types::c_long;
-pub type __useconds_t = crate::binary::c_types::c_ulong;
+pub type __nl_item = crate::c_types::c_int;
+pub type __nlink_t = crate::c_types::c_ushort;
+pub type __suseconds_t = crate::c_types::c_long;
+pub type __useconds_t = crate::c_types::c_ulong;
 pub type __va_list = __builtin_va_list;
-pub type __ULong = crate::binary::c_types::c_ulong;
+pub type __ULong = crate::c_types::c_ulong;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct __lock {
@@ -1151,10 +1151,10 @@ extern "C" {
     pub fn __retarget_lock_acquire_recursive(lock: _LOCK_T);
 }
 extern "C" {
-    pub fn __retarget_lock_try_acquire(lock: _LOCK_T) -> crate::binary::c_types::c_int;
+    pub fn __retarget_lock_try_acquire(lock: _LOCK_T) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn __retarget_lock_try_acquire_recursive(lock: _LOCK_T) -> crate::binary::c_types::c_int;
+    pub fn __retarget_lock_try_acquire_recursive(lock: _LOCK_T) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn __retarget_lock_release(lock: _LOCK_T);
@@ -1172,30 +1172,30 @@ pub struct __locale_t {
 #[derive(Copy, Clone)]
 pub struct _Bigint {
     pub _next: *mut _Bigint,
-    pub _k: crate::binary::c_types::c_int,
-    pub _maxwds: crate::binary::c_types::c_int,
-    pub _sign: crate::binary::c_types::c_int,
-    pub _wds: crate::binary::c_types::c_int,
+    pub _k: crate::c_types::c_int,
+    pub _maxwds: crate::c_types::c_int,
+    pub _sign: crate::c_types::c_int,
+    pub _wds: crate::c_types::c_int,
     pub _x: [__ULong; 1usize],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct __tm {
-    pub __tm_sec: crate::binary::c_types::c_int,
-    pub __tm_min: crate::binary::c_types::c_int,
-    pub __tm_hour: crate::binary::c_types::c_int,
-    pub __tm_mday: crate::binary::c_types::c_int,
-    pub __tm_mon: crate::binary::c_types::c_int,
-    pub __tm_year: crate::binary::c_types::c_int,
-    pub __tm_wday: crate::binary::c_types::c_int,
-    pub __tm_yday: crate::binary::c_types::c_int,
-    pub __tm_isdst: crate::binary::c_types::c_int,
+    pub __tm_sec: crate::c_types::c_int,
+    pub __tm_min: crate::c_types::c_int,
+    pub __tm_hour: crate::c_types::c_int,
+    pub __tm_mday: crate::c_types::c_int,
+    pub __tm_mon: crate::c_types::c_int,
+    pub __tm_year: crate::c_types::c_int,
+    pub __tm_wday: crate::c_types::c_int,
+    pub __tm_yday: crate::c_types::c_int,
+    pub __tm_isdst: crate::c_types::c_int,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _on_exit_args {
-    pub _fnargs: [*mut crate::binary::c_types::c_void; 32usize],
-    pub _dso_handle: [*mut crate::binary::c_types::c_void; 32usize],
+    pub _fnargs: [*mut crate::c_types::c_void; 32usize],
+    pub _dso_handle: [*mut crate::c_types::c_void; 32usize],
     pub _fntypes: __ULong,
     pub _is_cxa: __ULong,
 }
@@ -1203,26 +1203,26 @@ pub struct _on_exit_args {
 #[derive(Copy, Clone)]
 pub struct _atexit {
     pub _next: *mut _atexit,
-    pub _ind: crate::binary::c_types::c_int,
+    pub _ind: crate::c_types::c_int,
     pub _fns: [::core::option::Option<unsafe extern "C" fn()>; 32usize],
     pub _on_exit_args_ptr: *mut _on_exit_args,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct __sbuf {
-    pub _base: *mut crate::binary::c_types::c_uchar,
-    pub _size: crate::binary::c_types::c_int,
+    pub _base: *mut crate::c_types::c_uchar,
+    pub _size: crate::c_types::c_int,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct __sFILE_fake {
-    pub _p: *mut crate::binary::c_types::c_uchar,
-    pub _r: crate::binary::c_types::c_int,
-    pub _w: crate::binary::c_types::c_int,
-    pub _flags: crate::binary::c_types::c_short,
-    pub _file: crate::binary::c_types::c_short,
+    pub _p: *mut crate::c_types::c_uchar,
+    pub _r: crate::c_types::c_int,
+    pub _w: crate::c_types::c_int,
+    pub _flags: crate::c_types::c_short,
+    pub _file: crate::c_types::c_short,
     pub _bf: __sbuf,
-    pub _lbfsize: crate::binary::c_types::c_int,
+    pub _lbfsize: crate::c_types::c_int,
     pub _data: *mut _reent,
 }
 extern "C" {
@@ -1231,90 +1231,90 @@ extern "C" {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct __sFILE {
-    pub _p: *mut crate::binary::c_types::c_uchar,
-    pub _r: crate::binary::c_types::c_int,
-    pub _w: crate::binary::c_types::c_int,
-    pub _flags: crate::binary::c_types::c_short,
-    pub _file: crate::binary::c_types::c_short,
+    pub _p: *mut crate::c_types::c_uchar,
+    pub _r: crate::c_types::c_int,
+    pub _w: crate::c_types::c_int,
+    pub _flags: crate::c_types::c_short,
+    pub _file: crate::c_types::c_short,
     pub _bf: __sbuf,
-    pub _lbfsize: crate::binary::c_types::c_int,
+    pub _lbfsize: crate::c_types::c_int,
     pub _data: *mut _reent,
-    pub _cookie: *mut crate::binary::c_types::c_void,
+    pub _cookie: *mut crate::c_types::c_void,
     pub _read: ::core::option::Option<
         unsafe extern "C" fn(
             arg1: *mut _reent,
-            arg2: *mut crate::binary::c_types::c_void,
-            arg3: *mut crate::binary::c_types::c_char,
-            arg4: crate::binary::c_types::c_int,
-        ) -> crate::binary::c_types::c_int,
+            arg2: *mut crate::c_types::c_void,
+            arg3: *mut crate::c_types::c_char,
+            arg4: crate::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _write: ::core::option::Option<
         unsafe extern "C" fn(
             arg1: *mut _reent,
-            arg2: *mut crate::binary::c_types::c_void,
-            arg3: *const crate::binary::c_types::c_char,
-            arg4: crate::binary::c_types::c_int,
-        ) -> crate::binary::c_types::c_int,
+            arg2: *mut crate::c_types::c_void,
+            arg3: *const crate::c_types::c_char,
+            arg4: crate::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _seek: ::core::option::Option<
         unsafe extern "C" fn(
             arg1: *mut _reent,
-            arg2: *mut crate::binary::c_types::c_void,
+            arg2: *mut crate::c_types::c_void,
             arg3: _fpos_t,
-            arg4: crate::binary::c_types::c_int,
+            arg4: crate::c_types::c_int,
         ) -> _fpos_t,
     >,
     pub _close: ::core::option::Option<
         unsafe extern "C" fn(
             arg1: *mut _reent,
-            arg2: *mut crate::binary::c_types::c_void,
-        ) -> crate::binary::c_types::c_int,
+            arg2: *mut crate::c_types::c_void,
+        ) -> crate::c_types::c_int,
     >,
     pub _ub: __sbuf,
-    pub _up: *mut crate::binary::c_types::c_uchar,
-    pub _ur: crate::binary::c_types::c_int,
-    pub _ubuf: [crate::binary::c_types::c_uchar; 3usize],
-    pub _nbuf: [crate::binary::c_types::c_uchar; 1usize],
+    pub _up: *mut crate::c_types::c_uchar,
+    pub _ur: crate::c_types::c_int,
+    pub _ubuf: [crate::c_types::c_uchar; 3usize],
+    pub _nbuf: [crate::c_types::c_uchar; 1usize],
     pub _lb: __sbuf,
-    pub _blksize: crate::binary::c_types::c_int,
+    pub _blksize: crate::c_types::c_int,
     pub _offset: _off_t,
     pub _lock: _flock_t,
     pub _mbstate: _mbstate_t,
-    pub _flags2: crate::binary::c_types::c_int,
+    pub _flags2: crate::c_types::c_int,
 }
 pub type __FILE = __sFILE;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _glue {
     pub _next: *mut _glue,
-    pub _niobs: crate::binary::c_types::c_int,
+    pub _niobs: crate::c_types::c_int,
     pub _iobs: *mut __FILE,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _rand48 {
-    pub _seed: [crate::binary::c_types::c_ushort; 3usize],
-    pub _mult: [crate::binary::c_types::c_ushort; 3usize],
-    pub _add: crate::binary::c_types::c_ushort,
-    pub _rand_next: crate::binary::c_types::c_ulonglong,
+    pub _seed: [crate::c_types::c_ushort; 3usize],
+    pub _mult: [crate::c_types::c_ushort; 3usize],
+    pub _add: crate::c_types::c_ushort,
+    pub _rand_next: crate::c_types::c_ulonglong,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _mprec {
     pub _result: *mut _Bigint,
-    pub _result_k: crate::binary::c_types::c_int,
+    pub _result_k: crate::c_types::c_int,
     pub _p5s: *mut _Bigint,
     pub _freelist: *mut *mut _Bigint,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _misc_reent {
-    pub _strtok_last: *mut crate::binary::c_types::c_char,
+    pub _strtok_last: *mut crate::c_types::c_char,
     pub _mblen_state: _mbstate_t,
     pub _wctomb_state: _mbstate_t,
     pub _mbtowc_state: _mbstate_t,
-    pub _l64a_buf: [crate::binary::c_types::c_char; 8usize],
-    pub _getdate_err: crate::binary::c_types::c_int,
+    pub _l64a_buf: [crate::c_types::c_char; 8usize],
+    pub _getdate_err: crate::c_types::c_int,
     pub _mbrlen_state: _mbstate_t,
     pub _mbrtowc_state: _mbstate_t,
     pub _mbsrtowcs_state: _mbstate_t,
@@ -1324,31 +1324,31 @@ pub struct _misc_reent {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _reent {
-    pub _errno: crate::binary::c_types::c_int,
+    pub _errno: crate::c_types::c_int,
     pub _stdin: *mut __FILE,
     pub _stdout: *mut __FILE,
     pub _stderr: *mut __FILE,
-    pub _inc: crate::binary::c_types::c_int,
-    pub _emergency: *mut crate::binary::c_types::c_char,
-    pub __sdidinit: crate::binary::c_types::c_int,
-    pub _unspecified_locale_info: crate::binary::c_types::c_int,
+    pub _inc: crate::c_types::c_int,
+    pub _emergency: *mut crate::c_types::c_char,
+    pub __sdidinit: crate::c_types::c_int,
+    pub _unspecified_locale_info: crate::c_types::c_int,
     pub _locale: *mut __locale_t,
     pub _mp: *mut _mprec,
     pub __cleanup: ::core::option::Option<unsafe extern "C" fn(arg1: *mut _reent)>,
-    pub _gamma_signgam: crate::binary::c_types::c_int,
-    pub _cvtlen: crate::binary::c_types::c_int,
-    pub _cvtbuf: *mut crate::binary::c_types::c_char,
+    pub _gamma_signgam: crate::c_types::c_int,
+    pub _cvtlen: crate::c_types::c_int,
+    pub _cvtbuf: *mut crate::c_types::c_char,
     pub _r48: *mut _rand48,
     pub _localtime_buf: *mut __tm,
-    pub _asctime_buf: *mut crate::binary::c_types::c_char,
+    pub _asctime_buf: *mut crate::c_types::c_char,
     pub _sig_func:
-        *mut ::core::option::Option<unsafe extern "C" fn(arg1: crate::binary::c_types::c_int)>,
+        *mut ::core::option::Option<unsafe extern "C" fn(arg1: crate::c_types::c_int)>,
     pub _atexit: *mut _atexit,
     pub _atexit0: _atexit,
     pub __sglue: _glue,
     pub __sf: *mut __FILE,
     pub _misc: *mut _misc_reent,
-    pub _signal_buf: *mut crate::binary::c_types::c_char,
+    pub _signal_buf: *mut crate::c_types::c_char,
 }
 extern "C" {
     pub static __sf_fake_stdin: __sFILE_fake;
@@ -1361,17 +1361,17 @@ extern "C" {
 }
 extern "C" {
     pub fn __assert(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
     );
 }
 extern "C" {
     pub fn __assert_func(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
-        arg4: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
     );
 }
 extern "C" {
@@ -1388,9 +1388,9 @@ pub type u_int16_t = __uint16_t;
 pub type u_int32_t = __uint32_t;
 pub type u_int64_t = __uint64_t;
 pub type register_t = __intptr_t;
-pub type __sigset_t = crate::binary::c_types::c_ulong;
+pub type __sigset_t = crate::c_types::c_ulong;
 pub type suseconds_t = __suseconds_t;
-pub type time_t = crate::binary::c_types::c_long;
+pub type time_t = crate::c_types::c_long;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct timeval {
@@ -1401,7 +1401,7 @@ pub struct timeval {
 #[derive(Copy, Clone)]
 pub struct timespec {
     pub tv_sec: time_t,
-    pub tv_nsec: crate::binary::c_types::c_long,
+    pub tv_nsec: crate::c_types::c_long,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -1410,7 +1410,7 @@ pub struct itimerspec {
     pub it_value: timespec,
 }
 pub type sigset_t = __sigset_t;
-pub type fd_mask = crate::binary::c_types::c_ulong;
+pub type fd_mask = crate::c_types::c_ulong;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct _types_fd_set {
@@ -1418,38 +1418,38 @@ pub struct _types_fd_set {
 }
 extern "C" {
     pub fn select(
-        __n: crate::binary::c_types::c_int,
+        __n: crate::c_types::c_int,
         __readfds: *mut _types_fd_set,
         __writefds: *mut _types_fd_set,
         __exceptfds: *mut _types_fd_set,
         __timeout: *mut timeval,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn pselect(
-        __n: crate::binary::c_types::c_int,
+        __n: crate::c_types::c_int,
         __readfds: *mut _types_fd_set,
         __writefds: *mut _types_fd_set,
         __exceptfds: *mut _types_fd_set,
         __timeout: *const timespec,
         __set: *const sigset_t,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 pub type in_addr_t = __uint32_t;
 pub type in_port_t = __uint16_t;
 pub type u_register_t = __uintptr_t;
-pub type u_char = crate::binary::c_types::c_uchar;
-pub type u_short = crate::binary::c_types::c_ushort;
-pub type u_int = crate::binary::c_types::c_uint;
-pub type u_long = crate::binary::c_types::c_ulong;
-pub type ushort = crate::binary::c_types::c_ushort;
-pub type uint = crate::binary::c_types::c_uint;
-pub type ulong = crate::binary::c_types::c_ulong;
+pub type u_char = crate::c_types::c_uchar;
+pub type u_short = crate::c_types::c_ushort;
+pub type u_int = crate::c_types::c_uint;
+pub type u_long = crate::c_types::c_ulong;
+pub type ushort = crate::c_types::c_ushort;
+pub type uint = crate::c_types::c_uint;
+pub type ulong = crate::c_types::c_ulong;
 pub type blkcnt_t = __blkcnt_t;
 pub type blksize_t = __blksize_t;
-pub type clock_t = crate::binary::c_types::c_ulong;
-pub type daddr_t = crate::binary::c_types::c_long;
-pub type caddr_t = *mut crate::binary::c_types::c_char;
+pub type clock_t = crate::c_types::c_ulong;
+pub type daddr_t = crate::c_types::c_long;
+pub type caddr_t = *mut crate::c_types::c_char;
 pub type fsblkcnt_t = __fsblkcnt_t;
 pub type fsfilcnt_t = __fsfilcnt_t;
 pub type id_t = __id_t;
@@ -1470,220 +1470,220 @@ pub type sbintime_t = __int64_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct sched_param {
-    pub sched_priority: crate::binary::c_types::c_int,
+    pub sched_priority: crate::c_types::c_int,
 }
 extern "C" {
-    pub fn sched_yield() -> crate::binary::c_types::c_int;
+    pub fn sched_yield() -> crate::c_types::c_int;
 }
 pub type pthread_t = __uint32_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct pthread_attr_t {
-    pub is_initialized: crate::binary::c_types::c_int,
-    pub stackaddr: *mut crate::binary::c_types::c_void,
-    pub stacksize: crate::binary::c_types::c_int,
-    pub contentionscope: crate::binary::c_types::c_int,
-    pub inheritsched: crate::binary::c_types::c_int,
-    pub schedpolicy: crate::binary::c_types::c_int,
+    pub is_initialized: crate::c_types::c_int,
+    pub stackaddr: *mut crate::c_types::c_void,
+    pub stacksize: crate::c_types::c_int,
+    pub contentionscope: crate::c_types::c_int,
+    pub inheritsched: crate::c_types::c_int,
+    pub schedpolicy: crate::c_types::c_int,
     pub schedparam: sched_param,
-    pub detachstate: crate::binary::c_types::c_int,
+    pub detachstate: crate::c_types::c_int,
 }
 pub type pthread_mutex_t = __uint32_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct pthread_mutexattr_t {
-    pub is_initialized: crate::binary::c_types::c_int,
-    pub recursive: crate::binary::c_types::c_int,
+    pub is_initialized: crate::c_types::c_int,
+    pub recursive: crate::c_types::c_int,
 }
 pub type pthread_cond_t = __uint32_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct pthread_condattr_t {
-    pub is_initialized: crate::binary::c_types::c_int,
+    pub is_initialized: crate::c_types::c_int,
     pub clock: clock_t,
 }
 pub type pthread_key_t = __uint32_t;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct pthread_once_t {
-    pub is_initialized: crate::binary::c_types::c_int,
-    pub init_executed: crate::binary::c_types::c_int,
+    pub is_initialized: crate::c_types::c_int,
+    pub init_executed: crate::c_types::c_int,
 }
 pub type FILE = __FILE;
 pub type fpos_t = _fpos_t;
 extern "C" {
     pub fn ctermid(
-        arg1: *mut crate::binary::c_types::c_char,
-    ) -> *mut crate::binary::c_types::c_char;
+        arg1: *mut crate::c_types::c_char,
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn tmpfile() -> *mut FILE;
 }
 extern "C" {
-    pub fn tmpnam(arg1: *mut crate::binary::c_types::c_char)
-        -> *mut crate::binary::c_types::c_char;
+    pub fn tmpnam(arg1: *mut crate::c_types::c_char)
+        -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn tempnam(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
-    ) -> *mut crate::binary::c_types::c_char;
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
-    pub fn fclose(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fclose(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fflush(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fflush(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn freopen(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: *mut FILE,
     ) -> *mut FILE;
 }
 extern "C" {
-    pub fn setbuf(arg1: *mut FILE, arg2: *mut crate::binary::c_types::c_char);
+    pub fn setbuf(arg1: *mut FILE, arg2: *mut crate::c_types::c_char);
 }
 extern "C" {
     pub fn setvbuf(
         arg1: *mut FILE,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: crate::binary::c_types::c_int,
+        arg2: *mut crate::c_types::c_char,
+        arg3: crate::c_types::c_int,
         arg4: size_t,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fprintf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fscanf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn printf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn scanf(arg1: *const crate::binary::c_types::c_char, ...)
-        -> crate::binary::c_types::c_int;
+    pub fn scanf(arg1: *const crate::c_types::c_char, ...)
+        -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn sscanf(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vfprintf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vprintf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         arg2: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vsprintf(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fgetc(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fgetc(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fgets(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: crate::binary::c_types::c_int,
+        arg1: *mut crate::c_types::c_char,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn fputc(
-        arg1: crate::binary::c_types::c_int,
+        arg1: crate::c_types::c_int,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fputs(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn getc(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn getc(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn getchar() -> crate::binary::c_types::c_int;
+    pub fn getchar() -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn gets(arg1: *mut crate::binary::c_types::c_char) -> *mut crate::binary::c_types::c_char;
+    pub fn gets(arg1: *mut crate::c_types::c_char) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn putc(
-        arg1: crate::binary::c_types::c_int,
+        arg1: crate::c_types::c_int,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn putchar(arg1: crate::binary::c_types::c_int) -> crate::binary::c_types::c_int;
+    pub fn putchar(arg1: crate::c_types::c_int) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn puts(arg1: *const crate::binary::c_types::c_char) -> crate::binary::c_types::c_int;
+    pub fn puts(arg1: *const crate::c_types::c_char) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn ungetc(
-        arg1: crate::binary::c_types::c_int,
+        arg1: crate::c_types::c_int,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fread(
-        arg1: *mut crate::binary::c_types::c_void,
-        _size: crate::binary::c_types::c_ulonglong,
-        _n: crate::binary::c_types::c_ulonglong,
+        arg1: *mut crate::c_types::c_void,
+        _size: crate::c_types::c_ulonglong,
+        _n: crate::c_types::c_ulonglong,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_ulonglong;
+    ) -> crate::c_types::c_ulonglong;
 }
 extern "C" {
     pub fn fwrite(
-        arg1: *const crate::binary::c_types::c_void,
-        _size: crate::binary::c_types::c_ulonglong,
-        _n: crate::binary::c_types::c_ulonglong,
+        arg1: *const crate::c_types::c_void,
+        _size: crate::c_types::c_ulonglong,
+        _n: crate::c_types::c_ulonglong,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_ulonglong;
+    ) -> crate::c_types::c_ulonglong;
 }
 extern "C" {
-    pub fn fgetpos(arg1: *mut FILE, arg2: *mut fpos_t) -> crate::binary::c_types::c_int;
+    pub fn fgetpos(arg1: *mut FILE, arg2: *mut fpos_t) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fseek(
         arg1: *mut FILE,
-        arg2: crate::binary::c_types::c_long,
-        arg3: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int;
+        arg2: crate::c_types::c_long,
+        arg3: crate::c_types::c_int,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fsetpos(arg1: *mut FILE, arg2: *const fpos_t) -> crate::binary::c_types::c_int;
+    pub fn fsetpos(arg1: *mut FILE, arg2: *const fpos_t) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn ftell(arg1: *mut FILE) -> crate::binary::c_types::c_long;
+    pub fn ftell(arg1: *mut FILE) -> crate::c_types::c_long;
 }
 extern "C" {
     pub fn rewind(arg1: *mut FILE);
@@ -1692,472 +1692,472 @@ extern "C" {
     pub fn clearerr(arg1: *mut FILE);
 }
 extern "C" {
-    pub fn feof(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn feof(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn ferror(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn ferror(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn perror(arg1: *const crate::binary::c_types::c_char);
+    pub fn perror(arg1: *const crate::c_types::c_char);
 }
 extern "C" {
     pub fn fopen(
-        _name: *const crate::binary::c_types::c_char,
-        _type: *const crate::binary::c_types::c_char,
+        _name: *const crate::c_types::c_char,
+        _type: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn sprintf(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn remove(arg1: *const crate::binary::c_types::c_char) -> crate::binary::c_types::c_int;
+    pub fn remove(arg1: *const crate::c_types::c_char) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn rename(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
-    ) -> crate::binary::c_types::c_int;
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fseeko(
         arg1: *mut FILE,
         arg2: off_t,
-        arg3: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int;
+        arg3: crate::c_types::c_int,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn ftello(arg1: *mut FILE) -> off_t;
 }
 extern "C" {
     pub fn snprintf(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: crate::binary::c_types::c_ulonglong,
-        arg3: *const crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
+        arg2: crate::c_types::c_ulonglong,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vsnprintf(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: crate::binary::c_types::c_ulonglong,
-        arg3: *const crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
+        arg2: crate::c_types::c_ulonglong,
+        arg3: *const crate::c_types::c_char,
         arg4: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vfscanf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vscanf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         arg2: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vsscanf(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __builtin_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn asiprintf(
-        arg1: *mut *mut crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *mut *mut crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn asniprintf(
-        arg1: *mut crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
         arg2: *mut size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn asnprintf(
-        arg1: *mut crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
         arg2: *mut size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn diprintf(
-        arg1: crate::binary::c_types::c_int,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: crate::c_types::c_int,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fiprintf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fiscanf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn iprintf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn iscanf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn siprintf(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn siscanf(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn sniprintf(
-        arg1: *mut crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
         arg2: size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vasiprintf(
-        arg1: *mut *mut crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *mut *mut crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vasniprintf(
-        arg1: *mut crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
         arg2: *mut size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn vasnprintf(
-        arg1: *mut crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
         arg2: *mut size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn vdiprintf(
-        arg1: crate::binary::c_types::c_int,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: crate::c_types::c_int,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vfiprintf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vfiscanf(
         arg1: *mut FILE,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn viprintf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         arg2: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn viscanf(
-        arg1: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
         arg2: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vsiprintf(
-        arg1: *mut crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vsiscanf(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn vsniprintf(
-        arg1: *mut crate::binary::c_types::c_char,
+        arg1: *mut crate::c_types::c_char,
         arg2: size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fdopen(
-        arg1: crate::binary::c_types::c_int,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: crate::c_types::c_int,
+        arg2: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
-    pub fn fileno(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fileno(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn pclose(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn pclose(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn popen(
-        arg1: *const crate::binary::c_types::c_char,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: *const crate::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn setbuffer(
         arg1: *mut FILE,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: crate::binary::c_types::c_int,
+        arg2: *mut crate::c_types::c_char,
+        arg3: crate::c_types::c_int,
     );
 }
 extern "C" {
-    pub fn setlinebuf(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn setlinebuf(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn getw(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn getw(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn putw(
-        arg1: crate::binary::c_types::c_int,
+        arg1: crate::c_types::c_int,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn getc_unlocked(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn getc_unlocked(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn getchar_unlocked() -> crate::binary::c_types::c_int;
+    pub fn getchar_unlocked() -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn flockfile(arg1: *mut FILE);
 }
 extern "C" {
-    pub fn ftrylockfile(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn ftrylockfile(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn funlockfile(arg1: *mut FILE);
 }
 extern "C" {
     pub fn putc_unlocked(
-        arg1: crate::binary::c_types::c_int,
+        arg1: crate::c_types::c_int,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn putchar_unlocked(arg1: crate::binary::c_types::c_int) -> crate::binary::c_types::c_int;
+    pub fn putchar_unlocked(arg1: crate::c_types::c_int) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn dprintf(
-        arg1: crate::binary::c_types::c_int,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: crate::c_types::c_int,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fmemopen(
-        arg1: *mut crate::binary::c_types::c_void,
+        arg1: *mut crate::c_types::c_void,
         arg2: size_t,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn open_memstream(
-        arg1: *mut *mut crate::binary::c_types::c_char,
+        arg1: *mut *mut crate::c_types::c_char,
         arg2: *mut size_t,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn vdprintf(
-        arg1: crate::binary::c_types::c_int,
-        arg2: *const crate::binary::c_types::c_char,
+        arg1: crate::c_types::c_int,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn renameat(
-        arg1: crate::binary::c_types::c_int,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: crate::binary::c_types::c_int,
-        arg4: *const crate::binary::c_types::c_char,
-    ) -> crate::binary::c_types::c_int;
+        arg1: crate::c_types::c_int,
+        arg2: *const crate::c_types::c_char,
+        arg3: crate::c_types::c_int,
+        arg4: *const crate::c_types::c_char,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _asiprintf_r(
         arg1: *mut _reent,
-        arg2: *mut *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _asniprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: *mut size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         ...
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _asnprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: *mut size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         ...
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _asprintf_r(
         arg1: *mut _reent,
-        arg2: *mut *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _diprintf_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _dprintf_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _fclose_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _fclose_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _fcloseall_r(arg1: *mut _reent) -> crate::binary::c_types::c_int;
+    pub fn _fcloseall_r(arg1: *mut _reent) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fdopen_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
-    pub fn _fflush_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _fflush_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _fgetc_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _fgetc_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _fgetc_unlocked_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _fgetc_unlocked_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fgets_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: crate::binary::c_types::c_int,
+        arg2: *mut crate::c_types::c_char,
+        arg3: crate::c_types::c_int,
         arg4: *mut FILE,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _fgets_unlocked_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: crate::binary::c_types::c_int,
+        arg2: *mut crate::c_types::c_char,
+        arg3: crate::c_types::c_int,
         arg4: *mut FILE,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _fgetpos_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
         arg3: *mut fpos_t,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fsetpos_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
         arg3: *const fpos_t,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fiprintf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fiscanf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fmemopen_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_void,
+        arg2: *mut crate::c_types::c_void,
         arg3: size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn _fopen_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn _freopen_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: *mut FILE,
     ) -> *mut FILE;
 }
@@ -2165,45 +2165,45 @@ extern "C" {
     pub fn _fprintf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _fpurge_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _fpurge_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fputc_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fputc_unlocked_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fputs_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fputs_unlocked_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fread_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_void,
+        arg2: *mut crate::c_types::c_void,
         _size: size_t,
         _n: size_t,
         arg3: *mut FILE,
@@ -2212,7 +2212,7 @@ extern "C" {
 extern "C" {
     pub fn _fread_unlocked_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_void,
+        arg2: *mut crate::c_types::c_void,
         _size: size_t,
         _n: size_t,
         arg3: *mut FILE,
@@ -2222,28 +2222,28 @@ extern "C" {
     pub fn _fscanf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fseek_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: crate::binary::c_types::c_long,
-        arg4: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int;
+        arg3: crate::c_types::c_long,
+        arg4: crate::c_types::c_int,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _fseeko_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
         arg3: _off_t,
-        arg4: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int;
+        arg4: crate::c_types::c_int,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _ftell_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_long;
+    pub fn _ftell_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_long;
 }
 extern "C" {
     pub fn _ftello_r(arg1: *mut _reent, arg2: *mut FILE) -> _off_t;
@@ -2254,7 +2254,7 @@ extern "C" {
 extern "C" {
     pub fn _fwrite_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_void,
+        arg2: *const crate::c_types::c_void,
         _size: size_t,
         _n: size_t,
         arg3: *mut FILE,
@@ -2263,169 +2263,169 @@ extern "C" {
 extern "C" {
     pub fn _fwrite_unlocked_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_void,
+        arg2: *const crate::c_types::c_void,
         _size: size_t,
         _n: size_t,
         arg3: *mut FILE,
     ) -> size_t;
 }
 extern "C" {
-    pub fn _getc_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _getc_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _getc_unlocked_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn _getc_unlocked_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _getchar_r(arg1: *mut _reent) -> crate::binary::c_types::c_int;
+    pub fn _getchar_r(arg1: *mut _reent) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn _getchar_unlocked_r(arg1: *mut _reent) -> crate::binary::c_types::c_int;
+    pub fn _getchar_unlocked_r(arg1: *mut _reent) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _gets_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-    ) -> *mut crate::binary::c_types::c_char;
+        arg2: *mut crate::c_types::c_char,
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _iprintf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _iscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _open_memstream_r(
         arg1: *mut _reent,
-        arg2: *mut *mut crate::binary::c_types::c_char,
+        arg2: *mut *mut crate::c_types::c_char,
         arg3: *mut size_t,
     ) -> *mut FILE;
 }
 extern "C" {
-    pub fn _perror_r(arg1: *mut _reent, arg2: *const crate::binary::c_types::c_char);
+    pub fn _perror_r(arg1: *mut _reent, arg2: *const crate::c_types::c_char);
 }
 extern "C" {
     pub fn _printf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _putc_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _putc_unlocked_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _putchar_unlocked_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int;
+        arg2: crate::c_types::c_int,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _putchar_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int;
+        arg2: crate::c_types::c_int,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _puts_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-    ) -> crate::binary::c_types::c_int;
+        arg2: *const crate::c_types::c_char,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _remove_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-    ) -> crate::binary::c_types::c_int;
+        arg2: *const crate::c_types::c_char,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _rename_r(
         arg1: *mut _reent,
-        _old: *const crate::binary::c_types::c_char,
-        _new: *const crate::binary::c_types::c_char,
-    ) -> crate::binary::c_types::c_int;
+        _old: *const crate::c_types::c_char,
+        _new: *const crate::c_types::c_char,
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _scanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _siprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _siscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _sniprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _snprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _sprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _sscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         ...
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _tempnam_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
-    ) -> *mut crate::binary::c_types::c_char;
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _tmpfile_r(arg1: *mut _reent) -> *mut FILE;
@@ -2433,190 +2433,190 @@ extern "C" {
 extern "C" {
     pub fn _tmpnam_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-    ) -> *mut crate::binary::c_types::c_char;
+        arg2: *mut crate::c_types::c_char,
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _ungetc_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vasiprintf_r(
         arg1: *mut _reent,
-        arg2: *mut *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vasniprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: *mut size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         arg5: __gnuc_va_list,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _vasnprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: *mut size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         arg5: __gnuc_va_list,
-    ) -> *mut crate::binary::c_types::c_char;
+    ) -> *mut crate::c_types::c_char;
 }
 extern "C" {
     pub fn _vasprintf_r(
         arg1: *mut _reent,
-        arg2: *mut *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vdiprintf_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vdprintf_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: crate::c_types::c_int,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vfiprintf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vfiscanf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vfprintf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vfscanf_r(
         arg1: *mut _reent,
         arg2: *mut FILE,
-        arg3: *const crate::binary::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _viprintf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _viscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vprintf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
         arg3: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vsiprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vsiscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vsniprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         arg5: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vsnprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
         arg3: size_t,
-        arg4: *const crate::binary::c_types::c_char,
+        arg4: *const crate::c_types::c_char,
         arg5: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vsprintf_r(
         arg1: *mut _reent,
-        arg2: *mut crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *mut crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn _vsscanf_r(
         arg1: *mut _reent,
-        arg2: *const crate::binary::c_types::c_char,
-        arg3: *const crate::binary::c_types::c_char,
+        arg2: *const crate::c_types::c_char,
+        arg3: *const crate::c_types::c_char,
         arg4: __gnuc_va_list,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fpurge(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fpurge(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn __getdelim(
-        arg1: *mut *mut crate::binary::c_types::c_char,
+        arg1: *mut *mut crate::c_types::c_char,
         arg2: *mut size_t,
-        arg3: crate::binary::c_types::c_int,
+        arg3: crate::c_types::c_int,
         arg4: *mut FILE,
     ) -> ssize_t;
 }
 extern "C" {
     pub fn __getline(
-        arg1: *mut *mut crate::binary::c_types::c_char,
+        arg1: *mut *mut crate::c_types::c_char,
         arg2: *mut size_t,
         arg3: *mut FILE,
     ) -> ssize_t;
@@ -2625,29 +2625,29 @@ extern "C" {
     pub fn clearerr_unlocked(arg1: *mut FILE);
 }
 extern "C" {
-    pub fn feof_unlocked(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn feof_unlocked(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn ferror_unlocked(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn ferror_unlocked(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fileno_unlocked(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fileno_unlocked(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fflush_unlocked(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fflush_unlocked(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
-    pub fn fgetc_unlocked(arg1: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn fgetc_unlocked(arg1: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fputc_unlocked(
-        arg1: crate::binary::c_types::c_int,
+        arg1: crate::c_types::c_int,
         arg2: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn fread_unlocked(
-        arg1: *mut crate::binary::c_types::c_void,
+        arg1: *mut crate::c_types::c_void,
         _size: size_t,
         _n: size_t,
         arg2: *mut FILE,
@@ -2655,86 +2655,86 @@ extern "C" {
 }
 extern "C" {
     pub fn fwrite_unlocked(
-        arg1: *const crate::binary::c_types::c_void,
+        arg1: *const crate::c_types::c_void,
         _size: size_t,
         _n: size_t,
         arg2: *mut FILE,
     ) -> size_t;
 }
 extern "C" {
-    pub fn __srget_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::binary::c_types::c_int;
+    pub fn __srget_r(arg1: *mut _reent, arg2: *mut FILE) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn __swbuf_r(
         arg1: *mut _reent,
-        arg2: crate::binary::c_types::c_int,
+        arg2: crate::c_types::c_int,
         arg3: *mut FILE,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     pub fn funopen(
-        __cookie: *const crate::binary::c_types::c_void,
+        __cookie: *const crate::c_types::c_void,
         __readfn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
-                __buf: *mut crate::binary::c_types::c_char,
-                __n: crate::binary::c_types::c_int,
-            ) -> crate::binary::c_types::c_int,
+                __cookie: *mut crate::c_types::c_void,
+                __buf: *mut crate::c_types::c_char,
+                __n: crate::c_types::c_int,
+            ) -> crate::c_types::c_int,
         >,
         __writefn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
-                __buf: *const crate::binary::c_types::c_char,
-                __n: crate::binary::c_types::c_int,
-            ) -> crate::binary::c_types::c_int,
+                __cookie: *mut crate::c_types::c_void,
+                __buf: *const crate::c_types::c_char,
+                __n: crate::c_types::c_int,
+            ) -> crate::c_types::c_int,
         >,
         __seekfn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
+                __cookie: *mut crate::c_types::c_void,
                 __off: fpos_t,
-                __whence: crate::binary::c_types::c_int,
+                __whence: crate::c_types::c_int,
             ) -> fpos_t,
         >,
         __closefn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
-            ) -> crate::binary::c_types::c_int,
+                __cookie: *mut crate::c_types::c_void,
+            ) -> crate::c_types::c_int,
         >,
     ) -> *mut FILE;
 }
 extern "C" {
     pub fn _funopen_r(
         arg1: *mut _reent,
-        __cookie: *const crate::binary::c_types::c_void,
+        __cookie: *const crate::c_types::c_void,
         __readfn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
-                __buf: *mut crate::binary::c_types::c_char,
-                __n: crate::binary::c_types::c_int,
-            ) -> crate::binary::c_types::c_int,
+                __cookie: *mut crate::c_types::c_void,
+                __buf: *mut crate::c_types::c_char,
+                __n: crate::c_types::c_int,
+            ) -> crate::c_types::c_int,
         >,
         __writefn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
-                __buf: *const crate::binary::c_types::c_char,
-                __n: crate::binary::c_types::c_int,
-            ) -> crate::binary::c_types::c_int,
+                __cookie: *mut crate::c_types::c_void,
+                __buf: *const crate::c_types::c_char,
+                __n: crate::c_types::c_int,
+            ) -> crate::c_types::c_int,
         >,
         __seekfn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
+                __cookie: *mut crate::c_types::c_void,
                 __off: fpos_t,
-                __whence: crate::binary::c_types::c_int,
+                __whence: crate::c_types::c_int,
             ) -> fpos_t,
         >,
         __closefn: ::core::option::Option<
             unsafe extern "C" fn(
-                __cookie: *mut crate::binary::c_types::c_void,
-            ) -> crate::binary::c_types::c_int,
+                __cookie: *mut crate::c_types::c_void,
+            ) -> crate::c_types::c_int,
         >,
     ) -> *mut FILE;
 }
-pub type esp_err_t = crate::binary::c_types::c_int;
+pub type esp_err_t = crate::c_types::c_int;
 extern "C" {
     #[doc = " @brief Returns string for esp_err_t error codes"]
     #[doc = ""]
@@ -2747,7 +2747,7 @@ extern "C" {
     #[doc = ""]
     #[doc = " @param code esp_err_t error code"]
     #[doc = " @return string error message"]
-    pub fn esp_err_to_name(code: esp_err_t) -> *const crate::binary::c_types::c_char;
+    pub fn esp_err_to_name(code: esp_err_t) -> *const crate::c_types::c_char;
 }
 extern "C" {
     #[doc = " @brief Returns string for esp_err_t and system error codes"]
@@ -2766,28 +2766,28 @@ extern "C" {
     #[doc = " @return buf containing the string error message"]
     pub fn esp_err_to_name_r(
         code: esp_err_t,
-        buf: *mut crate::binary::c_types::c_char,
+        buf: *mut crate::c_types::c_char,
         buflen: size_t,
-    ) -> *const crate::binary::c_types::c_char;
+    ) -> *const crate::c_types::c_char;
 }
 extern "C" {
     #[doc = " @cond"]
     pub fn _esp_error_check_failed(
         rc: esp_err_t,
-        file: *const crate::binary::c_types::c_char,
-        line: crate::binary::c_types::c_int,
-        function: *const crate::binary::c_types::c_char,
-        expression: *const crate::binary::c_types::c_char,
+        file: *const crate::c_types::c_char,
+        line: crate::c_types::c_int,
+        function: *const crate::c_types::c_char,
+        expression: *const crate::c_types::c_char,
     );
 }
 extern "C" {
     #[doc = " @cond"]
     pub fn _esp_error_check_failed_without_abort(
         rc: esp_err_t,
-        file: *const crate::binary::c_types::c_char,
-        line: crate::binary::c_types::c_int,
-        function: *const crate::binary::c_types::c_char,
-        expression: *const crate::binary::c_types::c_char,
+        file: *const crate::c_types::c_char,
+        line: crate::c_types::c_int,
+        function: *const crate::c_types::c_char,
+        expression: *const crate::c_types::c_char,
     );
 }
 #[doc = "< ESP32 station interface"]
@@ -2797,18 +2797,18 @@ pub const esp_interface_t_ESP_IF_WIFI_AP: esp_interface_t = 1;
 #[doc = "< ESP32 ethernet interface"]
 pub const esp_interface_t_ESP_IF_ETH: esp_interface_t = 2;
 pub const esp_interface_t_ESP_IF_MAX: esp_interface_t = 3;
-pub type esp_interface_t = crate::binary::c_types::c_uint;
-pub type esp_event_base_t = *const crate::binary::c_types::c_char;
-pub type esp_event_loop_handle_t = *mut crate::binary::c_types::c_void;
+pub type esp_interface_t = crate::c_types::c_uint;
+pub type esp_event_base_t = *const crate::c_types::c_char;
+pub type esp_event_loop_handle_t = *mut crate::c_types::c_void;
 pub type esp_event_handler_t = ::core::option::Option<
     unsafe extern "C" fn(
-        event_handler_arg: *mut crate::binary::c_types::c_void,
+        event_handler_arg: *mut crate::c_types::c_void,
         event_base: esp_event_base_t,
         event_id: i32,
-        event_data: *mut crate::binary::c_types::c_void,
+        event_data: *mut crate::c_types::c_void,
     ),
 >;
-pub type esp_event_handler_instance_t = *mut crate::binary::c_types::c_void;
+pub type esp_event_handler_instance_t = *mut crate::c_types::c_void;
 #[doc = "< null mode"]
 pub const wifi_mode_t_WIFI_MODE_NULL: wifi_mode_t = 0;
 #[doc = "< WiFi station mode"]
@@ -2818,21 +2818,21 @@ pub const wifi_mode_t_WIFI_MODE_AP: wifi_mode_t = 2;
 #[doc = "< WiFi station + soft-AP mode"]
 pub const wifi_mode_t_WIFI_MODE_APSTA: wifi_mode_t = 3;
 pub const wifi_mode_t_WIFI_MODE_MAX: wifi_mode_t = 4;
-pub type wifi_mode_t = crate::binary::c_types::c_uint;
+pub type wifi_mode_t = crate::c_types::c_uint;
 pub const wifi_interface_t_WIFI_IF_STA: wifi_interface_t = 0;
 pub const wifi_interface_t_WIFI_IF_AP: wifi_interface_t = 1;
-pub type wifi_interface_t = crate::binary::c_types::c_uint;
+pub type wifi_interface_t = crate::c_types::c_uint;
 #[doc = "< Country policy is auto, use the country info of AP to which the station is connected"]
 pub const wifi_country_policy_t_WIFI_COUNTRY_POLICY_AUTO: wifi_country_policy_t = 0;
 #[doc = "< Country policy is manual, always use the configured country info"]
 pub const wifi_country_policy_t_WIFI_COUNTRY_POLICY_MANUAL: wifi_country_policy_t = 1;
-pub type wifi_country_policy_t = crate::binary::c_types::c_uint;
+pub type wifi_country_policy_t = crate::c_types::c_uint;
 #[doc = " @brief Structure describing WiFi country-based regional restrictions."]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct wifi_country_t {
     #[doc = "< country code string"]
-    pub cc: [crate::binary::c_types::c_char; 3usize],
+    pub cc: [crate::c_types::c_char; 3usize],
     #[doc = "< start channel"]
     pub schan: u8,
     #[doc = "< total channel number"]
@@ -2861,7 +2861,7 @@ pub const wifi_auth_mode_t_WIFI_AUTH_WPA2_WPA3_PSK: wifi_auth_mode_t = 7;
 #[doc = "< authenticate mode : WAPI_PSK"]
 pub const wifi_auth_mode_t_WIFI_AUTH_WAPI_PSK: wifi_auth_mode_t = 8;
 pub const wifi_auth_mode_t_WIFI_AUTH_MAX: wifi_auth_mode_t = 9;
-pub type wifi_auth_mode_t = crate::binary::c_types::c_uint;
+pub type wifi_auth_mode_t = crate::c_types::c_uint;
 pub const wifi_err_reason_t_WIFI_REASON_UNSPECIFIED: wifi_err_reason_t = 1;
 pub const wifi_err_reason_t_WIFI_REASON_AUTH_EXPIRE: wifi_err_reason_t = 2;
 pub const wifi_err_reason_t_WIFI_REASON_AUTH_LEAVE: wifi_err_reason_t = 3;
@@ -2894,19 +2894,19 @@ pub const wifi_err_reason_t_WIFI_REASON_HANDSHAKE_TIMEOUT: wifi_err_reason_t = 2
 pub const wifi_err_reason_t_WIFI_REASON_CONNECTION_FAIL: wifi_err_reason_t = 205;
 pub const wifi_err_reason_t_WIFI_REASON_AP_TSF_RESET: wifi_err_reason_t = 206;
 pub const wifi_err_reason_t_WIFI_REASON_ROAMING: wifi_err_reason_t = 207;
-pub type wifi_err_reason_t = crate::binary::c_types::c_uint;
+pub type wifi_err_reason_t = crate::c_types::c_uint;
 #[doc = "< the channel width is HT20"]
 pub const wifi_second_chan_t_WIFI_SECOND_CHAN_NONE: wifi_second_chan_t = 0;
 #[doc = "< the channel width is HT40 and the secondary channel is above the primary channel"]
 pub const wifi_second_chan_t_WIFI_SECOND_CHAN_ABOVE: wifi_second_chan_t = 1;
 #[doc = "< the channel width is HT40 and the secondary channel is below the primary channel"]
 pub const wifi_second_chan_t_WIFI_SECOND_CHAN_BELOW: wifi_second_chan_t = 2;
-pub type wifi_second_chan_t = crate::binary::c_types::c_uint;
+pub type wifi_second_chan_t = crate::c_types::c_uint;
 #[doc = "< active scan"]
 pub const wifi_scan_type_t_WIFI_SCAN_TYPE_ACTIVE: wifi_scan_type_t = 0;
 #[doc = "< passive scan"]
 pub const wifi_scan_type_t_WIFI_SCAN_TYPE_PASSIVE: wifi_scan_type_t = 1;
-pub type wifi_scan_type_t = crate::binary::c_types::c_uint;
+pub type wifi_scan_type_t = crate::c_types::c_uint;
 #[doc = " @brief Range of active scan times per channel"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -2962,7 +2962,7 @@ pub const wifi_cipher_type_t_WIFI_CIPHER_TYPE_AES_CMAC128: wifi_cipher_type_t =
 pub const wifi_cipher_type_t_WIFI_CIPHER_TYPE_SMS4: wifi_cipher_type_t = 7;
 #[doc = "< the cipher type is unknown"]
 pub const wifi_cipher_type_t_WIFI_CIPHER_TYPE_UNKNOWN: wifi_cipher_type_t = 8;
-pub type wifi_cipher_type_t = crate::binary::c_types::c_uint;
+pub type wifi_cipher_type_t = crate::c_types::c_uint;
 #[doc = "< WiFi antenna 0"]
 pub const wifi_ant_t_WIFI_ANT_ANT0: wifi_ant_t = 0;
 #[doc = "< WiFi antenna 1"]
@@ -2971,7 +2971,7 @@ pub const wifi_ant_t_WIFI_ANT_ANT1: wifi_ant_t = 1;
 pub const wifi_ant_t_WIFI_ANT_MAX: wifi_ant_t = 2;
 #[doc = " @brief WiFi antenna"]
 #[doc = ""]
-pub type wifi_ant_t = crate::binary::c_types::c_uint;
+pub type wifi_ant_t = crate::c_types::c_uint;
 #[doc = " @brief Description of a WiFi AP"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -3139,12 +3139,12 @@ impl wifi_ap_record_t {
 pub const wifi_scan_method_t_WIFI_FAST_SCAN: wifi_scan_method_t = 0;
 #[doc = "< All channel scan, scan will end after scan all the channel"]
 pub const wifi_scan_method_t_WIFI_ALL_CHANNEL_SCAN: wifi_scan_method_t = 1;
-pub type wifi_scan_method_t = crate::binary::c_types::c_uint;
+pub type wifi_scan_method_t = crate::c_types::c_uint;
 #[doc = "< Sort match AP in scan list by RSSI"]
 pub const wifi_sort_method_t_WIFI_CONNECT_AP_BY_SIGNAL: wifi_sort_method_t = 0;
 #[doc = "< Sort match AP in scan list by security mode"]
 pub const wifi_sort_method_t_WIFI_CONNECT_AP_BY_SECURITY: wifi_sort_method_t = 1;
-pub type wifi_sort_method_t = crate::binary::c_types::c_uint;
+pub type wifi_sort_method_t = crate::c_types::c_uint;
 #[doc = " @brief Structure describing parameters for a WiFi fast scan"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -3160,10 +3160,10 @@ pub const wifi_ps_type_t_WIFI_PS_NONE: wifi_ps_type_t = 0;
 pub const wifi_ps_type_t_WIFI_PS_MIN_MODEM: wifi_ps_type_t = 1;
 #[doc = "< Maximum modem power saving. In this mode, interval to receive beacons is determined by the listen_interval parameter in wifi_sta_config_t"]
 pub const wifi_ps_type_t_WIFI_PS_MAX_MODEM: wifi_ps_type_t = 2;
-pub type wifi_ps_type_t = crate::binary::c_types::c_uint;
+pub type wifi_ps_type_t = crate::c_types::c_uint;
 pub const wifi_bandwidth_t_WIFI_BW_HT20: wifi_bandwidth_t = 1;
 pub const wifi_bandwidth_t_WIFI_BW_HT40: wifi_bandwidth_t = 2;
-pub type wifi_bandwidth_t = crate::binary::c_types::c_uint;
+pub type wifi_bandwidth_t = crate::c_types::c_uint;
 #[doc = " Configuration structure for Protected Management Frame"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -3401,13 +3401,13 @@ pub struct wifi_sta_list_t {
     #[doc = "< station list"]
     pub sta: [wifi_sta_info_t; 10usize],
     #[doc = "< number of stations in the list (other entries are invalid)"]
-    pub num: crate::binary::c_types::c_int,
+    pub num: crate::c_types::c_int,
 }
 #[doc = "< all configuration will store in both memory and flash"]
 pub const wifi_storage_t_WIFI_STORAGE_FLASH: wifi_storage_t = 0;
 #[doc = "< all configuration will only store in the memory"]
 pub const wifi_storage_t_WIFI_STORAGE_RAM: wifi_storage_t = 1;
-pub type wifi_storage_t = crate::binary::c_types::c_uint;
+pub type wifi_storage_t = crate::c_types::c_uint;
 pub const wifi_vendor_ie_type_t_WIFI_VND_IE_TYPE_BEACON: wifi_vendor_ie_type_t = 0;
 pub const wifi_vendor_ie_type_t_WIFI_VND_IE_TYPE_PROBE_REQ: wifi_vendor_ie_type_t = 1;
 pub const wifi_vendor_ie_type_t_WIFI_VND_IE_TYPE_PROBE_RESP: wifi_vendor_ie_type_t = 2;
@@ -3416,13 +3416,13 @@ pub const wifi_vendor_ie_type_t_WIFI_VND_IE_TYPE_ASSOC_RESP: wifi_vendor_ie_type
 #[doc = " @brief     Vendor Information Element type"]
 #[doc = ""]
 #[doc = " Determines the frame type that the IE will be associated with."]
-pub type wifi_vendor_ie_type_t = crate::binary::c_types::c_uint;
+pub type wifi_vendor_ie_type_t = crate::c_types::c_uint;
 pub const wifi_vendor_ie_id_t_WIFI_VND_IE_ID_0: wifi_vendor_ie_id_t = 0;
 pub const wifi_vendor_ie_id_t_WIFI_VND_IE_ID_1: wifi_vendor_ie_id_t = 1;
 #[doc = " @brief     Vendor Information Element index"]
 #[doc = ""]
 #[doc = " Each IE type can have up to two associated vendor ID elements."]
-pub type wifi_vendor_ie_id_t = crate::binary::c_types::c_uint;
+pub type wifi_vendor_ie_id_t = crate::c_types::c_uint;
 #[doc = " @brief Vendor Information Element header"]
 #[doc = ""]
 #[doc = " The first bytes of the Information Element will match this header. Payload follows."]
@@ -3449,209 +3449,209 @@ pub struct wifi_pkt_rx_ctrl_t {
 }
 impl wifi_pkt_rx_ctrl_t {
     #[inline]
-    pub fn rssi(&self) -> crate::binary::c_types::c_int {
+    pub fn rssi(&self) -> crate::c_types::c_int {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_rssi(&mut self, val: crate::binary::c_types::c_int) {
+    pub fn set_rssi(&mut self, val: crate::c_types::c_int) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 8u8, val as u64)
         }
     }
     #[inline]
-    pub fn rate(&self) -> crate::binary::c_types::c_uint {
+    pub fn rate(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 5u8) as u32) }
     }
     #[inline]
-    pub fn set_rate(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_rate(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(8usize, 5u8, val as u64)
         }
     }
     #[inline]
-    pub fn sig_mode(&self) -> crate::binary::c_types::c_uint {
+    pub fn sig_mode(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(14usize, 2u8) as u32) }
     }
     #[inline]
-    pub fn set_sig_mode(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_sig_mode(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(14usize, 2u8, val as u64)
         }
     }
     #[inline]
-    pub fn mcs(&self) -> crate::binary::c_types::c_uint {
+    pub fn mcs(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(32usize, 7u8) as u32) }
     }
     #[inline]
-    pub fn set_mcs(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_mcs(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(32usize, 7u8, val as u64)
         }
     }
     #[inline]
-    pub fn cwb(&self) -> crate::binary::c_types::c_uint {
+    pub fn cwb(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(39usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_cwb(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_cwb(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(39usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn smoothing(&self) -> crate::binary::c_types::c_uint {
+    pub fn smoothing(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(56usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_smoothing(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_smoothing(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(56usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn not_sounding(&self) -> crate::binary::c_types::c_uint {
+    pub fn not_sounding(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(57usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_not_sounding(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_not_sounding(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(57usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn aggregation(&self) -> crate::binary::c_types::c_uint {
+    pub fn aggregation(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(59usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_aggregation(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_aggregation(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(59usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn stbc(&self) -> crate::binary::c_types::c_uint {
+    pub fn stbc(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(60usize, 2u8) as u32) }
     }
     #[inline]
-    pub fn set_stbc(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_stbc(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(60usize, 2u8, val as u64)
         }
     }
     #[inline]
-    pub fn fec_coding(&self) -> crate::binary::c_types::c_uint {
+    pub fn fec_coding(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(62usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_fec_coding(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_fec_coding(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(62usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn sgi(&self) -> crate::binary::c_types::c_uint {
+    pub fn sgi(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(63usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_sgi(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_sgi(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(63usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn ampdu_cnt(&self) -> crate::binary::c_types::c_uint {
+    pub fn ampdu_cnt(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(72usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_ampdu_cnt(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_ampdu_cnt(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(72usize, 8u8, val as u64)
         }
     }
     #[inline]
-    pub fn channel(&self) -> crate::binary::c_types::c_uint {
+    pub fn channel(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(80usize, 4u8) as u32) }
     }
     #[inline]
-    pub fn set_channel(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_channel(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(80usize, 4u8, val as u64)
         }
     }
     #[inline]
-    pub fn secondary_channel(&self) -> crate::binary::c_types::c_uint {
+    pub fn secondary_channel(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(84usize, 4u8) as u32) }
     }
     #[inline]
-    pub fn set_secondary_channel(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_secondary_channel(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(84usize, 4u8, val as u64)
         }
     }
     #[inline]
-    pub fn timestamp(&self) -> crate::binary::c_types::c_uint {
+    pub fn timestamp(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(96usize, 32u8) as u32) }
     }
     #[inline]
-    pub fn set_timestamp(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_timestamp(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(96usize, 32u8, val as u64)
         }
     }
     #[inline]
-    pub fn noise_floor(&self) -> crate::binary::c_types::c_int {
+    pub fn noise_floor(&self) -> crate::c_types::c_int {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(160usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_noise_floor(&mut self, val: crate::binary::c_types::c_int) {
+    pub fn set_noise_floor(&mut self, val: crate::c_types::c_int) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(160usize, 8u8, val as u64)
         }
     }
     #[inline]
-    pub fn ant(&self) -> crate::binary::c_types::c_uint {
+    pub fn ant(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(255usize, 1u8) as u32) }
     }
     #[inline]
-    pub fn set_ant(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_ant(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(255usize, 1u8, val as u64)
         }
     }
     #[inline]
-    pub fn sig_len(&self) -> crate::binary::c_types::c_uint {
+    pub fn sig_len(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(352usize, 12u8) as u32) }
     }
     #[inline]
-    pub fn set_sig_len(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_sig_len(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(352usize, 12u8, val as u64)
         }
     }
     #[inline]
-    pub fn rx_state(&self) -> crate::binary::c_types::c_uint {
+    pub fn rx_state(&self) -> crate::c_types::c_uint {
         unsafe { ::core::mem::transmute(self._bitfield_1.get(376usize, 8u8) as u32) }
     }
     #[inline]
-    pub fn set_rx_state(&mut self, val: crate::binary::c_types::c_uint) {
+    pub fn set_rx_state(&mut self, val: crate::c_types::c_uint) {
         unsafe {
             let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(376usize, 8u8, val as u64)
@@ -3678,7 +3678,7 @@ pub const wifi_promiscuous_pkt_type_t_WIFI_PKT_MISC: wifi_promiscuous_pkt_type_t
 #[doc = ""]
 #[doc = " Passed to promiscuous mode RX callback to indicate the type of parameter in the buffer."]
 #[doc = ""]
-pub type wifi_promiscuous_pkt_type_t = crate::binary::c_types::c_uint;
+pub type wifi_promiscuous_pkt_type_t = crate::c_types::c_uint;
 #[doc = " @brief Mask for filtering different packet types in promiscuous mode."]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -3785,7 +3785,7 @@ pub const wifi_ant_mode_t_WIFI_ANT_MODE_AUTO: wifi_ant_mode_t = 2;
 pub const wifi_ant_mode_t_WIFI_ANT_MODE_MAX: wifi_ant_mode_t = 3;
 #[doc = " @brief WiFi antenna mode"]
 #[doc = ""]
-pub type wifi_ant_mode_t = crate::binary::c_types::c_uint;
+pub type wifi_ant_mode_t = crate::c_types::c_uint;
 #[doc = " @brief WiFi antenna configuration"]
 #[doc = ""]
 #[repr(C)]
@@ -3854,7 +3854,7 @@ pub type wifi_action_rx_cb_t = ::core::option::Option<
         payload: *mut u8,
         len: size_t,
         channel: u8,
-    ) -> crate::binary::c_types::c_int,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief Action Frame Tx Request"]
 #[doc = ""]
@@ -3957,7 +3957,7 @@ pub const wifi_phy_rate_t_WIFI_PHY_RATE_LORA_500K: wifi_phy_rate_t = 42;
 pub const wifi_phy_rate_t_WIFI_PHY_RATE_MAX: wifi_phy_rate_t = 43;
 #[doc = " @brief WiFi PHY rate encodings"]
 #[doc = ""]
-pub type wifi_phy_rate_t = crate::binary::c_types::c_uint;
+pub type wifi_phy_rate_t = crate::c_types::c_uint;
 #[doc = "< ESP32 WiFi ready"]
 pub const wifi_event_t_WIFI_EVENT_WIFI_READY: wifi_event_t = 0;
 #[doc = "< ESP32 finish scanning AP"]
@@ -4005,7 +4005,7 @@ pub const wifi_event_t_WIFI_EVENT_STA_BEACON_TIMEOUT: wifi_event_t = 21;
 #[doc = "< Invalid WiFi event ID"]
 pub const wifi_event_t_WIFI_EVENT_MAX: wifi_event_t = 22;
 #[doc = " WiFi event declarations"]
-pub type wifi_event_t = crate::binary::c_types::c_uint;
+pub type wifi_event_t = crate::c_types::c_uint;
 extern "C" {
     pub static mut WIFI_EVENT: esp_event_base_t;
 }
@@ -4073,7 +4073,7 @@ pub const wifi_event_sta_wps_fail_reason_t_WPS_FAIL_REASON_RECV_M2D:
 pub const wifi_event_sta_wps_fail_reason_t_WPS_FAIL_REASON_MAX: wifi_event_sta_wps_fail_reason_t =
     2;
 #[doc = " Argument structure for WIFI_EVENT_STA_WPS_ER_FAILED event"]
-pub type wifi_event_sta_wps_fail_reason_t = crate::binary::c_types::c_uint;
+pub type wifi_event_sta_wps_fail_reason_t = crate::c_types::c_uint;
 #[doc = " Argument structure for WIFI_EVENT_STA_WPS_ER_SUCCESS event"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -4114,7 +4114,7 @@ pub struct wifi_event_ap_stadisconnected_t {
 #[derive(Copy, Clone)]
 pub struct wifi_event_ap_probe_req_rx_t {
     #[doc = "< Received probe request signal strength"]
-    pub rssi: crate::binary::c_types::c_int,
+    pub rssi: crate::c_types::c_int,
     #[doc = "< MAC address of the station which send probe request"]
     pub mac: [u8; 6usize],
 }
@@ -4137,7 +4137,7 @@ pub const wifi_ftm_status_t_FTM_STATUS_NO_RESPONSE: wifi_ftm_status_t = 3;
 pub const wifi_ftm_status_t_FTM_STATUS_FAIL: wifi_ftm_status_t = 4;
 #[doc = " @brief FTM operation status types"]
 #[doc = ""]
-pub type wifi_ftm_status_t = crate::binary::c_types::c_uint;
+pub type wifi_ftm_status_t = crate::c_types::c_uint;
 #[doc = " Argument structure for"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -4261,7 +4261,7 @@ pub const system_event_id_t_SYSTEM_EVENT_ETH_GOT_IP: system_event_id_t = 30;
 #[doc = "< Number of members in this enum"]
 pub const system_event_id_t_SYSTEM_EVENT_MAX: system_event_id_t = 31;
 #[doc = " System event types enumeration"]
-pub type system_event_id_t = crate::binary::c_types::c_uint;
+pub type system_event_id_t = crate::c_types::c_uint;
 #[doc = " Argument structure for WIFI_EVENT_STA_WPS_ER_FAILED event"]
 pub use self::wifi_event_sta_wps_fail_reason_t as system_event_sta_wps_fail_reason_t;
 #[doc = " Argument structure for WIFI_EVENT_SCAN_DONE event"]
@@ -4325,7 +4325,7 @@ pub type system_event_handler_t = ::core::option::Option<
     unsafe extern "C" fn(
         event_base: esp_event_base_t,
         event_id: i32,
-        event_data: *mut crate::binary::c_types::c_void,
+        event_data: *mut crate::c_types::c_void,
         event_data_size: size_t,
         ticks_to_wait: TickType_t,
     ) -> esp_err_t,
@@ -4361,7 +4361,7 @@ extern "C" {
     pub fn esp_event_send_internal(
         event_base: esp_event_base_t,
         event_id: i32,
-        event_data: *mut crate::binary::c_types::c_void,
+        event_data: *mut crate::c_types::c_void,
         event_data_size: size_t,
         ticks_to_wait: TickType_t,
     ) -> esp_err_t;
@@ -4408,7 +4408,7 @@ extern "C" {
 #[doc = "    - others: fail"]
 pub type system_event_cb_t = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut crate::binary::c_types::c_void,
+        ctx: *mut crate::c_types::c_void,
         event: *mut system_event_t,
     ) -> esp_err_t,
 >;
@@ -4427,7 +4427,7 @@ extern "C" {
     #[doc = "    - others: fail"]
     pub fn esp_event_loop_init(
         cb: system_event_cb_t,
-        ctx: *mut crate::binary::c_types::c_void,
+        ctx: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -4445,7 +4445,7 @@ extern "C" {
     #[doc = " @return old callback"]
     pub fn esp_event_loop_set_cb(
         cb: system_event_cb_t,
-        ctx: *mut crate::binary::c_types::c_void,
+        ctx: *mut crate::c_types::c_void,
     ) -> system_event_cb_t;
 }
 #[doc = " Configuration for creating event loops"]
@@ -4456,7 +4456,7 @@ pub struct esp_event_loop_args_t {
     pub queue_size: i32,
     #[doc = "< name of the event loop task; if NULL,"]
     #[doc = "a dedicated task is not created for event loop"]
-    pub task_name: *const crate::binary::c_types::c_char,
+    pub task_name: *const crate::c_types::c_char,
     #[doc = "< priority of the event loop task, ignored if task name is NULL"]
     pub task_priority: UBaseType_t,
     #[doc = "< stack size of the event loop task, ignored if task name is NULL"]
@@ -4573,7 +4573,7 @@ extern "C" {
         event_base: esp_event_base_t,
         event_id: i32,
         event_handler: esp_event_handler_t,
-        event_handler_arg: *mut crate::binary::c_types::c_void,
+        event_handler_arg: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -4604,7 +4604,7 @@ extern "C" {
         event_base: esp_event_base_t,
         event_id: i32,
         event_handler: esp_event_handler_t,
-        event_handler_arg: *mut crate::binary::c_types::c_void,
+        event_handler_arg: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -4650,7 +4650,7 @@ extern "C" {
         event_base: esp_event_base_t,
         event_id: i32,
         event_handler: esp_event_handler_t,
-        event_handler_arg: *mut crate::binary::c_types::c_void,
+        event_handler_arg: *mut crate::c_types::c_void,
         instance: *mut esp_event_handler_instance_t,
     ) -> esp_err_t;
 }
@@ -4683,7 +4683,7 @@ extern "C" {
         event_base: esp_event_base_t,
         event_id: i32,
         event_handler: esp_event_handler_t,
-        event_handler_arg: *mut crate::binary::c_types::c_void,
+        event_handler_arg: *mut crate::c_types::c_void,
         instance: *mut esp_event_handler_instance_t,
     ) -> esp_err_t;
 }
@@ -4810,7 +4810,7 @@ extern "C" {
     pub fn esp_event_post(
         event_base: esp_event_base_t,
         event_id: i32,
-        event_data: *mut crate::binary::c_types::c_void,
+        event_data: *mut crate::c_types::c_void,
         event_data_size: size_t,
         ticks_to_wait: TickType_t,
     ) -> esp_err_t;
@@ -4840,7 +4840,7 @@ extern "C" {
         event_loop: esp_event_loop_handle_t,
         event_base: esp_event_base_t,
         event_id: i32,
-        event_data: *mut crate::binary::c_types::c_void,
+        event_data: *mut crate::c_types::c_void,
         event_data_size: size_t,
         ticks_to_wait: TickType_t,
     ) -> esp_err_t;
@@ -4896,14 +4896,14 @@ pub const esp_crypto_hash_alg_t_ESP_CRYPTO_HASH_ALG_HMAC_MD5: esp_crypto_hash_al
 pub const esp_crypto_hash_alg_t_ESP_CRYPTO_HASH_ALG_HMAC_SHA1: esp_crypto_hash_alg_t = 3;
 pub const esp_crypto_hash_alg_t_ESP_CRYPTO_HASH_ALG_SHA256: esp_crypto_hash_alg_t = 4;
 pub const esp_crypto_hash_alg_t_ESP_CRYPTO_HASH_ALG_HMAC_SHA256: esp_crypto_hash_alg_t = 5;
-pub type esp_crypto_hash_alg_t = crate::binary::c_types::c_uint;
+pub type esp_crypto_hash_alg_t = crate::c_types::c_uint;
 pub const esp_crypto_cipher_alg_t_ESP_CRYPTO_CIPHER_NULL: esp_crypto_cipher_alg_t = 0;
 pub const esp_crypto_cipher_alg_t_ESP_CRYPTO_CIPHER_ALG_AES: esp_crypto_cipher_alg_t = 1;
 pub const esp_crypto_cipher_alg_t_ESP_CRYPTO_CIPHER_ALG_3DES: esp_crypto_cipher_alg_t = 2;
 pub const esp_crypto_cipher_alg_t_ESP_CRYPTO_CIPHER_ALG_DES: esp_crypto_cipher_alg_t = 3;
 pub const esp_crypto_cipher_alg_t_ESP_CRYPTO_CIPHER_ALG_RC2: esp_crypto_cipher_alg_t = 4;
 pub const esp_crypto_cipher_alg_t_ESP_CRYPTO_CIPHER_ALG_RC4: esp_crypto_cipher_alg_t = 5;
-pub type esp_crypto_cipher_alg_t = crate::binary::c_types::c_uint;
+pub type esp_crypto_cipher_alg_t = crate::c_types::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct crypto_hash {
@@ -4924,11 +4924,11 @@ pub type esp_crypto_cipher_t = crypto_cipher;
 #[doc = " @param data_len  Length of data in bytes (must be divisible by 16)"]
 pub type esp_aes_128_encrypt_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        iv: *const crate::binary::c_types::c_uchar,
-        data: *mut crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        iv: *const crate::c_types::c_uchar,
+        data: *mut crate::c_types::c_uchar,
+        data_len: crate::c_types::c_int,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief The AES callback function when do WPS connect."]
 #[doc = ""]
@@ -4939,11 +4939,11 @@ pub type esp_aes_128_encrypt_t = ::core::option::Option<
 #[doc = ""]
 pub type esp_aes_128_decrypt_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        iv: *const crate::binary::c_types::c_uchar,
-        data: *mut crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        iv: *const crate::c_types::c_uchar,
+        data: *mut crate::c_types::c_uchar,
+        data_len: crate::c_types::c_int,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief The AES callback function when do STA connect."]
 #[doc = ""]
@@ -4954,11 +4954,11 @@ pub type esp_aes_128_decrypt_t = ::core::option::Option<
 #[doc = ""]
 pub type esp_aes_wrap_t = ::core::option::Option<
     unsafe extern "C" fn(
-        kek: *const crate::binary::c_types::c_uchar,
-        n: crate::binary::c_types::c_int,
-        plain: *const crate::binary::c_types::c_uchar,
-        cipher: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        kek: *const crate::c_types::c_uchar,
+        n: crate::c_types::c_int,
+        plain: *const crate::c_types::c_uchar,
+        cipher: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief The AES callback function when do STA connect."]
 #[doc = ""]
@@ -4969,11 +4969,11 @@ pub type esp_aes_wrap_t = ::core::option::Option<
 #[doc = ""]
 pub type esp_aes_unwrap_t = ::core::option::Option<
     unsafe extern "C" fn(
-        kek: *const crate::binary::c_types::c_uchar,
-        n: crate::binary::c_types::c_int,
-        cipher: *const crate::binary::c_types::c_uchar,
-        plain: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        kek: *const crate::c_types::c_uchar,
+        n: crate::c_types::c_int,
+        cipher: *const crate::c_types::c_uchar,
+        plain: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief The SHA256 callback function when do WPS connect."]
 #[doc = ""]
@@ -4986,13 +4986,13 @@ pub type esp_aes_unwrap_t = ::core::option::Option<
 #[doc = ""]
 pub type esp_hmac_sha256_vector_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_int,
-        num_elem: crate::binary::c_types::c_int,
-        addr: *mut *const crate::binary::c_types::c_uchar,
-        len: *const crate::binary::c_types::c_int,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_int,
+        num_elem: crate::c_types::c_int,
+        addr: *mut *const crate::c_types::c_uchar,
+        len: *const crate::c_types::c_int,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief The AES callback function when do STA connect."]
 #[doc = ""]
@@ -5006,14 +5006,14 @@ pub type esp_hmac_sha256_vector_t = ::core::option::Option<
 #[doc = ""]
 pub type esp_sha256_prf_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_int,
-        label: *const crate::binary::c_types::c_char,
-        data: *const crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_int,
-        buf: *mut crate::binary::c_types::c_uchar,
-        buf_len: crate::binary::c_types::c_int,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_int,
+        label: *const crate::c_types::c_char,
+        data: *const crate::c_types::c_uchar,
+        data_len: crate::c_types::c_int,
+        buf: *mut crate::c_types::c_uchar,
+        buf_len: crate::c_types::c_int,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief HMAC-MD5 over data buffer (RFC 2104)'"]
 #[doc = ""]
@@ -5025,12 +5025,12 @@ pub type esp_sha256_prf_t = ::core::option::Option<
 #[doc = " Returns: 0 on success, -1 on failure"]
 pub type esp_hmac_md5_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_uint,
-        data: *const crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_uint,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_uint,
+        data: *const crate::c_types::c_uchar,
+        data_len: crate::c_types::c_uint,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief HMAC-MD5 over data vector (RFC 2104)"]
 #[doc = ""]
@@ -5043,13 +5043,13 @@ pub type esp_hmac_md5_t = ::core::option::Option<
 #[doc = " Returns: 0 on success, -1 on failure"]
 pub type esp_hmac_md5_vector_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_uint,
-        num_elem: crate::binary::c_types::c_uint,
-        addr: *mut *const crate::binary::c_types::c_uchar,
-        len: *const crate::binary::c_types::c_uint,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_uint,
+        num_elem: crate::c_types::c_uint,
+        addr: *mut *const crate::c_types::c_uchar,
+        len: *const crate::c_types::c_uint,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief HMAC-SHA1 over data buffer (RFC 2104)"]
 #[doc = ""]
@@ -5061,12 +5061,12 @@ pub type esp_hmac_md5_vector_t = ::core::option::Option<
 #[doc = " Returns: 0 on success, -1 of failure"]
 pub type esp_hmac_sha1_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_uint,
-        data: *const crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_uint,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_uint,
+        data: *const crate::c_types::c_uchar,
+        data_len: crate::c_types::c_uint,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief HMAC-SHA1 over data vector (RFC 2104)"]
 #[doc = ""]
@@ -5079,13 +5079,13 @@ pub type esp_hmac_sha1_t = ::core::option::Option<
 #[doc = " Returns: 0 on success, -1 on failure"]
 pub type esp_hmac_sha1_vector_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_uint,
-        num_elem: crate::binary::c_types::c_uint,
-        addr: *mut *const crate::binary::c_types::c_uchar,
-        len: *const crate::binary::c_types::c_uint,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_uint,
+        num_elem: crate::c_types::c_uint,
+        addr: *mut *const crate::c_types::c_uchar,
+        len: *const crate::c_types::c_uint,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief SHA1-based Pseudo-Random Function (PRF) (IEEE 802.11i, 8.5.1.1)"]
 #[doc = ""]
@@ -5102,14 +5102,14 @@ pub type esp_hmac_sha1_vector_t = ::core::option::Option<
 #[doc = " given key (e.g., PMK in IEEE 802.11i)."]
 pub type esp_sha1_prf_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        key_len: crate::binary::c_types::c_uint,
-        label: *const crate::binary::c_types::c_char,
-        data: *const crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_uint,
-        buf: *mut crate::binary::c_types::c_uchar,
-        buf_len: crate::binary::c_types::c_uint,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        key_len: crate::c_types::c_uint,
+        label: *const crate::c_types::c_char,
+        data: *const crate::c_types::c_uchar,
+        data_len: crate::c_types::c_uint,
+        buf: *mut crate::c_types::c_uchar,
+        buf_len: crate::c_types::c_uint,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief SHA-1 hash for data vector"]
 #[doc = ""]
@@ -5120,11 +5120,11 @@ pub type esp_sha1_prf_t = ::core::option::Option<
 #[doc = " Returns: 0 on success, -1 on failure"]
 pub type esp_sha1_vector_t = ::core::option::Option<
     unsafe extern "C" fn(
-        num_elem: crate::binary::c_types::c_uint,
-        addr: *mut *const crate::binary::c_types::c_uchar,
-        len: *const crate::binary::c_types::c_uint,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        num_elem: crate::c_types::c_uint,
+        addr: *mut *const crate::c_types::c_uchar,
+        len: *const crate::c_types::c_uint,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief SHA1-based key derivation function (PBKDF2) for IEEE 802.11i"]
 #[doc = ""]
@@ -5141,13 +5141,13 @@ pub type esp_sha1_vector_t = ::core::option::Option<
 #[doc = " IEEE Std 802.11-2004, Clause H.4. The main construction is from PKCS#5 v2.0."]
 pub type esp_pbkdf2_sha1_t = ::core::option::Option<
     unsafe extern "C" fn(
-        passphrase: *const crate::binary::c_types::c_char,
-        ssid: *const crate::binary::c_types::c_char,
-        ssid_len: crate::binary::c_types::c_uint,
-        iterations: crate::binary::c_types::c_int,
-        buf: *mut crate::binary::c_types::c_uchar,
-        buflen: crate::binary::c_types::c_uint,
-    ) -> crate::binary::c_types::c_int,
+        passphrase: *const crate::c_types::c_char,
+        ssid: *const crate::c_types::c_char,
+        ssid_len: crate::c_types::c_uint,
+        iterations: crate::c_types::c_int,
+        buf: *mut crate::c_types::c_uchar,
+        buflen: crate::c_types::c_uint,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief XOR RC4 stream to given data with skip-stream-start"]
 #[doc = ""]
@@ -5163,12 +5163,12 @@ pub type esp_pbkdf2_sha1_t = ::core::option::Option<
 #[doc = " encryption/decryption."]
 pub type esp_rc4_skip_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        keylen: crate::binary::c_types::c_uint,
-        skip: crate::binary::c_types::c_uint,
-        data: *mut crate::binary::c_types::c_uchar,
-        data_len: crate::binary::c_types::c_uint,
-    ) -> crate::binary::c_types::c_int,
+        key: *const crate::c_types::c_uchar,
+        keylen: crate::c_types::c_uint,
+        skip: crate::c_types::c_uint,
+        data: *mut crate::c_types::c_uchar,
+        data_len: crate::c_types::c_uint,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief MD5 hash for data vector"]
 #[doc = ""]
@@ -5179,11 +5179,11 @@ pub type esp_rc4_skip_t = ::core::option::Option<
 #[doc = " Returns: 0 on success, -1 on failure"]
 pub type esp_md5_vector_t = ::core::option::Option<
     unsafe extern "C" fn(
-        num_elem: crate::binary::c_types::c_uint,
-        addr: *mut *const crate::binary::c_types::c_uchar,
-        len: *const crate::binary::c_types::c_uint,
-        mac: *mut crate::binary::c_types::c_uchar,
-    ) -> crate::binary::c_types::c_int,
+        num_elem: crate::c_types::c_uint,
+        addr: *mut *const crate::c_types::c_uchar,
+        len: *const crate::c_types::c_uint,
+        mac: *mut crate::c_types::c_uchar,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief Encrypt one AES block"]
 #[doc = ""]
@@ -5192,9 +5192,9 @@ pub type esp_md5_vector_t = ::core::option::Option<
 #[doc = " @crypt: Buffer for the encrypted data (16 bytes)"]
 pub type esp_aes_encrypt_t = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut crate::binary::c_types::c_void,
-        plain: *const crate::binary::c_types::c_uchar,
-        crypt: *mut crate::binary::c_types::c_uchar,
+        ctx: *mut crate::c_types::c_void,
+        plain: *const crate::c_types::c_uchar,
+        crypt: *mut crate::c_types::c_uchar,
     ),
 >;
 #[doc = " @brief Initialize AES for encryption"]
@@ -5204,15 +5204,15 @@ pub type esp_aes_encrypt_t = ::core::option::Option<
 #[doc = " Returns: Pointer to context data or %NULL on failure"]
 pub type esp_aes_encrypt_init_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        len: crate::binary::c_types::c_uint,
-    ) -> *mut crate::binary::c_types::c_void,
+        key: *const crate::c_types::c_uchar,
+        len: crate::c_types::c_uint,
+    ) -> *mut crate::c_types::c_void,
 >;
 #[doc = " @brief Deinitialize AES encryption"]
 #[doc = ""]
 #[doc = " @ctx: Context pointer from aes_encrypt_init()"]
 pub type esp_aes_encrypt_deinit_t =
-    ::core::option::Option<unsafe extern "C" fn(ctx: *mut crate::binary::c_types::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(ctx: *mut crate::c_types::c_void)>;
 #[doc = " @brief Decrypt one AES block"]
 #[doc = ""]
 #[doc = " @ctx: Context pointer from aes_encrypt_init()"]
@@ -5220,9 +5220,9 @@ pub type esp_aes_encrypt_deinit_t =
 #[doc = " @plain: Buffer for the decrypted data (16 bytes)"]
 pub type esp_aes_decrypt_t = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut crate::binary::c_types::c_void,
-        crypt: *const crate::binary::c_types::c_uchar,
-        plain: *mut crate::binary::c_types::c_uchar,
+        ctx: *mut crate::c_types::c_void,
+        crypt: *const crate::c_types::c_uchar,
+        plain: *mut crate::c_types::c_uchar,
     ),
 >;
 #[doc = " @brief Initialize AES for decryption"]
@@ -5232,15 +5232,15 @@ pub type esp_aes_decrypt_t = ::core::option::Option<
 #[doc = " Returns: Pointer to context data or %NULL on failure"]
 pub type esp_aes_decrypt_init_t = ::core::option::Option<
     unsafe extern "C" fn(
-        key: *const crate::binary::c_types::c_uchar,
-        len: crate::binary::c_types::c_uint,
-    ) -> *mut crate::binary::c_types::c_void,
+        key: *const crate::c_types::c_uchar,
+        len: crate::c_types::c_uint,
+    ) -> *mut crate::c_types::c_void,
 >;
 #[doc = " @brief Deinitialize AES decryption"]
 #[doc = ""]
 #[doc = " @ctx: Context pointer from aes_encrypt_init()"]
 pub type esp_aes_decrypt_deinit_t =
-    ::core::option::Option<unsafe extern "C" fn(ctx: *mut crate::binary::c_types::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(ctx: *mut crate::c_types::c_void)>;
 #[doc = " @brief One-Key CBC MAC (OMAC1) hash with AES-128 for MIC computation"]
 #[doc = ""]
 #[doc = " @key: 128-bit key for the hash operation"]
@@ -5254,7 +5254,7 @@ pub type esp_omac1_aes_128_t = ::core::option::Option<
         data: *const u8,
         data_len: size_t,
         mic: *mut u8,
-    ) -> crate::binary::c_types::c_int,
+    ) -> crate::c_types::c_int,
 >;
 #[doc = " @brief Decrypt data using CCMP (Counter Mode CBC-MAC Protocol OR"]
 #[doc = "        Counter Mode Cipher Block Chaining Message Authentication"]
@@ -5293,7 +5293,7 @@ pub type esp_ccmp_encrypt_t = ::core::option::Option<
         len: size_t,
         hdrlen: size_t,
         pn: *mut u8,
-        keyid: crate::binary::c_types::c_int,
+        keyid: crate::c_types::c_int,
         encrypted_len: *mut size_t,
     ) -> *mut u8,
 >;
@@ -5357,154 +5357,154 @@ pub struct wifi_osi_funcs_t {
     pub _set_isr: ::core::option::Option<
         unsafe extern "C" fn(
             n: i32,
-            f: *mut crate::binary::c_types::c_void,
-            arg: *mut crate::binary::c_types::c_void,
+            f: *mut crate::c_types::c_void,
+            arg: *mut crate::c_types::c_void,
         ),
     >,
     pub _ints_on: ::core::option::Option<unsafe extern "C" fn(mask: u32)>,
     pub _ints_off: ::core::option::Option<unsafe extern "C" fn(mask: u32)>,
     pub _is_from_isr: ::core::option::Option<unsafe extern "C" fn() -> bool>,
     pub _spin_lock_create:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _spin_lock_delete:
-        ::core::option::Option<unsafe extern "C" fn(lock: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(lock: *mut crate::c_types::c_void)>,
     pub _wifi_int_disable: ::core::option::Option<
-        unsafe extern "C" fn(wifi_int_mux: *mut crate::binary::c_types::c_void) -> u32,
+        unsafe extern "C" fn(wifi_int_mux: *mut crate::c_types::c_void) -> u32,
     >,
     pub _wifi_int_restore: ::core::option::Option<
-        unsafe extern "C" fn(wifi_int_mux: *mut crate::binary::c_types::c_void, tmp: u32),
+        unsafe extern "C" fn(wifi_int_mux: *mut crate::c_types::c_void, tmp: u32),
     >,
     pub _task_yield_from_isr: ::core::option::Option<unsafe extern "C" fn()>,
     pub _semphr_create: ::core::option::Option<
-        unsafe extern "C" fn(max: u32, init: u32) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(max: u32, init: u32) -> *mut crate::c_types::c_void,
     >,
     pub _semphr_delete:
-        ::core::option::Option<unsafe extern "C" fn(semphr: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(semphr: *mut crate::c_types::c_void)>,
     pub _semphr_take: ::core::option::Option<
         unsafe extern "C" fn(
-            semphr: *mut crate::binary::c_types::c_void,
+            semphr: *mut crate::c_types::c_void,
             block_time_tick: u32,
         ) -> i32,
     >,
     pub _semphr_give: ::core::option::Option<
-        unsafe extern "C" fn(semphr: *mut crate::binary::c_types::c_void) -> i32,
+        unsafe extern "C" fn(semphr: *mut crate::c_types::c_void) -> i32,
     >,
     pub _wifi_thread_semphr_get:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _mutex_create:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _recursive_mutex_create:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _mutex_delete:
-        ::core::option::Option<unsafe extern "C" fn(mutex: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(mutex: *mut crate::c_types::c_void)>,
     pub _mutex_lock: ::core::option::Option<
-        unsafe extern "C" fn(mutex: *mut crate::binary::c_types::c_void) -> i32,
+        unsafe extern "C" fn(mutex: *mut crate::c_types::c_void) -> i32,
     >,
     pub _mutex_unlock: ::core::option::Option<
-        unsafe extern "C" fn(mutex: *mut crate::binary::c_types::c_void) -> i32,
+        unsafe extern "C" fn(mutex: *mut crate::c_types::c_void) -> i32,
     >,
     pub _queue_create: ::core::option::Option<
-        unsafe extern "C" fn(queue_len: u32, item_size: u32) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(queue_len: u32, item_size: u32) -> *mut crate::c_types::c_void,
     >,
     pub _queue_delete:
-        ::core::option::Option<unsafe extern "C" fn(queue: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(queue: *mut crate::c_types::c_void)>,
     pub _queue_send: ::core::option::Option<
         unsafe extern "C" fn(
-            queue: *mut crate::binary::c_types::c_void,
-            item: *mut crate::binary::c_types::c_void,
+            queue: *mut crate::c_types::c_void,
+            item: *mut crate::c_types::c_void,
             block_time_tick: u32,
         ) -> i32,
     >,
     pub _queue_send_from_isr: ::core::option::Option<
         unsafe extern "C" fn(
-            queue: *mut crate::binary::c_types::c_void,
-            item: *mut crate::binary::c_types::c_void,
-            hptw: *mut crate::binary::c_types::c_void,
+            queue: *mut crate::c_types::c_void,
+            item: *mut crate::c_types::c_void,
+            hptw: *mut crate::c_types::c_void,
         ) -> i32,
     >,
     pub _queue_send_to_back: ::core::option::Option<
         unsafe extern "C" fn(
-            queue: *mut crate::binary::c_types::c_void,
-            item: *mut crate::binary::c_types::c_void,
+            queue: *mut crate::c_types::c_void,
+            item: *mut crate::c_types::c_void,
             block_time_tick: u32,
         ) -> i32,
     >,
     pub _queue_send_to_front: ::core::option::Option<
         unsafe extern "C" fn(
-            queue: *mut crate::binary::c_types::c_void,
-            item: *mut crate::binary::c_types::c_void,
+            queue: *mut crate::c_types::c_void,
+            item: *mut crate::c_types::c_void,
             block_time_tick: u32,
         ) -> i32,
     >,
     pub _queue_recv: ::core::option::Option<
         unsafe extern "C" fn(
-            queue: *mut crate::binary::c_types::c_void,
-            item: *mut crate::binary::c_types::c_void,
+            queue: *mut crate::c_types::c_void,
+            item: *mut crate::c_types::c_void,
             block_time_tick: u32,
         ) -> i32,
     >,
     pub _queue_msg_waiting: ::core::option::Option<
-        unsafe extern "C" fn(queue: *mut crate::binary::c_types::c_void) -> u32,
+        unsafe extern "C" fn(queue: *mut crate::c_types::c_void) -> u32,
     >,
     pub _event_group_create:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _event_group_delete:
-        ::core::option::Option<unsafe extern "C" fn(event: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(event: *mut crate::c_types::c_void)>,
     pub _event_group_set_bits: ::core::option::Option<
-        unsafe extern "C" fn(event: *mut crate::binary::c_types::c_void, bits: u32) -> u32,
+        unsafe extern "C" fn(event: *mut crate::c_types::c_void, bits: u32) -> u32,
     >,
     pub _event_group_clear_bits: ::core::option::Option<
-        unsafe extern "C" fn(event: *mut crate::binary::c_types::c_void, bits: u32) -> u32,
+        unsafe extern "C" fn(event: *mut crate::c_types::c_void, bits: u32) -> u32,
     >,
     pub _event_group_wait_bits: ::core::option::Option<
         unsafe extern "C" fn(
-            event: *mut crate::binary::c_types::c_void,
+            event: *mut crate::c_types::c_void,
             bits_to_wait_for: u32,
-            clear_on_exit: crate::binary::c_types::c_int,
-            wait_for_all_bits: crate::binary::c_types::c_int,
+            clear_on_exit: crate::c_types::c_int,
+            wait_for_all_bits: crate::c_types::c_int,
             block_time_tick: u32,
         ) -> u32,
     >,
     pub _task_create_pinned_to_core: ::core::option::Option<
         unsafe extern "C" fn(
-            task_func: *mut crate::binary::c_types::c_void,
-            name: *const crate::binary::c_types::c_char,
+            task_func: *mut crate::c_types::c_void,
+            name: *const crate::c_types::c_char,
             stack_depth: u32,
-            param: *mut crate::binary::c_types::c_void,
+            param: *mut crate::c_types::c_void,
             prio: u32,
-            task_handle: *mut crate::binary::c_types::c_void,
+            task_handle: *mut crate::c_types::c_void,
             core_id: u32,
         ) -> i32,
     >,
     pub _task_create: ::core::option::Option<
         unsafe extern "C" fn(
-            task_func: *mut crate::binary::c_types::c_void,
-            name: *const crate::binary::c_types::c_char,
+            task_func: *mut crate::c_types::c_void,
+            name: *const crate::c_types::c_char,
             stack_depth: u32,
-            param: *mut crate::binary::c_types::c_void,
+            param: *mut crate::c_types::c_void,
             prio: u32,
-            task_handle: *mut crate::binary::c_types::c_void,
+            task_handle: *mut crate::c_types::c_void,
         ) -> i32,
     >,
     pub _task_delete: ::core::option::Option<
-        unsafe extern "C" fn(task_handle: *mut crate::binary::c_types::c_void),
+        unsafe extern "C" fn(task_handle: *mut crate::c_types::c_void),
     >,
     pub _task_delay: ::core::option::Option<unsafe extern "C" fn(tick: u32)>,
     pub _task_ms_to_tick: ::core::option::Option<unsafe extern "C" fn(ms: u32) -> i32>,
     pub _task_get_current_task:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _task_get_max_priority: ::core::option::Option<unsafe extern "C" fn() -> i32>,
     pub _malloc: ::core::option::Option<
         unsafe extern "C" fn(
-            size: crate::binary::c_types::c_uint,
-        ) -> *mut crate::binary::c_types::c_void,
+            size: crate::c_types::c_uint,
+        ) -> *mut crate::c_types::c_void,
     >,
-    pub _free: ::core::option::Option<unsafe extern "C" fn(p: *mut crate::binary::c_types::c_void)>,
+    pub _free: ::core::option::Option<unsafe extern "C" fn(p: *mut crate::c_types::c_void)>,
     pub _event_post: ::core::option::Option<
         unsafe extern "C" fn(
-            event_base: *const crate::binary::c_types::c_char,
+            event_base: *const crate::c_types::c_char,
             event_id: i32,
-            event_data: *mut crate::binary::c_types::c_void,
+            event_data: *mut crate::c_types::c_void,
             event_data_size: size_t,
             ticks_to_wait: u32,
         ) -> i32,
@@ -5519,28 +5519,28 @@ pub struct wifi_osi_funcs_t {
     pub _phy_enable: ::core::option::Option<unsafe extern "C" fn()>,
     pub _phy_update_country_info: ::core::option::Option<
         unsafe extern "C" fn(
-            country: *const crate::binary::c_types::c_char,
-        ) -> crate::binary::c_types::c_int,
+            country: *const crate::c_types::c_char,
+        ) -> crate::c_types::c_int,
     >,
     pub _read_mac: ::core::option::Option<
-        unsafe extern "C" fn(mac: *mut u8, type_: u32) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(mac: *mut u8, type_: u32) -> crate::c_types::c_int,
     >,
     pub _timer_arm: ::core::option::Option<
-        unsafe extern "C" fn(timer: *mut crate::binary::c_types::c_void, tmout: u32, repeat: bool),
+        unsafe extern "C" fn(timer: *mut crate::c_types::c_void, tmout: u32, repeat: bool),
     >,
     pub _timer_disarm:
-        ::core::option::Option<unsafe extern "C" fn(timer: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(timer: *mut crate::c_types::c_void)>,
     pub _timer_done:
-        ::core::option::Option<unsafe extern "C" fn(ptimer: *mut crate::binary::c_types::c_void)>,
+        ::core::option::Option<unsafe extern "C" fn(ptimer: *mut crate::c_types::c_void)>,
     pub _timer_setfn: ::core::option::Option<
         unsafe extern "C" fn(
-            ptimer: *mut crate::binary::c_types::c_void,
-            pfunction: *mut crate::binary::c_types::c_void,
-            parg: *mut crate::binary::c_types::c_void,
+            ptimer: *mut crate::c_types::c_void,
+            pfunction: *mut crate::c_types::c_void,
+            parg: *mut crate::c_types::c_void,
         ),
     >,
     pub _timer_arm_us: ::core::option::Option<
-        unsafe extern "C" fn(ptimer: *mut crate::binary::c_types::c_void, us: u32, repeat: bool),
+        unsafe extern "C" fn(ptimer: *mut crate::c_types::c_void, us: u32, repeat: bool),
     >,
     pub _wifi_reset_mac: ::core::option::Option<unsafe extern "C" fn()>,
     pub _wifi_clock_enable: ::core::option::Option<unsafe extern "C" fn()>,
@@ -5551,146 +5551,146 @@ pub struct wifi_osi_funcs_t {
     pub _nvs_set_i8: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
+            key: *const crate::c_types::c_char,
             value: i8,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_get_i8: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
+            key: *const crate::c_types::c_char,
             out_value: *mut i8,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_set_u8: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
+            key: *const crate::c_types::c_char,
             value: u8,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_get_u8: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
+            key: *const crate::c_types::c_char,
             out_value: *mut u8,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_set_u16: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
+            key: *const crate::c_types::c_char,
             value: u16,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_get_u16: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
+            key: *const crate::c_types::c_char,
             out_value: *mut u16,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_open: ::core::option::Option<
         unsafe extern "C" fn(
-            name: *const crate::binary::c_types::c_char,
+            name: *const crate::c_types::c_char,
             open_mode: u32,
             out_handle: *mut u32,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_close: ::core::option::Option<unsafe extern "C" fn(handle: u32)>,
     pub _nvs_commit:
-        ::core::option::Option<unsafe extern "C" fn(handle: u32) -> crate::binary::c_types::c_int>,
+        ::core::option::Option<unsafe extern "C" fn(handle: u32) -> crate::c_types::c_int>,
     pub _nvs_set_blob: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
-            value: *const crate::binary::c_types::c_void,
+            key: *const crate::c_types::c_char,
+            value: *const crate::c_types::c_void,
             length: size_t,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_get_blob: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
-            out_value: *mut crate::binary::c_types::c_void,
+            key: *const crate::c_types::c_char,
+            out_value: *mut crate::c_types::c_void,
             length: *mut size_t,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _nvs_erase_key: ::core::option::Option<
         unsafe extern "C" fn(
             handle: u32,
-            key: *const crate::binary::c_types::c_char,
-        ) -> crate::binary::c_types::c_int,
+            key: *const crate::c_types::c_char,
+        ) -> crate::c_types::c_int,
     >,
     pub _get_random: ::core::option::Option<
-        unsafe extern "C" fn(buf: *mut u8, len: size_t) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(buf: *mut u8, len: size_t) -> crate::c_types::c_int,
     >,
     pub _get_time: ::core::option::Option<
         unsafe extern "C" fn(
-            t: *mut crate::binary::c_types::c_void,
-        ) -> crate::binary::c_types::c_int,
+            t: *mut crate::c_types::c_void,
+        ) -> crate::c_types::c_int,
     >,
-    pub _random: ::core::option::Option<unsafe extern "C" fn() -> crate::binary::c_types::c_ulong>,
+    pub _random: ::core::option::Option<unsafe extern "C" fn() -> crate::c_types::c_ulong>,
     pub _slowclk_cal_get: ::core::option::Option<unsafe extern "C" fn() -> u32>,
     pub _log_write: ::core::option::Option<
         unsafe extern "C" fn(
             level: u32,
-            tag: *const crate::binary::c_types::c_char,
-            format: *const crate::binary::c_types::c_char,
+            tag: *const crate::c_types::c_char,
+            format: *const crate::c_types::c_char,
             ...
         ),
     >,
     pub _log_writev: ::core::option::Option<
         unsafe extern "C" fn(
             level: u32,
-            tag: *const crate::binary::c_types::c_char,
-            format: *const crate::binary::c_types::c_char,
+            tag: *const crate::c_types::c_char,
+            format: *const crate::c_types::c_char,
             args: va_list,
         ),
     >,
     pub _log_timestamp: ::core::option::Option<unsafe extern "C" fn() -> u32>,
     pub _malloc_internal: ::core::option::Option<
-        unsafe extern "C" fn(size: size_t) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(size: size_t) -> *mut crate::c_types::c_void,
     >,
     pub _realloc_internal: ::core::option::Option<
         unsafe extern "C" fn(
-            ptr: *mut crate::binary::c_types::c_void,
+            ptr: *mut crate::c_types::c_void,
             size: size_t,
-        ) -> *mut crate::binary::c_types::c_void,
+        ) -> *mut crate::c_types::c_void,
     >,
     pub _calloc_internal: ::core::option::Option<
-        unsafe extern "C" fn(n: size_t, size: size_t) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(n: size_t, size: size_t) -> *mut crate::c_types::c_void,
     >,
     pub _zalloc_internal: ::core::option::Option<
-        unsafe extern "C" fn(size: size_t) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(size: size_t) -> *mut crate::c_types::c_void,
     >,
     pub _wifi_malloc: ::core::option::Option<
-        unsafe extern "C" fn(size: size_t) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(size: size_t) -> *mut crate::c_types::c_void,
     >,
     pub _wifi_realloc: ::core::option::Option<
         unsafe extern "C" fn(
-            ptr: *mut crate::binary::c_types::c_void,
+            ptr: *mut crate::c_types::c_void,
             size: size_t,
-        ) -> *mut crate::binary::c_types::c_void,
+        ) -> *mut crate::c_types::c_void,
     >,
     pub _wifi_calloc: ::core::option::Option<
-        unsafe extern "C" fn(n: size_t, size: size_t) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(n: size_t, size: size_t) -> *mut crate::c_types::c_void,
     >,
     pub _wifi_zalloc: ::core::option::Option<
-        unsafe extern "C" fn(size: size_t) -> *mut crate::binary::c_types::c_void,
+        unsafe extern "C" fn(size: size_t) -> *mut crate::c_types::c_void,
     >,
     pub _wifi_create_queue: ::core::option::Option<
         unsafe extern "C" fn(
-            queue_len: crate::binary::c_types::c_int,
-            item_size: crate::binary::c_types::c_int,
-        ) -> *mut crate::binary::c_types::c_void,
+            queue_len: crate::c_types::c_int,
+            item_size: crate::c_types::c_int,
+        ) -> *mut crate::c_types::c_void,
     >,
     pub _wifi_delete_queue:
-        ::core::option::Option<unsafe extern "C" fn(queue: *mut crate::binary::c_types::c_void)>,
-    pub _coex_init: ::core::option::Option<unsafe extern "C" fn() -> crate::binary::c_types::c_int>,
+        ::core::option::Option<unsafe extern "C" fn(queue: *mut crate::c_types::c_void)>,
+    pub _coex_init: ::core::option::Option<unsafe extern "C" fn() -> crate::c_types::c_int>,
     pub _coex_deinit: ::core::option::Option<unsafe extern "C" fn()>,
     pub _coex_enable:
-        ::core::option::Option<unsafe extern "C" fn() -> crate::binary::c_types::c_int>,
+        ::core::option::Option<unsafe extern "C" fn() -> crate::c_types::c_int>,
     pub _coex_disable: ::core::option::Option<unsafe extern "C" fn()>,
     pub _coex_status_get: ::core::option::Option<unsafe extern "C" fn() -> u32>,
     pub _coex_condition_set:
@@ -5700,35 +5700,35 @@ pub struct wifi_osi_funcs_t {
             event: u32,
             latency: u32,
             duration: u32,
-        ) -> crate::binary::c_types::c_int,
+        ) -> crate::c_types::c_int,
     >,
     pub _coex_wifi_release:
-        ::core::option::Option<unsafe extern "C" fn(event: u32) -> crate::binary::c_types::c_int>,
+        ::core::option::Option<unsafe extern "C" fn(event: u32) -> crate::c_types::c_int>,
     pub _coex_wifi_channel_set: ::core::option::Option<
-        unsafe extern "C" fn(primary: u8, secondary: u8) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(primary: u8, secondary: u8) -> crate::c_types::c_int,
     >,
     pub _coex_event_duration_get: ::core::option::Option<
-        unsafe extern "C" fn(event: u32, duration: *mut u32) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(event: u32, duration: *mut u32) -> crate::c_types::c_int,
     >,
     pub _coex_pti_get: ::core::option::Option<
-        unsafe extern "C" fn(event: u32, pti: *mut u8) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(event: u32, pti: *mut u8) -> crate::c_types::c_int,
     >,
     pub _coex_schm_status_bit_clear:
         ::core::option::Option<unsafe extern "C" fn(type_: u32, status: u32)>,
     pub _coex_schm_status_bit_set:
         ::core::option::Option<unsafe extern "C" fn(type_: u32, status: u32)>,
     pub _coex_schm_interval_set: ::core::option::Option<
-        unsafe extern "C" fn(interval: u32) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(interval: u32) -> crate::c_types::c_int,
     >,
     pub _coex_schm_interval_get: ::core::option::Option<unsafe extern "C" fn() -> u32>,
     pub _coex_schm_curr_period_get: ::core::option::Option<unsafe extern "C" fn() -> u8>,
     pub _coex_schm_curr_phase_get:
-        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::binary::c_types::c_void>,
+        ::core::option::Option<unsafe extern "C" fn() -> *mut crate::c_types::c_void>,
     pub _coex_schm_curr_phase_idx_set: ::core::option::Option<
-        unsafe extern "C" fn(idx: crate::binary::c_types::c_int) -> crate::binary::c_types::c_int,
+        unsafe extern "C" fn(idx: crate::c_types::c_int) -> crate::c_types::c_int,
     >,
     pub _coex_schm_curr_phase_idx_get:
-        ::core::option::Option<unsafe extern "C" fn() -> crate::binary::c_types::c_int>,
+        ::core::option::Option<unsafe extern "C" fn() -> crate::c_types::c_int>,
     pub _magic: i32,
 }
 extern "C" {
@@ -5776,7 +5776,7 @@ extern "C" {
     #[doc = " @return"]
     #[doc = "  - ESP_OK on success, error returned from esp_event_handler_register if failed"]
     pub fn esp_wifi_clear_default_wifi_driver_and_handlers(
-        esp_netif: *mut crate::binary::c_types::c_void,
+        esp_netif: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -5836,43 +5836,43 @@ pub struct wifi_init_config_t {
     #[doc = "< WiFi station crypto functions when connect"]
     pub wpa_crypto_funcs: wpa_crypto_funcs_t,
     #[doc = "< WiFi static RX buffer number"]
-    pub static_rx_buf_num: crate::binary::c_types::c_int,
+    pub static_rx_buf_num: crate::c_types::c_int,
     #[doc = "< WiFi dynamic RX buffer number"]
-    pub dynamic_rx_buf_num: crate::binary::c_types::c_int,
+    pub dynamic_rx_buf_num: crate::c_types::c_int,
     #[doc = "< WiFi TX buffer type"]
-    pub tx_buf_type: crate::binary::c_types::c_int,
+    pub tx_buf_type: crate::c_types::c_int,
     #[doc = "< WiFi static TX buffer number"]
-    pub static_tx_buf_num: crate::binary::c_types::c_int,
+    pub static_tx_buf_num: crate::c_types::c_int,
     #[doc = "< WiFi dynamic TX buffer number"]
-    pub dynamic_tx_buf_num: crate::binary::c_types::c_int,
+    pub dynamic_tx_buf_num: crate::c_types::c_int,
     #[doc = "< WiFi TX cache buffer number"]
-    pub cache_tx_buf_num: crate::binary::c_types::c_int,
+    pub cache_tx_buf_num: crate::c_types::c_int,
     #[doc = "< WiFi channel state information enable flag"]
-    pub csi_enable: crate::binary::c_types::c_int,
+    pub csi_enable: crate::c_types::c_int,
     #[doc = "< WiFi AMPDU RX feature enable flag"]
-    pub ampdu_rx_enable: crate::binary::c_types::c_int,
+    pub ampdu_rx_enable: crate::c_types::c_int,
     #[doc = "< WiFi AMPDU TX feature enable flag"]
-    pub ampdu_tx_enable: crate::binary::c_types::c_int,
+    pub ampdu_tx_enable: crate::c_types::c_int,
     #[doc = "< WiFi AMSDU TX feature enable flag"]
-    pub amsdu_tx_enable: crate::binary::c_types::c_int,
+    pub amsdu_tx_enable: crate::c_types::c_int,
     #[doc = "< WiFi NVS flash enable flag"]
-    pub nvs_enable: crate::binary::c_types::c_int,
+    pub nvs_enable: crate::c_types::c_int,
     #[doc = "< Nano option for printf/scan family enable flag"]
-    pub nano_enable: crate::binary::c_types::c_int,
+    pub nano_enable: crate::c_types::c_int,
     #[doc = "< WiFi Block Ack RX window size"]
-    pub rx_ba_win: crate::binary::c_types::c_int,
+    pub rx_ba_win: crate::c_types::c_int,
     #[doc = "< WiFi Task Core ID"]
-    pub wifi_task_core_id: crate::binary::c_types::c_int,
+    pub wifi_task_core_id: crate::c_types::c_int,
     #[doc = "< WiFi softAP maximum length of the beacon"]
-    pub beacon_max_len: crate::binary::c_types::c_int,
+    pub beacon_max_len: crate::c_types::c_int,
     #[doc = "< WiFi management short buffer number, the minimum value is 6, the maximum value is 32"]
-    pub mgmt_sbuf_num: crate::binary::c_types::c_int,
+    pub mgmt_sbuf_num: crate::c_types::c_int,
     #[doc = "< Enables additional WiFi features and capabilities"]
     pub feature_caps: u64,
     #[doc = "< WiFi Power Management for station at disconnected status"]
     pub sta_disconnected_pm: bool,
     #[doc = "< WiFi init magic number, it should be the last field"]
-    pub magic: crate::binary::c_types::c_int,
+    pub magic: crate::c_types::c_int,
 }
 extern "C" {
     #[doc = " @addtogroup WPA_APIs"]
@@ -6304,7 +6304,7 @@ extern "C" {
 #[doc = ""]
 pub type wifi_promiscuous_cb_t = ::core::option::Option<
     unsafe extern "C" fn(
-        buf: *mut crate::binary::c_types::c_void,
+        buf: *mut crate::c_types::c_void,
         type_: wifi_promiscuous_pkt_type_t,
     ),
 >;
@@ -6480,11 +6480,11 @@ extern "C" {
 #[doc = " @param     rssi Received signal strength indication."]
 pub type esp_vendor_ie_cb_t = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut crate::binary::c_types::c_void,
+        ctx: *mut crate::c_types::c_void,
         type_: wifi_vendor_ie_type_t,
         sa: *const u8,
         vnd_ie: *const vendor_ie_data_t,
-        rssi: crate::binary::c_types::c_int,
+        rssi: crate::c_types::c_int,
     ),
 >;
 extern "C" {
@@ -6506,7 +6506,7 @@ extern "C" {
         enable: bool,
         type_: wifi_vendor_ie_type_t,
         idx: wifi_vendor_ie_id_t,
-        vnd_ie: *const crate::binary::c_types::c_void,
+        vnd_ie: *const crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -6520,7 +6520,7 @@ extern "C" {
     #[doc = "    - ESP_ERR_WIFI_NOT_INIT: WiFi is not initialized by esp_wifi_init"]
     pub fn esp_wifi_set_vendor_ie_cb(
         cb: esp_vendor_ie_cb_t,
-        ctx: *mut crate::binary::c_types::c_void,
+        ctx: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -6606,8 +6606,8 @@ extern "C" {
     #[doc = "    - ESP_ERR_WIFI_NO_MEM: out of memory"]
     pub fn esp_wifi_80211_tx(
         ifx: wifi_interface_t,
-        buffer: *const crate::binary::c_types::c_void,
-        len: crate::binary::c_types::c_int,
+        buffer: *const crate::c_types::c_void,
+        len: crate::c_types::c_int,
         en_sys_seq: bool,
     ) -> esp_err_t;
 }
@@ -6619,7 +6619,7 @@ extern "C" {
 #[doc = " @param data CSI data received. The memory that it points to will be deallocated after callback function returns."]
 #[doc = ""]
 pub type wifi_csi_cb_t = ::core::option::Option<
-    unsafe extern "C" fn(ctx: *mut crate::binary::c_types::c_void, data: *mut wifi_csi_info_t),
+    unsafe extern "C" fn(ctx: *mut crate::c_types::c_void, data: *mut wifi_csi_info_t),
 >;
 extern "C" {
     #[doc = " @brief Register the RX callback function of CSI data."]
@@ -6634,7 +6634,7 @@ extern "C" {
     #[doc = "    - ESP_ERR_WIFI_NOT_INIT: WiFi is not initialized by esp_wifi_init"]
     pub fn esp_wifi_set_csi_rx_cb(
         cb: wifi_csi_cb_t,
-        ctx: *mut crate::binary::c_types::c_void,
+        ctx: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -6830,7 +6830,7 @@ pub const smartconfig_type_t_SC_TYPE_AIRKISS: smartconfig_type_t = 1;
 pub const smartconfig_type_t_SC_TYPE_ESPTOUCH_AIRKISS: smartconfig_type_t = 2;
 #[doc = "< protocol: ESPTouch v2"]
 pub const smartconfig_type_t_SC_TYPE_ESPTOUCH_V2: smartconfig_type_t = 3;
-pub type smartconfig_type_t = crate::binary::c_types::c_uint;
+pub type smartconfig_type_t = crate::c_types::c_uint;
 #[doc = "< ESP32 station smartconfig has finished to scan for APs"]
 pub const smartconfig_event_t_SC_EVENT_SCAN_DONE: smartconfig_event_t = 0;
 #[doc = "< ESP32 station smartconfig has found the channel of the target AP"]
@@ -6840,7 +6840,7 @@ pub const smartconfig_event_t_SC_EVENT_GOT_SSID_PSWD: smartconfig_event_t = 2;
 #[doc = "< ESP32 station smartconfig has sent ACK to cellphone"]
 pub const smartconfig_event_t_SC_EVENT_SEND_ACK_DONE: smartconfig_event_t = 3;
 #[doc = " Smartconfig event declarations"]
-pub type smartconfig_event_t = crate::binary::c_types::c_uint;
+pub type smartconfig_event_t = crate::c_types::c_uint;
 extern "C" {
     pub static mut SC_EVENT: esp_event_base_t;
 }
@@ -6872,14 +6872,14 @@ pub struct smartconfig_start_config_t {
     #[doc = "< Enable ESPTouch v2 crypt."]
     pub esp_touch_v2_enable_crypt: bool,
     #[doc = "< ESPTouch v2 crypt key, len should be 16."]
-    pub esp_touch_v2_key: *mut crate::binary::c_types::c_char,
+    pub esp_touch_v2_key: *mut crate::c_types::c_char,
 }
 extern "C" {
     #[doc = " @brief  Get the version of SmartConfig."]
     #[doc = ""]
     #[doc = " @return"]
     #[doc = "     - SmartConfig version const char."]
-    pub fn esp_smartconfig_get_version() -> *const crate::binary::c_types::c_char;
+    pub fn esp_smartconfig_get_version() -> *const crate::c_types::c_char;
 }
 extern "C" {
     #[doc = " @brief     Start SmartConfig, config ESP device to connect AP. You need to broadcast information by phone APP."]
@@ -6964,14 +6964,14 @@ pub const wifi_ioctl_cmd_t_WIFI_IOCTL_GET_STA_HT2040_COEX: wifi_ioctl_cmd_t = 2;
 pub const wifi_ioctl_cmd_t_WIFI_IOCTL_MAX: wifi_ioctl_cmd_t = 3;
 #[doc = " @brief WiFi ioctl command type"]
 #[doc = ""]
-pub type wifi_ioctl_cmd_t = crate::binary::c_types::c_uint;
+pub type wifi_ioctl_cmd_t = crate::c_types::c_uint;
 #[doc = " @brief Configuration for STA's HT2040 coexist management"]
 #[doc = ""]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct wifi_ht2040_coex_t {
     #[doc = "< Indicate whether STA's HT2040 coexist management is enabled or not"]
-    pub enable: crate::binary::c_types::c_int,
+    pub enable: crate::c_types::c_int,
 }
 #[doc = " @brief Configuration for WiFi ioctl"]
 #[doc = ""]
@@ -6993,7 +6993,7 @@ pub struct wifi_static_queue_t {
     #[doc = "< FreeRTOS queue handler"]
     pub handle: QueueHandle_t,
     #[doc = "< storage for FreeRTOS queue"]
-    pub storage: *mut crate::binary::c_types::c_void,
+    pub storage: *mut crate::c_types::c_void,
 }
 pub const wifi_log_level_t_WIFI_LOG_NONE: wifi_log_level_t = 0;
 pub const wifi_log_level_t_WIFI_LOG_ERROR: wifi_log_level_t = 1;
@@ -7003,14 +7003,14 @@ pub const wifi_log_level_t_WIFI_LOG_DEBUG: wifi_log_level_t = 4;
 pub const wifi_log_level_t_WIFI_LOG_VERBOSE: wifi_log_level_t = 5;
 #[doc = " @brief WiFi log level"]
 #[doc = ""]
-pub type wifi_log_level_t = crate::binary::c_types::c_uint;
+pub type wifi_log_level_t = crate::c_types::c_uint;
 pub const wifi_log_module_t_WIFI_LOG_MODULE_ALL: wifi_log_module_t = 0;
 pub const wifi_log_module_t_WIFI_LOG_MODULE_WIFI: wifi_log_module_t = 1;
 pub const wifi_log_module_t_WIFI_LOG_MODULE_COEX: wifi_log_module_t = 2;
 pub const wifi_log_module_t_WIFI_LOG_MODULE_MESH: wifi_log_module_t = 3;
 #[doc = " @brief WiFi log module definition"]
 #[doc = ""]
-pub type wifi_log_module_t = crate::binary::c_types::c_uint;
+pub type wifi_log_module_t = crate::c_types::c_uint;
 extern "C" {
     #[doc = " @brief Initialize Wi-Fi Driver"]
     #[doc = "     Alloc resource for WiFi driver, such as WiFi control structure, RX/TX buffer,"]
@@ -7050,7 +7050,7 @@ extern "C" {
     #[doc = " @brief  free the rx buffer which allocated by wifi driver"]
     #[doc = ""]
     #[doc = " @param  void* buffer: rx buffer pointer"]
-    pub fn esp_wifi_internal_free_rx_buffer(buffer: *mut crate::binary::c_types::c_void);
+    pub fn esp_wifi_internal_free_rx_buffer(buffer: *mut crate::c_types::c_void);
 }
 extern "C" {
     #[doc = " @brief  transmit the buffer via wifi driver"]
@@ -7075,18 +7075,18 @@ extern "C" {
     #[doc = "    - ESP_ERR_WIFI_POST : caller fails to post event to WiFi task"]
     pub fn esp_wifi_internal_tx(
         wifi_if: wifi_interface_t,
-        buffer: *mut crate::binary::c_types::c_void,
+        buffer: *mut crate::c_types::c_void,
         len: u16,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 #[doc = " @brief     The net stack buffer reference counter callback function"]
 #[doc = ""]
 pub type wifi_netstack_buf_ref_cb_t =
-    ::core::option::Option<unsafe extern "C" fn(netstack_buf: *mut crate::binary::c_types::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(netstack_buf: *mut crate::c_types::c_void)>;
 #[doc = " @brief     The net stack buffer free callback function"]
 #[doc = ""]
 pub type wifi_netstack_buf_free_cb_t =
-    ::core::option::Option<unsafe extern "C" fn(netstack_buf: *mut crate::binary::c_types::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(netstack_buf: *mut crate::c_types::c_void)>;
 extern "C" {
     #[doc = " @brief  transmit the buffer by reference via wifi driver"]
     #[doc = ""]
@@ -7113,9 +7113,9 @@ extern "C" {
     #[doc = "    - ESP_ERR_WIFI_POST : caller fails to post event to WiFi task"]
     pub fn esp_wifi_internal_tx_by_ref(
         ifx: wifi_interface_t,
-        buffer: *mut crate::binary::c_types::c_void,
+        buffer: *mut crate::c_types::c_void,
         len: size_t,
-        netstack_buf: *mut crate::binary::c_types::c_void,
+        netstack_buf: *mut crate::c_types::c_void,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -7156,9 +7156,9 @@ extern "C" {
 #[doc = "            Each time the WiFi need to forward the packets to high layer, the callback function will be called"]
 pub type wifi_rxcb_t = ::core::option::Option<
     unsafe extern "C" fn(
-        buffer: *mut crate::binary::c_types::c_void,
+        buffer: *mut crate::c_types::c_void,
         len: u16,
-        eb: *mut crate::binary::c_types::c_void,
+        eb: *mut crate::c_types::c_void,
     ) -> esp_err_t,
 >;
 extern "C" {
@@ -7240,7 +7240,7 @@ extern "C" {
     #[doc = "     - ESP_OK : succeed"]
     #[doc = "     - ESP_WIFI_INVALID_ARG : MD5 check fail"]
     pub fn esp_wifi_internal_osi_funcs_md5_check(
-        md5: *const crate::binary::c_types::c_char,
+        md5: *const crate::c_types::c_char,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -7252,7 +7252,7 @@ extern "C" {
     #[doc = "     - ESP_OK : succeed"]
     #[doc = "     - ESP_WIFI_INVALID_ARG : MD5 check fail"]
     pub fn esp_wifi_internal_crypto_funcs_md5_check(
-        md5: *const crate::binary::c_types::c_char,
+        md5: *const crate::c_types::c_char,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -7264,7 +7264,7 @@ extern "C" {
     #[doc = "     - ESP_OK : succeed"]
     #[doc = "     - ESP_WIFI_INVALID_ARG : MD5 check fail"]
     pub fn esp_wifi_internal_wifi_type_md5_check(
-        md5: *const crate::binary::c_types::c_char,
+        md5: *const crate::c_types::c_char,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -7276,7 +7276,7 @@ extern "C" {
     #[doc = "     - ESP_OK : succeed"]
     #[doc = "     - ESP_WIFI_INVALID_ARG : MD5 check fail"]
     pub fn esp_wifi_internal_esp_wifi_md5_check(
-        md5: *const crate::binary::c_types::c_char,
+        md5: *const crate::c_types::c_char,
     ) -> esp_err_t;
 }
 extern "C" {
@@ -7287,7 +7287,7 @@ extern "C" {
     #[doc = " @param     size_t size : Size, in bytes, of the amount of memory to allocate"]
     #[doc = ""]
     #[doc = " @return    A pointer to the memory allocated on success, NULL on failure"]
-    pub fn wifi_malloc(size: size_t) -> *mut crate::binary::c_types::c_void;
+    pub fn wifi_malloc(size: size_t) -> *mut crate::c_types::c_void;
 }
 extern "C" {
     #[doc = " @brief     Reallocate a chunk of memory for WiFi driver"]
@@ -7299,9 +7299,9 @@ extern "C" {
     #[doc = ""]
     #[doc = " @return    A pointer to the memory allocated on success, NULL on failure"]
     pub fn wifi_realloc(
-        ptr: *mut crate::binary::c_types::c_void,
+        ptr: *mut crate::c_types::c_void,
         size: size_t,
-    ) -> *mut crate::binary::c_types::c_void;
+    ) -> *mut crate::c_types::c_void;
 }
 extern "C" {
     #[doc = " @brief     Callocate memory for WiFi driver"]
@@ -7312,7 +7312,7 @@ extern "C" {
     #[doc = " @param     size_t size : Size, in bytes, of the amount of memory to allocate"]
     #[doc = ""]
     #[doc = " @return    A pointer to the memory allocated on success, NULL on failure"]
-    pub fn wifi_calloc(n: size_t, size: size_t) -> *mut crate::binary::c_types::c_void;
+    pub fn wifi_calloc(n: size_t, size: size_t) -> *mut crate::c_types::c_void;
 }
 #[doc = " @brief     Update WiFi MAC time"]
 #[doc = ""]
@@ -7383,7 +7383,7 @@ extern "C" {
     #[doc = "    - ESP_OK: succeed"]
     #[doc = "    - others: failed"]
     pub fn esp_wifi_internal_ioctl(
-        cmd: crate::binary::c_types::c_int,
+        cmd: crate::c_types::c_int,
         cfg: *mut wifi_ioctl_config_t,
     ) -> esp_err_t;
 }
@@ -7477,8 +7477,8 @@ extern "C" {
     #[doc = " @param   min_freq_mhz: minimum frequency of DFS"]
     #[doc = " @param   max_freq_mhz: maximum frequency of DFS"]
     pub fn esp_wifi_internal_update_light_sleep_default_params(
-        min_freq_mhz: crate::binary::c_types::c_int,
-        max_freq_mhz: crate::binary::c_types::c_int,
+        min_freq_mhz: crate::c_types::c_int,
+        max_freq_mhz: crate::c_types::c_int,
     );
 }
 extern "C" {
@@ -7538,7 +7538,7 @@ pub const esp_phy_calibration_mode_t_PHY_RF_CAL_PARTIAL: esp_phy_calibration_mod
 pub const esp_phy_calibration_mode_t_PHY_RF_CAL_NONE: esp_phy_calibration_mode_t = 1;
 #[doc = "< Do full RF calibration. Produces best results, but also consumes a lot of time and current. Suggested to be used once."]
 pub const esp_phy_calibration_mode_t_PHY_RF_CAL_FULL: esp_phy_calibration_mode_t = 2;
-pub type esp_phy_calibration_mode_t = crate::binary::c_types::c_uint;
+pub type esp_phy_calibration_mode_t = crate::c_types::c_uint;
 extern "C" {
     #[doc = " @brief Get PHY init data"]
     #[doc = ""]
@@ -7657,13 +7657,13 @@ extern "C" {
 }
 extern "C" {
     #[doc = " @brief Update the corresponding PHY init type according to the country code of Wi-Fi."]
-    pub fn esp_phy_update_country_info(country: *const crate::binary::c_types::c_char)
+    pub fn esp_phy_update_country_info(country: *const crate::c_types::c_char)
         -> esp_err_t;
 }
 extern "C" {
     #[doc = " @brief Get PHY lib version"]
     #[doc = " @return PHY lib version."]
-    pub fn get_phy_version_str() -> *mut crate::binary::c_types::c_char;
+    pub fn get_phy_version_str() -> *mut crate::c_types::c_char;
 }
 extern "C" {
     #[doc = " @brief Return ROM function pointer table from PHY library."]
@@ -7679,7 +7679,7 @@ extern "C" {
         init_data: *const esp_phy_init_data_t,
         cal_data: *mut esp_phy_calibration_data_t,
         cal_mode: esp_phy_calibration_mode_t,
-    ) -> crate::binary::c_types::c_int;
+    ) -> crate::c_types::c_int;
 }
 extern "C" {
     #[doc = " @brief Get the format version of calibration data used by PHY library."]
@@ -7719,14 +7719,14 @@ extern "C" {
     pub fn phy_dig_reg_backup(backup_en: bool, mem_addr: *mut u32) -> u8;
 }
 extern "C" {
-    pub static phy_init_magic_pre: [crate::binary::c_types::c_char; 8usize];
+    pub static phy_init_magic_pre: [crate::c_types::c_char; 8usize];
 }
 extern "C" {
     #[doc = " @brief Structure containing default recommended PHY initialization parameters."]
     pub static phy_init_data: esp_phy_init_data_t;
 }
 extern "C" {
-    pub static phy_init_magic_post: [crate::binary::c_types::c_char; 8usize];
+    pub static phy_init_magic_post: [crate::c_types::c_char; 8usize];
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -7738,11 +7738,11 @@ pub type esp_timer_handle_t = *mut esp_timer;
 #[doc = " @brief Timer callback function type"]
 #[doc = " @param arg pointer to opaque user-specific data"]
 pub type esp_timer_cb_t =
-    ::core::option::Option<unsafe extern "C" fn(arg: *mut crate::binary::c_types::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(arg: *mut crate::c_types::c_void)>;
 #[doc = "!< Callback is called from timer task"]
 pub const esp_timer_dispatch_t_ESP_TIMER_TASK: esp_timer_dispatch_t = 0;
 #[doc = " @brief Method for dispatching timer callback"]
-pub type esp_timer_dispatch_t = crate::binary::c_types::c_uint;
+pub type esp_timer_dispatch_t = crate::c_types::c_uint;
 #[doc = " @brief Timer configuration passed to esp_timer_create"]
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -7750,11 +7750,11 @@ pub struct esp_timer_create_args_t {
     #[doc = "!< Function to call when timer expires"]
     pub callback: esp_timer_cb_t,
     #[doc = "!< Argument to pass to the callback"]
-    pub arg: *mut crate::binary::c_types::c_void,
+    pub arg: *mut crate::c_types::c_void,
     #[doc = "!< Call the callback from task or from ISR"]
     pub dispatch_method: esp_timer_dispatch_t,
     #[doc = "!< Timer name, used in esp_timer_dump function"]
-    pub name: *const crate::binary::c_types::c_char,
+    pub name: *const crate::c_types::c_char,
     #[doc = "!< Skip unhandled events for periodic timers"]
     pub skip_unhandled_events: bool,
 }
@@ -7898,4 +7898,4 @@ extern "C" {
 pub struct timer_adpt {
     pub _address: u8,
 }
-pub type __builtin_va_list = *mut crate::binary::c_types::c_char;
+pub type __builtin_va_list = *mut crate::c_types::c_char;
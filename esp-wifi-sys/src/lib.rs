@@ -0,0 +1,15 @@
+//! The WiFi driver blobs, the bindings generated from their headers and the ROM functions
+//! they need
+//!
+//! Only the raw FFI layer - the OS adapter the blobs need and the safe API are in
+//! `esp32c3-wifi-rs`.
+
+#![no_std]
+
+pub mod c_types;
+pub mod header_md5;
+pub mod include;
+
+// the driver keeps its init config and OS adapter table in statics
+unsafe impl Sync for include::wifi_init_config_t {}
+unsafe impl Sync for include::wifi_osi_funcs_t {}
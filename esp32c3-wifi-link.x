@@ -46,11 +46,13 @@ REGION_ALIAS("REGION_STACK", DRAM);
 REGION_ALIAS("REGION_RWTEXT", IRAM);
 REGION_ALIAS("REGION_RTC_FAST", RTC_FAST);
 
+/* generated by build.rs - defines REGION_WIFI_BUFFERS and _heap_size */
+INCLUDE wifi_config.x
+
 PROVIDE(_stext = ORIGIN(REGION_TEXT));
 PROVIDE(_stack_start = ORIGIN(REGION_STACK) + LENGTH(REGION_STACK));
 PROVIDE(_max_hart_id = 0);
 PROVIDE(_hart_stack_size = 2K);
-PROVIDE(_heap_size = 0);
 
 PROVIDE(UserSoft = DefaultHandler);
 PROVIDE(SupervisorSoft = DefaultHandler);
@@ -210,6 +212,15 @@ SECTIONS
     *(.noinit.*)
   } > REGION_DATA
 
+  /* large driver buffers (task stacks, tx buffer) - not initialized */
+  .wifi_buffers (NOLOAD) : ALIGN(8)
+  {
+    _swifi_buffers = .;
+    *(.wifi_buffers .wifi_buffers.*)
+    . = ALIGN(8);
+    _ewifi_buffers = .;
+  } > REGION_WIFI_BUFFERS
+
   .data : ALIGN(4)
   {
     _sidata = LOADADDR(.data);
@@ -51,15 +51,15 @@ fn main() -> ! {
     init_clocks();
 
     let res = wifi_init();
-    println!("\n\n\nesp_wifi_init_internal returned {}", res);
+    println!("\n\n\nesp_wifi_init_internal returned {:?}", res);
 
     println!("\n\n\nCall wifi_start");
     let res = wifi_start();
-    println!("\n\n\nwifi_start returned {}", res);
+    println!("\n\n\nwifi_start returned {:?}", res);
 
     // println!("Call wifi_start_scan");
     // let res = wifi::wifi_start_scan();
-    // println!("wifi_start_scan returned {}", res);
+    // println!("wifi_start_scan returned {:?}", res);
     print_scan_result();
     println!("\n\n\n\n");
 
@@ -106,7 +106,7 @@ fn main() -> ! {
 
     println!("Call wifi_connect");
     let res = wifi_connect(SSID, PASSWORD);
-    println!("wifi_connect returned {}", res);
+    println!("wifi_connect returned {:?}", res);
 
     loop {
         if wifi::is_connected() {
@@ -1,2 +0,0 @@
-pub mod c_types;
-pub mod include;
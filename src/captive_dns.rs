@@ -0,0 +1,234 @@
+//! A DNS responder for provisioning via the SoftAP
+//!
+//! Answers every A query with the same address (usually the SoftAP's own) and every other
+//! query with an empty answer. Phones and laptops take this as a captive portal and open
+//! their sign-in page pointing at the device. Announce the SoftAP as DNS server with the
+//! DHCP server (`DhcpServerConfig::dns_server`) and serve the page via HTTP.
+
+use smoltcp::{
+    socket::{SocketHandle, SocketSet, UdpPacketMetadata, UdpSocket, UdpSocketBuffer},
+    wire::Ipv4Address,
+};
+
+const DNS_PORT: u16 = 53;
+
+/// Largest DNS message sent over UDP without EDNS
+const MAX_MESSAGE_LEN: usize = 512;
+
+const HEADER_LEN: usize = 12;
+const TYPE_A: u16 = 1;
+const CLASS_IN: u16 = 1;
+
+/// TTL of the answers - short so the clients ask again once provisioning is done
+const ANSWER_TTL_S: u32 = 60;
+
+/// Memory used by the UDP socket of a [CaptiveDns]
+pub struct CaptiveDnsStorage {
+    rx_metadata: [UdpPacketMetadata; 4],
+    rx_buffer: [u8; 1024],
+    tx_metadata: [UdpPacketMetadata; 4],
+    tx_buffer: [u8; 1024],
+}
+
+impl CaptiveDnsStorage {
+    pub const fn new() -> CaptiveDnsStorage {
+        CaptiveDnsStorage {
+            rx_metadata: [UdpPacketMetadata::EMPTY; 4],
+            rx_buffer: [0u8; 1024],
+            tx_metadata: [UdpPacketMetadata::EMPTY; 4],
+            tx_buffer: [0u8; 1024],
+        }
+    }
+}
+
+impl Default for CaptiveDnsStorage {
+    fn default() -> Self {
+        CaptiveDnsStorage::new()
+    }
+}
+
+pub struct CaptiveDns {
+    handle: SocketHandle,
+    address: Ipv4Address,
+}
+
+impl CaptiveDns {
+    /// Adds a UDP socket listening on the DNS port to `sockets` - all names resolve to
+    /// `address`
+    pub fn new<'a>(
+        sockets: &mut SocketSet<'a>,
+        storage: &'a mut CaptiveDnsStorage,
+        address: Ipv4Address,
+    ) -> CaptiveDns {
+        let mut socket = UdpSocket::new(
+            UdpSocketBuffer::new(&mut storage.rx_metadata[..], &mut storage.rx_buffer[..]),
+            UdpSocketBuffer::new(&mut storage.tx_metadata[..], &mut storage.tx_buffer[..]),
+        );
+        socket.bind(DNS_PORT).ok();
+
+        CaptiveDns {
+            handle: sockets.add(socket),
+            address,
+        }
+    }
+
+    /// Answers the pending queries - call it after polling the interface
+    pub fn poll(&mut self, sockets: &mut SocketSet) {
+        let mut socket = sockets.get::<UdpSocket>(self.handle);
+        let mut query = [0u8; MAX_MESSAGE_LEN];
+        let mut response = [0u8; MAX_MESSAGE_LEN];
+
+        while let Ok((len, endpoint)) = socket.recv_slice(&mut query) {
+            if let Some(response_len) = self.answer(&query[..len], &mut response) {
+                socket.send_slice(&response[..response_len], endpoint).ok();
+            }
+        }
+    }
+
+    /// Builds the response to `query` - `None` for anything but a standard query with a
+    /// single question
+    fn answer(&self, query: &[u8], response: &mut [u8]) -> Option<usize> {
+        if query.len() < HEADER_LEN {
+            return None;
+        }
+
+        let flags = u16::from_be_bytes([query[2], query[3]]);
+        let is_response = flags & 0x8000 != 0;
+        let opcode = (flags >> 11) & 0x0f;
+        let question_count = u16::from_be_bytes([query[4], query[5]]);
+        if is_response || opcode != 0 || question_count != 1 {
+            return None;
+        }
+
+        // the name is a sequence of labels ending with an empty one - compression
+        // pointers don't appear in the question of a query
+        let mut pos = HEADER_LEN;
+        loop {
+            let label_len = *query.get(pos)? as usize;
+            if label_len & 0xc0 != 0 {
+                return None;
+            }
+            pos += 1 + label_len;
+            if label_len == 0 {
+                break;
+            }
+        }
+        let question_end = pos + 4;
+        let question = query.get(HEADER_LEN..question_end)?;
+        let qtype = u16::from_be_bytes([query[pos], query[pos + 1]]);
+        let qclass = u16::from_be_bytes([query[pos + 2], query[pos + 3]]);
+
+        let answer_count: u16 = if qtype == TYPE_A && qclass == CLASS_IN {
+            1
+        } else {
+            0
+        };
+        let response_len = question_end + if answer_count == 1 { 16 } else { 0 };
+        if response_len > response.len() {
+            return None;
+        }
+
+        // ID, flags: response, authoritative, recursion desired copied, no error
+        response[0..2].copy_from_slice(&query[0..2]);
+        response[2..4].copy_from_slice(&(0x8400 | (flags & 0x0100)).to_be_bytes());
+        response[4..6].copy_from_slice(&1u16.to_be_bytes());
+        response[6..8].copy_from_slice(&answer_count.to_be_bytes());
+        response[8..12].fill(0);
+        response[HEADER_LEN..question_end].copy_from_slice(question);
+
+        if answer_count == 1 {
+            let answer = &mut response[question_end..response_len];
+            // pointer to the name in the question
+            answer[0..2].copy_from_slice(&(0xc000 | HEADER_LEN as u16).to_be_bytes());
+            answer[2..4].copy_from_slice(&TYPE_A.to_be_bytes());
+            answer[4..6].copy_from_slice(&CLASS_IN.to_be_bytes());
+            answer[6..10].copy_from_slice(&ANSWER_TTL_S.to_be_bytes());
+            answer[10..12].copy_from_slice(&4u16.to_be_bytes());
+            answer[12..16].copy_from_slice(self.address.as_bytes());
+        }
+
+        Some(response_len)
+    }
+
+    /// The address all names resolve to
+    pub fn address(&self) -> Ipv4Address {
+        self.address
+    }
+
+    pub fn set_address(&mut self, address: Ipv4Address) {
+        self.address = address;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: [u8; HEADER_LEN] = [0xab, 0xcd, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+
+    fn dns() -> CaptiveDns {
+        CaptiveDns {
+            handle: SocketHandle::default(),
+            address: Ipv4Address::new(192, 168, 4, 1),
+        }
+    }
+
+    fn query(name: &[u8], qtype: u16, qclass: u16) -> std::vec::Vec<u8> {
+        let mut query = HEADER.to_vec();
+        query.extend_from_slice(name);
+        query.extend_from_slice(&qtype.to_be_bytes());
+        query.extend_from_slice(&qclass.to_be_bytes());
+        query
+    }
+
+    const EXAMPLE_COM: &[u8] = b"\x07example\x03com\x00";
+
+    #[test]
+    fn answers_a_queries_with_the_address() {
+        let query = query(EXAMPLE_COM, TYPE_A, CLASS_IN);
+        let mut response = [0u8; MAX_MESSAGE_LEN];
+        let len = dns().answer(&query, &mut response).unwrap();
+
+        assert_eq!(&response[..8], &[0xab, 0xcd, 0x85, 0x00, 0, 1, 0, 1]);
+        assert_eq!(&response[HEADER_LEN..query.len()], &query[HEADER_LEN..]);
+        assert_eq!(
+            &response[query.len()..len],
+            &[0xc0, 12, 0, 1, 0, 1, 0, 0, 0, 60, 0, 4, 192, 168, 4, 1]
+        );
+    }
+
+    #[test]
+    fn other_queries_get_an_empty_answer() {
+        let mut response = [0u8; MAX_MESSAGE_LEN];
+        for (qtype, qclass) in [(28, CLASS_IN), (TYPE_A, 3)] {
+            let query = query(EXAMPLE_COM, qtype, qclass);
+            assert_eq!(dns().answer(&query, &mut response), Some(query.len()));
+            assert_eq!(&response[6..8], &[0, 0]);
+        }
+    }
+
+    #[test]
+    fn truncated_question() {
+        let query = query(EXAMPLE_COM, TYPE_A, CLASS_IN);
+        let mut response = [0u8; MAX_MESSAGE_LEN];
+        for len in HEADER_LEN..query.len() {
+            assert_eq!(dns().answer(&query[..len], &mut response), None);
+        }
+    }
+
+    #[test]
+    fn label_pointer_loop() {
+        // the name points at itself
+        let query = query(&[0xc0, HEADER_LEN as u8], TYPE_A, CLASS_IN);
+        let mut response = [0u8; MAX_MESSAGE_LEN];
+        assert_eq!(dns().answer(&query, &mut response), None);
+    }
+
+    #[test]
+    fn shorter_than_the_header() {
+        let mut response = [0u8; MAX_MESSAGE_LEN];
+        for len in 0..HEADER_LEN {
+            assert_eq!(dns().answer(&HEADER[..len], &mut response), None);
+        }
+    }
+}
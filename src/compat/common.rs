@@ -1,10 +1,18 @@
 #![allow(unused)]
 
 use crate::{
-    binary::{c_types::c_void, include::OSI_FUNCS_TIME_BLOCKING},
-    log,
+    binary::{
+        c_types::c_void,
+        include::{
+            esp_err_t, ESP_ERR_INVALID_ARG, ESP_ERR_NOT_SUPPORTED, ESP_ERR_NO_MEM,
+            OSI_FUNCS_TIME_BLOCKING,
+        },
+    },
+    debug,
+    log::{self, blob_log_level, blob_log_message, BlobLogLevel},
     preempt::current_task,
     print, trace,
+    wifi::error::report_error,
 };
 use core::{ffi::VaListImpl, fmt::Write};
 
@@ -103,11 +111,16 @@ impl Write for StrBuf {
     }
 }
 
-pub unsafe extern "C" fn syslog(_priority: u32, format: *const u8, mut args: VaListImpl) {
+pub unsafe extern "C" fn syslog(priority: u32, format: *const u8, mut args: VaListImpl) {
+    let level = BlobLogLevel::from_raw(priority);
+    if level == BlobLogLevel::None || level > blob_log_level() {
+        return;
+    }
+
     let mut buf = [0u8; 512];
     vsnprintf(&mut buf as *mut u8, 511, format, args);
     let res_str = StrBuf::from(&buf as *const u8);
-    print!("{}", res_str.as_str_ref());
+    blob_log_message(level, res_str.as_str_ref());
 }
 
 pub(crate) unsafe fn vsnprintf(
@@ -248,6 +261,10 @@ pub fn sem_create(max: u32, init: u32) -> *mut crate::binary::c_types::c_void {
 }
 
 pub fn sem_delete(semphr: *mut crate::binary::c_types::c_void) {
+    if semphr.is_null() {
+        return;
+    }
+
     critical_section::with(|_| unsafe {
         CURR_SEM[semphr as usize - 1] = None;
     })
@@ -256,6 +273,10 @@ pub fn sem_delete(semphr: *mut crate::binary::c_types::c_void) {
 pub fn sem_take(semphr: *mut crate::binary::c_types::c_void, tick: u32) -> i32 {
     trace!(">>>> semphr_take {:p} block_time_tick {}", semphr, tick);
 
+    if semphr.is_null() {
+        return 0;
+    }
+
     let forever = if tick == OSI_FUNCS_TIME_BLOCKING {
         true
     } else {
@@ -299,6 +320,10 @@ pub fn sem_take(semphr: *mut crate::binary::c_types::c_void, tick: u32) -> i32 {
 pub fn sem_give(semphr: *mut crate::binary::c_types::c_void) -> i32 {
     trace!("semphr_give {:p}", semphr);
 
+    if semphr.is_null() {
+        return 0;
+    }
+
     let res = critical_section::with(|_| unsafe {
         if let Some(cnt) = CURR_SEM[semphr as usize - 1] {
             CURR_SEM[semphr as usize - 1] = Some(cnt + 1);
@@ -330,6 +355,12 @@ pub fn thread_sem_get() -> *mut crate::binary::c_types::c_void {
 
 pub fn create_recursive_mutex() -> *mut crate::binary::c_types::c_void {
     critical_section::with(|_| unsafe {
+        if MUTEX_IDX_CURRENT >= MUTEXES.len() {
+            debug!("recursive_mutex_create failed - out of mutexes");
+            report_error(ESP_ERR_NO_MEM as esp_err_t);
+            return core::ptr::null_mut();
+        }
+
         let ptr = &mut MUTEXES[MUTEX_IDX_CURRENT] as *mut _ as *mut Mutex;
         (*ptr).recursive = true;
         MUTEX_IDX_CURRENT += 1;
@@ -402,20 +433,41 @@ pub fn create_wifi_queue(
     }
 
     if item_size > 8 {
-        panic!("don't expecting the wifi queue to hold items larger than 8");
+        debug!("wifi_create_queue failed - items larger than 8 bytes are not supported");
+        report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+        return core::ptr::null_mut();
     }
 
     unsafe {
         if REAL_WIFI_QUEUE.is_none() {
             REAL_WIFI_QUEUE = Some(SimpleQueue::new());
         } else {
-            panic!("don't expecting more than one wifi queue");
+            debug!("wifi_create_queue failed - only one wifi queue is supported");
+            report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+            return core::ptr::null_mut();
         }
 
         &mut FAKE_WIFI_QUEUE as *mut _ as *mut crate::binary::c_types::c_void
     }
 }
 
+pub fn queued_count(queue: *mut crate::binary::c_types::c_void) -> u32 {
+    unsafe {
+        if queue == &mut REAL_WIFI_QUEUE as *mut _ as *mut crate::binary::c_types::c_void {
+            critical_section::with(|_| {
+                REAL_WIFI_QUEUE
+                    .as_ref()
+                    .map(|real_queue| real_queue.len() as u32)
+                    .unwrap_or(0)
+            })
+        } else {
+            debug!("queue_msg_waiting on unknown queue {:p}", queue);
+            report_error(ESP_ERR_INVALID_ARG as esp_err_t);
+            0
+        }
+    }
+}
+
 pub fn send_queued(
     queue: *mut crate::binary::c_types::c_void,
     item: *mut crate::binary::c_types::c_void,
@@ -440,12 +492,25 @@ pub fn send_queued(
                 }
                 trace!("queue posting {:x?}", data);
 
-                REAL_WIFI_QUEUE.as_mut().unwrap().enqueue(data);
-            });
+                let queued = REAL_WIFI_QUEUE
+                    .as_mut()
+                    .map(|real_queue| real_queue.enqueue(data))
+                    .unwrap_or(false);
+
+                if queued {
+                    1
+                } else {
+                    debug!("queue_send failed - queue full");
+                    report_error(ESP_ERR_NO_MEM as esp_err_t);
+                    0
+                }
+            })
+        } else {
+            debug!("queue_send to unknown queue {:p}", queue);
+            report_error(ESP_ERR_INVALID_ARG as esp_err_t);
+            0
         }
     }
-
-    1
 }
 
 pub fn receive_queued(
@@ -466,8 +531,11 @@ pub fn receive_queued(
     unsafe {
         if queue == &mut REAL_WIFI_QUEUE as *mut _ as *mut crate::binary::c_types::c_void {
             loop {
+                // only the WiFi task waits on this queue
+                crate::wifi::watchdog::wifi_task_check_in();
+
                 let res = critical_section::with(|_| {
-                    let message = REAL_WIFI_QUEUE.as_mut().unwrap().dequeue();
+                    let message = REAL_WIFI_QUEUE.as_mut().and_then(|queue| queue.dequeue());
                     if message.is_some() {
                         let message = message.unwrap();
                         let item = item as *mut u8;
@@ -494,7 +562,8 @@ pub fn receive_queued(
                 }
             }
         } else {
-            panic!("Unknown queue to handle in queue_recv");
+            debug!("queue_recv from unknown queue {:p}", queue);
+            report_error(ESP_ERR_INVALID_ARG as esp_err_t);
             -1
         }
     }
@@ -1,35 +1,133 @@
-use crate::trace;
+use crate::{
+    binary::include::{esp_err_t, ESP_ERR_INVALID_ARG, ESP_ERR_NO_MEM},
+    debug, trace,
+    wifi::error::report_error,
+};
 
 extern "C" {
     static _sheap: u8;
+    static _eheap: u8;
 }
 
+/// Who an allocation was made for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Subsystem {
+    /// Through the allocation functions of the WiFi driver's OS adapter
+    Wifi,
+    /// Through `malloc` / `calloc` directly - the supplicant library does that, and so does
+    /// the crate for scan results. The coexist library doesn't allocate at all
+    Other,
+}
+
+const SUBSYSTEMS: usize = 2;
+
 #[derive(Debug, Copy, Clone)]
 struct Allocation {
     address: *const u8,
     size: usize,
     free: bool,
+    subsystem: Subsystem,
 }
 
 static mut ALLOCATIONS: [Option<Allocation>; 128] = [None; 128];
 static mut ALLOC_INDEX: isize = -1;
 
+static mut SUBSYSTEM_USED: [usize; SUBSYSTEMS] = [0; SUBSYSTEMS];
+static mut SUBSYSTEM_PEAK: [usize; SUBSYSTEMS] = [0; SUBSYSTEMS];
+
+/// Heap usage of one [Subsystem]
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SubsystemUsage {
+    /// Bytes currently allocated - reused blocks count with their full size
+    pub used: usize,
+    /// Number of live allocations
+    pub allocations: usize,
+    /// The most bytes allocated at once since boot
+    pub peak: usize,
+}
+
+/// Usage of the heap used by the driver
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct HeapStats {
+    /// Size of the heap in bytes
+    pub size: usize,
+    /// Bytes currently allocated
+    pub used: usize,
+    /// Number of live allocations
+    pub allocations: usize,
+    /// Slots left in the allocation table
+    pub free_slots: usize,
+    /// Usage per [Subsystem] - see [HeapStats::subsystem]
+    pub subsystems: [SubsystemUsage; SUBSYSTEMS],
+}
+
+impl HeapStats {
+    pub fn subsystem(&self, subsystem: Subsystem) -> SubsystemUsage {
+        self.subsystems[subsystem as usize]
+    }
+}
+
+pub fn heap_stats() -> HeapStats {
+    critical_section::with(|_| unsafe {
+        let size = &_eheap as *const u8 as usize - &_sheap as *const u8 as usize;
+        let mut used = 0;
+        let mut allocations = 0;
+        let mut subsystems = [SubsystemUsage::default(); SUBSYSTEMS];
+        for allocation in ALLOCATIONS.iter().flatten() {
+            if !allocation.free {
+                used += allocation.size;
+                allocations += 1;
+                subsystems[allocation.subsystem as usize].allocations += 1;
+            }
+        }
+        for (i, usage) in subsystems.iter_mut().enumerate() {
+            usage.used = SUBSYSTEM_USED[i];
+            usage.peak = SUBSYSTEM_PEAK[i];
+        }
+
+        HeapStats {
+            size,
+            used,
+            allocations,
+            free_slots: ALLOCATIONS.len() - (ALLOC_INDEX + 1) as usize,
+            subsystems,
+        }
+    })
+}
+
 pub unsafe extern "C" fn malloc(size: u32) -> *const u8 {
+    malloc_for(Subsystem::Other, size)
+}
+
+pub unsafe fn malloc_for(subsystem: Subsystem, size: u32) -> *const u8 {
     trace!("malloc called {}", size);
 
+    let aligned_size = match size.checked_add(if size % 8 != 0 { 8 - size % 8 } else { 0 }) {
+        Some(aligned_size) => aligned_size,
+        None => {
+            debug!("malloc of {} bytes failed - size overflows", size);
+            report_error(ESP_ERR_NO_MEM as esp_err_t);
+            return core::ptr::null();
+        }
+    };
+
     let mut candidate_addr = &_sheap as *const u8;
 
     critical_section::with(|_critical_section| {
-        let aligned_size = size + if size % 8 != 0 { 8 - size % 8 } else { 0 };
-
         // try to find a previously freed block
         let mut reused = 0 as *const u8;
+        let mut allocated_size = aligned_size as usize;
         for allocation in ALLOCATIONS.iter_mut() {
             match allocation {
                 Some(ref mut allocation) => {
                     if allocation.free && aligned_size <= allocation.size as u32 {
                         allocation.free = false;
+                        allocation.subsystem = subsystem;
                         reused = allocation.address;
+                        allocated_size = allocation.size;
                         break;
                     }
                 }
@@ -46,12 +144,21 @@ pub unsafe extern "C" fn malloc(size: u32) -> *const u8 {
                     .offset(ALLOCATIONS[ALLOC_INDEX as usize].unwrap().size as isize);
             }
 
+            if ALLOC_INDEX + 1 >= ALLOCATIONS.len() as isize
+                || aligned_size as usize > &_eheap as *const u8 as usize - candidate_addr as usize
+            {
+                debug!("malloc of {} bytes failed - out of memory", size);
+                candidate_addr = core::ptr::null();
+                return;
+            }
+
             ALLOC_INDEX += 1;
 
             ALLOCATIONS[ALLOC_INDEX as usize] = Some(Allocation {
                 address: candidate_addr,
                 size: aligned_size as usize,
                 free: false,
+                subsystem,
             });
             trace!("new allocation idx = {}", ALLOC_INDEX);
         } else {
@@ -59,9 +166,18 @@ pub unsafe extern "C" fn malloc(size: u32) -> *const u8 {
             candidate_addr = reused;
         }
 
+        let used = &mut SUBSYSTEM_USED[subsystem as usize];
+        *used += allocated_size;
+        let peak = &mut SUBSYSTEM_PEAK[subsystem as usize];
+        *peak = (*peak).max(*used);
+
         trace!("malloc at {:p}", candidate_addr);
     });
 
+    if candidate_addr.is_null() {
+        report_error(ESP_ERR_NO_MEM as esp_err_t);
+    }
+
     return candidate_addr;
 }
 
@@ -72,7 +188,7 @@ pub unsafe extern "C" fn free(ptr: *const u8) {
         return;
     }
 
-    critical_section::with(|_critical_section| {
+    let known = critical_section::with(|_critical_section| {
         let alloced_idx = ALLOCATIONS
             .iter()
             .enumerate()
@@ -82,6 +198,11 @@ pub unsafe extern "C" fn free(ptr: *const u8) {
             let alloced_idx = alloced_idx.unwrap().0;
             trace!("free idx {}", alloced_idx);
 
+            let allocation = ALLOCATIONS[alloced_idx].unwrap();
+            if !allocation.free {
+                SUBSYSTEM_USED[allocation.subsystem as usize] -= allocation.size;
+            }
+
             if alloced_idx as isize == ALLOC_INDEX {
                 ALLOCATIONS[alloced_idx] = None;
                 ALLOC_INDEX -= 1;
@@ -90,18 +211,82 @@ pub unsafe extern "C" fn free(ptr: *const u8) {
                     .take()
                     .and_then(|v| Some(Allocation { free: true, ..v }));
             }
+            true
         } else {
-            panic!("freeing a memory area we don't know of");
+            debug!("freeing a memory area we don't know of {:p} - ignored", ptr);
+            false
         }
     });
+
+    if !known {
+        report_error(ESP_ERR_INVALID_ARG as esp_err_t);
+    }
+}
+
+/// Moves the allocation to a block of `size` bytes - keeps it and returns null if there
+/// is no memory left
+pub unsafe fn realloc_for(subsystem: Subsystem, ptr: *const u8, size: u32) -> *const u8 {
+    trace!("realloc {:p} {}", ptr, size);
+
+    if ptr.is_null() {
+        return malloc_for(subsystem, size);
+    }
+
+    let old_size = critical_section::with(|_critical_section| {
+        ALLOCATIONS
+            .iter()
+            .flatten()
+            .find(|allocation| allocation.address == ptr && !allocation.free)
+            .map(|allocation| allocation.size)
+    });
+    let old_size = match old_size {
+        Some(old_size) => old_size,
+        None => {
+            debug!(
+                "realloc of a memory area we don't know of {:p} - failed",
+                ptr
+            );
+            report_error(ESP_ERR_INVALID_ARG as esp_err_t);
+            return core::ptr::null();
+        }
+    };
+
+    let new_ptr = malloc_for(subsystem, size);
+    if new_ptr.is_null() {
+        return new_ptr;
+    }
+
+    core::ptr::copy_nonoverlapping(ptr, new_ptr as *mut u8, old_size.min(size as usize));
+    free(ptr);
+    new_ptr
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn calloc(number: u32, size: u32) -> *const u8 {
+    calloc_for(Subsystem::Other, number, size)
+}
+
+pub unsafe fn calloc_for(subsystem: Subsystem, number: u32, size: u32) -> *const u8 {
     trace!("calloc {} {}", number, size);
-    let ptr = malloc(number * size);
+    let total = match number.checked_mul(size) {
+        Some(total) => total,
+        None => {
+            debug!(
+                "calloc of {} * {} bytes failed - size overflows",
+                number, size
+            );
+            report_error(ESP_ERR_NO_MEM as esp_err_t);
+            return core::ptr::null();
+        }
+    };
+
+    let ptr = malloc_for(subsystem, total);
+    if ptr.is_null() {
+        return ptr;
+    }
+
     let mut zp = ptr as *mut u8;
-    for _ in 0..(number * size) {
+    for _ in 0..total {
         zp.write_volatile(0x00);
         zp = zp.offset(1);
     }
@@ -25,16 +25,17 @@ impl<T, const N: usize> SimpleQueue<T, N> {
         queue
     }
 
+    /// Returns `false` and drops the element if the queue is full
     pub fn enqueue(&mut self, e: T) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
         self.data[self.write_index] = Some(e);
 
         self.write_index += 1;
         self.write_index %= N;
 
-        if self.write_index == self.read_index {
-            return false;
-        }
-
         true
     }
 
@@ -53,8 +54,17 @@ impl<T, const N: usize> SimpleQueue<T, N> {
         self.read_index == self.write_index
     }
 
+    pub fn len(&self) -> usize {
+        (self.write_index + N - self.read_index) % N
+    }
+
+    /// The maximum number of elements - one slot is always kept free
+    pub fn capacity(&self) -> usize {
+        N - 1
+    }
+
     pub fn is_full(&self) -> bool {
-        let mut next_write = self.read_index + 1;
+        let mut next_write = self.write_index + 1;
         next_write %= N;
 
         next_write == self.read_index
@@ -1,6 +1,9 @@
 use crate::{
-    binary::include::{esp_timer_create_args_t, esp_timer_handle_t},
-    trace,
+    binary::include::{
+        esp_err_t, esp_timer_create_args_t, esp_timer_handle_t, ESP_ERR_INVALID_ARG, ESP_ERR_NO_MEM,
+    },
+    debug, trace,
+    wifi::error::report_error,
 };
 
 static ESP_FAKE_TIMER: () = ();
@@ -62,6 +65,16 @@ pub fn compat_timer_disarm(ptimer: *mut crate::binary::c_types::c_void) {
     });
 }
 
+/// Disarms every timer, e.g. before the chip goes to sleep
+pub fn compat_timer_disarm_all() {
+    trace!("timer_disarm_all");
+    critical_section::with(|_| unsafe {
+        for timer in TIMERS.iter_mut().flatten() {
+            timer.active = false;
+        }
+    });
+}
+
 pub fn compat_timer_done(ptimer: *mut crate::binary::c_types::c_void) {
     trace!("timer_done {:p}", ptimer);
     critical_section::with(|_| unsafe {
@@ -108,7 +121,8 @@ pub fn compat_timer_setfn(
             }
 
             if !success {
-                panic!("Ran out of timers!");
+                debug!("timer_setfn failed - ran out of timers");
+                report_error(ESP_ERR_NO_MEM as esp_err_t);
             }
         }
     });
@@ -129,6 +143,11 @@ pub fn compat_esp_timer_create(
 
     let args = args as *const esp_timer_create_args_t;
 
+    let callback = match unsafe { (*args).callback } {
+        Some(callback) => callback,
+        None => return ESP_ERR_INVALID_ARG as i32,
+    };
+
     critical_section::with(|_| unsafe {
         let mut success = false;
         for i in 0..TIMERS.len() {
@@ -139,7 +158,7 @@ pub fn compat_esp_timer_create(
                     expire: 0,
                     period: 0,
                     active: false,
-                    timer_ptr: core::mem::transmute((*args).callback.unwrap()),
+                    timer_ptr: core::mem::transmute(callback),
                     arg_ptr: (*args).arg,
                 });
                 out_handle = &ESP_FAKE_TIMER as *const _ as *mut esp_timer_handle_t;
@@ -150,9 +169,11 @@ pub fn compat_esp_timer_create(
             }
         }
         if !success {
-            panic!("ran out of timers");
+            debug!("esp_timer_create failed - ran out of timers");
+            report_error(ESP_ERR_NO_MEM as esp_err_t);
+            return ESP_ERR_NO_MEM as i32;
         }
-    });
 
-    0
+        0
+    })
 }
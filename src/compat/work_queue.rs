@@ -1,5 +1,9 @@
 use super::queue::SimpleQueue;
-use crate::trace;
+use crate::{
+    binary::include::{esp_err_t, ESP_ERR_NO_MEM},
+    debug, trace,
+    wifi::error::report_error,
+};
 
 static mut WORKER_HIGH: Option<
     SimpleQueue<
@@ -32,10 +36,15 @@ pub fn queue_work(
             WORKER_HIGH = Some(SimpleQueue::new());
         }
 
-        let _ = &WORKER_HIGH
+        let queued = WORKER_HIGH
             .as_mut()
-            .unwrap()
-            .enqueue((core::mem::transmute(task_func), param));
+            .map(|worker| worker.enqueue((core::mem::transmute(task_func), param)))
+            .unwrap_or(false);
+
+        if !queued {
+            debug!("work_queue full - task {:p} dropped", task_func);
+            report_error(ESP_ERR_NO_MEM as esp_err_t);
+        }
     });
 }
 
@@ -52,7 +61,7 @@ pub fn do_work() {
             }
 
             todo.iter_mut().for_each(|e| {
-                let work = &WORKER_HIGH.as_mut().unwrap().dequeue();
+                let work = &WORKER_HIGH.as_mut().and_then(|worker| worker.dequeue());
 
                 match work {
                     Some(worker) => {
@@ -0,0 +1,46 @@
+//! Compile-time configuration
+//!
+//! Values are taken from `ESP32C3_WIFI_*` env variables when building and validated by the
+//! build script. See the README for the available options.
+
+include!(concat!(env!("OUT_DIR"), "/config.rs"));
+
+/// The configuration the crate was built with
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config {
+    pub static_rx_buf_num: usize,
+    pub dynamic_rx_buf_num: usize,
+    pub dynamic_tx_buf_num: usize,
+    pub rx_queue_size: usize,
+    pub max_connections: usize,
+    pub heap_size: usize,
+    pub country: [u8; 2],
+    /// Follow the country of the access point the station is connected to
+    pub country_policy_auto: bool,
+    /// Time slice of the task scheduler
+    pub tick_period_us: u32,
+    /// Built with `ESP32C3_WIFI_PRESET=low-power`
+    pub low_power_preset: bool,
+    pub driver_task_stack_size: usize,
+    pub timer_task_stack_size: usize,
+}
+
+pub const CONFIG: Config = Config {
+    static_rx_buf_num: STATIC_RX_BUF_NUM,
+    dynamic_rx_buf_num: DYNAMIC_RX_BUF_NUM,
+    dynamic_tx_buf_num: DYNAMIC_TX_BUF_NUM,
+    rx_queue_size: RX_QUEUE_SIZE,
+    max_connections: MAX_CONNECTIONS,
+    heap_size: HEAP_SIZE,
+    country: COUNTRY,
+    country_policy_auto: COUNTRY_POLICY_AUTO,
+    tick_period_us: TICK_PERIOD_US,
+    low_power_preset: LOW_POWER_PRESET,
+    driver_task_stack_size: DRIVER_TASK_STACK_SIZE,
+    timer_task_stack_size: TIMER_TASK_STACK_SIZE,
+};
+
+pub fn get_config() -> Config {
+    CONFIG
+}
@@ -0,0 +1,291 @@
+//! Keeps the station connected
+//!
+//! [ConnectionManager] connects to the configured access point and reconnects after the
+//! connection got lost. When to retry is decided by a [RetryPolicy].
+//!
+//! ```ignore
+//! let mut manager = ConnectionManager::new(SSID, PASSWORD, RetryPolicy::default())?;
+//! loop {
+//!     if let Some(ConnectionEvent::GaveUp) = manager.poll() {
+//!         // e.g. start provisioning
+//!     }
+//! }
+//! ```
+
+use heapless::String;
+
+use crate::{
+    credentials::{CredentialStore, Credentials, ProvisioningState},
+    wifi::{get_sta_mac, is_connected, wifi_connect, WifiError},
+};
+
+/// When to retry connecting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RetryPolicy {
+    /// Wait the same time before every attempt
+    FixedInterval {
+        interval_ms: u32,
+        max_attempts: Option<u32>,
+    },
+    /// Double the delay after every failed attempt up to `max_delay_ms`
+    ///
+    /// Each delay is shortened by a random amount of up to `jitter_percent` percent so a
+    /// fleet of devices doesn't hammer the access point in lockstep.
+    ExponentialBackoff {
+        initial_delay_ms: u32,
+        max_delay_ms: u32,
+        jitter_percent: u8,
+        max_attempts: Option<u32>,
+    },
+}
+
+impl RetryPolicy {
+    /// The number of consecutive failed attempts after which [ConnectionEvent::GaveUp] is
+    /// returned - `None` retries forever
+    pub fn max_attempts(&self) -> Option<u32> {
+        match *self {
+            RetryPolicy::FixedInterval { max_attempts, .. } => max_attempts,
+            RetryPolicy::ExponentialBackoff { max_attempts, .. } => max_attempts,
+        }
+    }
+
+    /// The delay before the next attempt after `failed_attempts` consecutive failures
+    fn delay_ms(&self, failed_attempts: u32, random: u32) -> u32 {
+        match *self {
+            RetryPolicy::FixedInterval { interval_ms, .. } => interval_ms,
+            RetryPolicy::ExponentialBackoff {
+                initial_delay_ms,
+                max_delay_ms,
+                jitter_percent,
+                ..
+            } => {
+                let shift = failed_attempts.saturating_sub(1).min(31);
+                let delay = initial_delay_ms
+                    .saturating_mul(1 << shift)
+                    .min(max_delay_ms);
+
+                let max_jitter = (delay as u64 * jitter_percent.min(100) as u64 / 100) as u32;
+                if max_jitter == 0 {
+                    delay
+                } else {
+                    delay - random % (max_jitter + 1)
+                }
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// 1s doubling up to 60s with 25% jitter, retrying forever
+    fn default() -> Self {
+        RetryPolicy::ExponentialBackoff {
+            initial_delay_ms: 1_000,
+            max_delay_ms: 60_000,
+            jitter_percent: 25,
+            max_attempts: None,
+        }
+    }
+}
+
+/// Something noteworthy happened while polling the [ConnectionManager]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConnectionEvent {
+    Connected,
+    /// The connection got lost - reconnecting is scheduled
+    Disconnected,
+    /// An attempt to connect failed
+    AttemptFailed {
+        attempt: u32,
+    },
+    /// `max_attempts` consecutive attempts failed - no more attempts are made until
+    /// [ConnectionManager::reset] is called
+    GaveUp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Waiting to (re)connect at the given time
+    Waiting(u64),
+    /// `wifi_connect` was called at the given time
+    Connecting(u64),
+    Connected,
+    GaveUp,
+}
+
+/// Time to wait for the connection after calling `wifi_connect`
+const CONNECT_TIMEOUT_MS: u64 = 10_000;
+
+pub struct ConnectionManager {
+    ssid: String<32>,
+    password: String<64>,
+    policy: RetryPolicy,
+    state: State,
+    failed_attempts: u32,
+    random: u32,
+    verified: bool,
+}
+
+impl ConnectionManager {
+    /// The driver is expected to be initialized and started - connecting starts with the
+    /// first poll. Fails with `InvalidSsid`/`InvalidPassword` if they are too long.
+    pub fn new(
+        ssid: &str,
+        password: &str,
+        policy: RetryPolicy,
+    ) -> Result<ConnectionManager, WifiError> {
+        let mut ssid_buf = String::new();
+        ssid_buf
+            .push_str(ssid)
+            .map_err(|_| WifiError::InvalidSsid)?;
+        let mut password_buf = String::new();
+        password_buf
+            .push_str(password)
+            .map_err(|_| WifiError::InvalidPassword)?;
+
+        let mut mac = [0u8; 6];
+        get_sta_mac(&mut mac);
+
+        // seed the jitter with the MAC so devices differ
+        let seed = u32::from_le_bytes([mac[2], mac[3], mac[4], mac[5]]);
+
+        Ok(ConnectionManager {
+            ssid: ssid_buf,
+            password: password_buf,
+            policy,
+            state: State::Waiting(0),
+            failed_attempts: 0,
+            random: seed | 1,
+            verified: false,
+        })
+    }
+
+    /// Connects to the network remembered in `store` - `None` if nothing is stored, i.e.
+    /// the device needs provisioning
+    pub fn from_store<S: CredentialStore>(
+        store: &mut S,
+        policy: RetryPolicy,
+    ) -> Result<Option<ConnectionManager>, S::Error> {
+        Ok(store.load()?.map(|credentials| {
+            // the lengths fit, the buffers have the same size
+            let mut manager =
+                ConnectionManager::new(&credentials.ssid, &credentials.password, policy).unwrap();
+            manager.verified = credentials.state == ProvisioningState::Verified;
+            manager
+        }))
+    }
+
+    /// Stores the credentials as [ProvisioningState::Verified] - call it on
+    /// [ConnectionEvent::Connected], it only writes the first time
+    pub fn remember<S: CredentialStore>(&mut self, store: &mut S) -> Result<(), S::Error> {
+        if self.verified || self.state != State::Connected {
+            return Ok(());
+        }
+
+        store.store(&Credentials {
+            ssid: self.ssid.clone(),
+            password: self.password.clone(),
+            state: ProvisioningState::Verified,
+        })?;
+        self.verified = true;
+        Ok(())
+    }
+
+    /// A connection with the credentials succeeded before - see [ProvisioningState]
+    pub fn verified(&self) -> bool {
+        self.verified
+    }
+
+    pub fn policy(&self) -> RetryPolicy {
+        self.policy
+    }
+
+    pub fn set_policy(&mut self, policy: RetryPolicy) {
+        self.policy = policy;
+    }
+
+    /// Number of consecutive failed attempts
+    pub fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    /// Starts over after [ConnectionEvent::GaveUp] - the next poll tries to connect
+    pub fn reset(&mut self) {
+        self.failed_attempts = 0;
+        self.state = State::Waiting(0);
+    }
+
+    /// Drives the connection - call it regularly
+    #[cfg(not(feature = "mock"))]
+    pub fn poll(&mut self) -> Option<ConnectionEvent> {
+        self.poll_at(crate::timer::get_systimer_count() / 16_000)
+    }
+
+    pub fn poll_at(&mut self, now_ms: u64) -> Option<ConnectionEvent> {
+        match self.state {
+            State::Connected => {
+                if is_connected() {
+                    return None;
+                }
+
+                self.schedule_retry(now_ms);
+                Some(ConnectionEvent::Disconnected)
+            }
+            State::Connecting(since) => {
+                if is_connected() {
+                    self.failed_attempts = 0;
+                    self.state = State::Connected;
+                    return Some(ConnectionEvent::Connected);
+                }
+
+                if now_ms.wrapping_sub(since) < CONNECT_TIMEOUT_MS {
+                    return None;
+                }
+
+                Some(self.attempt_failed(now_ms))
+            }
+            State::Waiting(at) => {
+                if now_ms < at {
+                    return None;
+                }
+
+                match wifi_connect(&self.ssid, &self.password) {
+                    Ok(()) => {
+                        self.state = State::Connecting(now_ms);
+                        None
+                    }
+                    Err(_) => Some(self.attempt_failed(now_ms)),
+                }
+            }
+            State::GaveUp => None,
+        }
+    }
+
+    fn attempt_failed(&mut self, now_ms: u64) -> ConnectionEvent {
+        self.failed_attempts += 1;
+
+        if let Some(max_attempts) = self.policy.max_attempts() {
+            if self.failed_attempts >= max_attempts {
+                self.state = State::GaveUp;
+                return ConnectionEvent::GaveUp;
+            }
+        }
+
+        let attempt = self.failed_attempts;
+        self.schedule_retry(now_ms);
+        ConnectionEvent::AttemptFailed { attempt }
+    }
+
+    fn schedule_retry(&mut self, now_ms: u64) {
+        // xorshift32
+        self.random ^= self.random << 13;
+        self.random ^= self.random >> 17;
+        self.random ^= self.random << 5;
+
+        let delay = self
+            .policy
+            .delay_ms(self.failed_attempts.max(1), self.random);
+        self.state = State::Waiting(now_ms + delay as u64);
+    }
+}
@@ -0,0 +1,111 @@
+//! Remembering the network to connect to
+//!
+//! A [CredentialStore] persists the [Credentials] wherever the application keeps them -
+//! a flash sector, NVS, RTC memory. [Credentials::to_bytes] / [Credentials::from_bytes]
+//! give a fixed size record so an implementation only has to read and write bytes.
+//!
+//! ```ignore
+//! let mut manager = match ConnectionManager::from_store(&mut store, RetryPolicy::default())? {
+//!     Some(manager) => manager,
+//!     None => provision(&mut store), // stores `ProvisioningState::Unverified` credentials
+//! };
+//! loop {
+//!     match manager.poll() {
+//!         Some(ConnectionEvent::Connected) => manager.remember(&mut store)?,
+//!         Some(ConnectionEvent::GaveUp) if !manager.verified() => store.clear()?,
+//!         _ => (),
+//!     }
+//! }
+//! ```
+
+use heapless::String;
+
+/// Size of the record of [Credentials::to_bytes]
+pub const CREDENTIALS_LEN: usize = 100;
+
+const RECORD_VERSION: u8 = 1;
+
+/// How far provisioning got
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ProvisioningState {
+    /// Received (e.g. via the SoftAP) but never used to connect successfully - a typo in
+    /// the password is likely if connecting keeps failing
+    Unverified,
+    /// A connection succeeded with them - failures are more likely a problem of the
+    /// access point
+    Verified,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Credentials {
+    pub ssid: String<32>,
+    pub password: String<64>,
+    pub state: ProvisioningState,
+}
+
+impl Credentials {
+    /// Layout: version, state, SSID length, SSID (32 bytes), password length, password
+    /// (64 bytes) - unused bytes are zero
+    pub fn to_bytes(&self) -> [u8; CREDENTIALS_LEN] {
+        let mut bytes = [0u8; CREDENTIALS_LEN];
+        bytes[0] = RECORD_VERSION;
+        bytes[1] = match self.state {
+            ProvisioningState::Unverified => 0,
+            ProvisioningState::Verified => 1,
+        };
+        bytes[2] = self.ssid.len() as u8;
+        bytes[3..3 + self.ssid.len()].copy_from_slice(self.ssid.as_bytes());
+        bytes[35] = self.password.len() as u8;
+        bytes[36..36 + self.password.len()].copy_from_slice(self.password.as_bytes());
+        bytes
+    }
+
+    /// `None` for anything not written by [Credentials::to_bytes], e.g. erased flash
+    pub fn from_bytes(bytes: &[u8]) -> Option<Credentials> {
+        if bytes.len() < CREDENTIALS_LEN || bytes[0] != RECORD_VERSION {
+            return None;
+        }
+
+        let state = match bytes[1] {
+            0 => ProvisioningState::Unverified,
+            1 => ProvisioningState::Verified,
+            _ => return None,
+        };
+
+        let ssid_len = bytes[2] as usize;
+        let password_len = bytes[35] as usize;
+        if ssid_len == 0 || ssid_len > 32 || password_len > 64 {
+            return None;
+        }
+
+        let mut ssid = String::new();
+        ssid.push_str(core::str::from_utf8(&bytes[3..3 + ssid_len]).ok()?)
+            .ok()?;
+        let mut password = String::new();
+        password
+            .push_str(core::str::from_utf8(&bytes[36..36 + password_len]).ok()?)
+            .ok()?;
+
+        Some(Credentials {
+            ssid,
+            password,
+            state,
+        })
+    }
+}
+
+/// Where the credentials are kept - called by [crate::connection::ConnectionManager] and
+/// the application's provisioning code
+pub trait CredentialStore {
+    type Error;
+
+    /// `None` if nothing is stored yet
+    fn load(&mut self) -> Result<Option<Credentials>, Self::Error>;
+
+    fn store(&mut self, credentials: &Credentials) -> Result<(), Self::Error>;
+
+    /// Forgets the credentials, e.g. to start provisioning again
+    fn clear(&mut self) -> Result<(), Self::Error>;
+}
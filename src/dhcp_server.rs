@@ -0,0 +1,365 @@
+//! A minimal DHCP server for the SoftAP
+//!
+//! Hands out the addresses of a single /24 subnet to up to [MAX_LEASES] clients. It works
+//! with any smoltcp socket set - usually the one of an interface created with
+//! `WifiDevice::new_ap` and a static address.
+//!
+//! ```ignore
+//! let mut server = DhcpServer::new(&mut sockets, &mut storage, DhcpServerConfig::default());
+//! loop {
+//!     interface.poll(&mut sockets, now).ok();
+//!     server.poll(&mut sockets, now);
+//! }
+//! ```
+
+use smoltcp::{
+    socket::{SocketHandle, SocketSet, UdpPacketMetadata, UdpSocket, UdpSocketBuffer},
+    time::{Duration, Instant},
+    wire::{DhcpMessageType, DhcpPacket, DhcpRepr, EthernetAddress, IpEndpoint, Ipv4Address},
+};
+
+/// Number of addresses in the pool
+pub const MAX_LEASES: usize = 10;
+
+const SERVER_PORT: u16 = 67;
+const CLIENT_PORT: u16 = 68;
+
+/// How long an offered address is kept for the client's request
+const OFFER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Some clients drop replies shorter than a BOOTP message
+const MIN_REPLY_LEN: usize = 300;
+
+/// Memory used by the UDP socket of a [DhcpServer]
+pub struct DhcpServerStorage {
+    rx_metadata: [UdpPacketMetadata; 4],
+    rx_buffer: [u8; 1200],
+    tx_metadata: [UdpPacketMetadata; 4],
+    tx_buffer: [u8; 1200],
+}
+
+impl DhcpServerStorage {
+    pub const fn new() -> DhcpServerStorage {
+        DhcpServerStorage {
+            rx_metadata: [UdpPacketMetadata::EMPTY; 4],
+            rx_buffer: [0u8; 1200],
+            tx_metadata: [UdpPacketMetadata::EMPTY; 4],
+            tx_buffer: [0u8; 1200],
+        }
+    }
+}
+
+impl Default for DhcpServerStorage {
+    fn default() -> Self {
+        DhcpServerStorage::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DhcpServerConfig {
+    /// The address of the SoftAP's interface - announced as the router
+    pub server_ip: Ipv4Address,
+    /// The first address of the pool - the pool has to stay within the server's /24 subnet
+    pub pool_start: Ipv4Address,
+    pub lease_time_s: u32,
+    /// DNS server announced to the clients
+    pub dns_server: Option<Ipv4Address>,
+}
+
+impl Default for DhcpServerConfig {
+    fn default() -> Self {
+        DhcpServerConfig {
+            server_ip: Ipv4Address::new(192, 168, 4, 1),
+            pool_start: Ipv4Address::new(192, 168, 4, 2),
+            lease_time_s: 7200,
+            dns_server: Some(Ipv4Address::new(192, 168, 4, 1)),
+        }
+    }
+}
+
+/// A client holding (or being offered) an address
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lease {
+    pub mac: EthernetAddress,
+    pub ip: Ipv4Address,
+    pub expires: Instant,
+}
+
+pub struct DhcpServer {
+    handle: SocketHandle,
+    config: DhcpServerConfig,
+    /// Index `i` holds the lease of `pool_start + i`
+    leases: [Option<(EthernetAddress, Instant)>; MAX_LEASES],
+}
+
+impl DhcpServer {
+    /// Adds a UDP socket listening on the server port to `sockets`
+    pub fn new<'a>(
+        sockets: &mut SocketSet<'a>,
+        storage: &'a mut DhcpServerStorage,
+        config: DhcpServerConfig,
+    ) -> DhcpServer {
+        let mut socket = UdpSocket::new(
+            UdpSocketBuffer::new(&mut storage.rx_metadata[..], &mut storage.rx_buffer[..]),
+            UdpSocketBuffer::new(&mut storage.tx_metadata[..], &mut storage.tx_buffer[..]),
+        );
+        socket.bind(SERVER_PORT).ok();
+
+        DhcpServer {
+            handle: sockets.add(socket),
+            config,
+            leases: [None; MAX_LEASES],
+        }
+    }
+
+    /// Answers the pending requests - call it after polling the interface
+    pub fn poll(&mut self, sockets: &mut SocketSet, now: Instant) {
+        let mut socket = sockets.get::<UdpSocket>(self.handle);
+        let mut buffer = [0u8; 600];
+
+        while let Ok((len, _)) = socket.recv_slice(&mut buffer) {
+            let packet = match DhcpPacket::new_checked(&buffer[..len]) {
+                Ok(packet) => packet,
+                Err(_) => continue,
+            };
+            let request = match DhcpRepr::parse(&packet) {
+                Ok(request) => request,
+                Err(_) => continue,
+            };
+
+            let mac = request.client_hardware_address;
+            let reply = match request.message_type {
+                DhcpMessageType::Discover => self
+                    .allocate(mac, request.requested_ip, now, now + OFFER_TIMEOUT)
+                    .map(|ip| (DhcpMessageType::Offer, ip)),
+                DhcpMessageType::Request => {
+                    // the client picked another server
+                    if matches!(request.server_identifier, Some(id) if id != self.config.server_ip)
+                    {
+                        self.release(mac);
+                        continue;
+                    }
+
+                    let requested = request
+                        .requested_ip
+                        .or_else(|| Some(request.client_ip).filter(|ip| !ip.is_unspecified()));
+                    let lease_end = now + Duration::from_secs(self.config.lease_time_s as u64);
+                    match self.allocate(mac, requested, now, lease_end) {
+                        Some(ip) if requested.map(|r| r == ip).unwrap_or(true) => {
+                            Some((DhcpMessageType::Ack, ip))
+                        }
+                        _ => {
+                            self.release(mac);
+                            Some((DhcpMessageType::Nak, Ipv4Address::UNSPECIFIED))
+                        }
+                    }
+                }
+                DhcpMessageType::Release | DhcpMessageType::Decline => {
+                    self.release(mac);
+                    None
+                }
+                _ => None,
+            };
+
+            if let Some((message_type, ip)) = reply {
+                self.reply(&mut socket, &request, message_type, ip);
+            }
+        }
+    }
+
+    fn reply(
+        &self,
+        socket: &mut UdpSocket,
+        request: &DhcpRepr,
+        message_type: DhcpMessageType,
+        your_ip: Ipv4Address,
+    ) {
+        let nak = message_type == DhcpMessageType::Nak;
+        let repr = DhcpRepr {
+            message_type,
+            transaction_id: request.transaction_id,
+            client_hardware_address: request.client_hardware_address,
+            client_ip: Ipv4Address::UNSPECIFIED,
+            your_ip,
+            server_ip: Ipv4Address::UNSPECIFIED,
+            router: (!nak).then_some(self.config.server_ip),
+            subnet_mask: (!nak).then_some(Ipv4Address::new(255, 255, 255, 0)),
+            relay_agent_ip: Ipv4Address::UNSPECIFIED,
+            broadcast: request.broadcast,
+            requested_ip: None,
+            client_identifier: None,
+            server_identifier: Some(self.config.server_ip),
+            parameter_request_list: None,
+            dns_servers: self
+                .config
+                .dns_server
+                .filter(|_| !nak)
+                .map(|dns| [Some(dns), None, None]),
+            max_size: None,
+            lease_duration: (!nak).then_some(self.config.lease_time_s),
+        };
+
+        // the client has no address yet - always broadcast the reply
+        let endpoint = IpEndpoint::new(Ipv4Address::BROADCAST.into(), CLIENT_PORT);
+        let len = repr.buffer_len().max(MIN_REPLY_LEN);
+        if let Ok(buffer) = socket.send(len, endpoint) {
+            buffer.fill(0);
+            repr.emit(&mut DhcpPacket::new_unchecked(buffer)).ok();
+        }
+    }
+
+    /// The address of `mac` - its current one, the requested one if free or the first
+    /// free one
+    fn allocate(
+        &mut self,
+        mac: EthernetAddress,
+        requested: Option<Ipv4Address>,
+        now: Instant,
+        expires: Instant,
+    ) -> Option<Ipv4Address> {
+        let free = |entry: &Option<(EthernetAddress, Instant)>| match entry {
+            Some((_, lease_end)) => *lease_end < now,
+            None => true,
+        };
+
+        let index = self
+            .leases
+            .iter()
+            .position(|entry| matches!(entry, Some((addr, _)) if *addr == mac))
+            .or_else(|| {
+                requested
+                    .and_then(|ip| self.index_of(ip))
+                    .filter(|&index| free(&self.leases[index]))
+            })
+            .or_else(|| self.leases.iter().position(free))?;
+
+        self.leases[index] = Some((mac, expires));
+        Some(self.ip_at(index))
+    }
+
+    fn release(&mut self, mac: EthernetAddress) {
+        for entry in self.leases.iter_mut() {
+            if matches!(entry, Some((addr, _)) if *addr == mac) {
+                *entry = None;
+            }
+        }
+    }
+
+    fn index_of(&self, ip: Ipv4Address) -> Option<usize> {
+        let start = self.config.pool_start.0;
+        if ip.0[..3] != start[..3] || ip.0[3] < start[3] {
+            return None;
+        }
+
+        Some((ip.0[3] - start[3]) as usize).filter(|&index| index < MAX_LEASES)
+    }
+
+    fn ip_at(&self, index: usize) -> Ipv4Address {
+        let mut ip = self.config.pool_start;
+        ip.0[3] = ip.0[3].wrapping_add(index as u8);
+        ip
+    }
+
+    /// The clients holding an address - includes expired leases until the address is
+    /// handed out again
+    pub fn leases(&self) -> impl Iterator<Item = Lease> + '_ {
+        self.leases
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, entry)| {
+                entry.map(|(mac, expires)| Lease {
+                    mac,
+                    ip: self.ip_at(index),
+                    expires,
+                })
+            })
+    }
+
+    pub fn config(&self) -> DhcpServerConfig {
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server() -> DhcpServer {
+        DhcpServer {
+            handle: SocketHandle::default(),
+            config: DhcpServerConfig::default(),
+            leases: [None; MAX_LEASES],
+        }
+    }
+
+    fn mac(n: u8) -> EthernetAddress {
+        EthernetAddress([2, 0, 0, 0, 0, n])
+    }
+
+    fn at(s: i64) -> Instant {
+        Instant::from_secs(s)
+    }
+
+    #[test]
+    fn full_pool() {
+        let mut server = server();
+        for n in 0..MAX_LEASES as u8 {
+            assert_eq!(
+                server.allocate(mac(n), None, at(0), at(100)),
+                Some(Ipv4Address::new(192, 168, 4, 2 + n))
+            );
+        }
+
+        assert_eq!(server.allocate(mac(99), None, at(0), at(100)), None);
+        // expired leases are handed out again
+        assert_eq!(
+            server.allocate(mac(99), None, at(101), at(200)),
+            Some(Ipv4Address::new(192, 168, 4, 2))
+        );
+    }
+
+    #[test]
+    fn requested_ip_outside_the_pool() {
+        let mut server = server();
+        for ip in [
+            Ipv4Address::new(10, 0, 0, 5),
+            Ipv4Address::new(192, 168, 4, 1),
+            Ipv4Address::new(192, 168, 4, 2 + MAX_LEASES as u8),
+            Ipv4Address::new(192, 168, 4, 255),
+        ] {
+            assert_eq!(server.index_of(ip), None);
+        }
+
+        assert_eq!(
+            server.allocate(mac(1), Some(Ipv4Address::new(10, 0, 0, 5)), at(0), at(100)),
+            Some(Ipv4Address::new(192, 168, 4, 2))
+        );
+        assert_eq!(
+            server.allocate(
+                mac(2),
+                Some(Ipv4Address::new(192, 168, 4, 5)),
+                at(0),
+                at(100)
+            ),
+            Some(Ipv4Address::new(192, 168, 4, 5))
+        );
+    }
+
+    #[test]
+    fn renewal_keeps_the_address() {
+        let mut server = server();
+        let ip = server.allocate(mac(1), None, at(0), at(100)).unwrap();
+        server.allocate(mac(2), None, at(0), at(100)).unwrap();
+
+        let requested = Some(Ipv4Address::new(192, 168, 4, 9));
+        assert_eq!(
+            server.allocate(mac(1), requested, at(50), at(150)),
+            Some(ip)
+        );
+
+        let lease = server.leases().find(|lease| lease.mac == mac(1)).unwrap();
+        assert_eq!(lease.ip, ip);
+        assert_eq!(lease.expires, at(150));
+        assert_eq!(server.leases().count(), 2);
+    }
+}
@@ -1,19 +1,44 @@
-#![no_std]
-#![feature(c_variadic)]
+#![cfg_attr(not(feature = "mock"), no_std)]
+#![cfg_attr(not(feature = "mock"), feature(c_variadic))]
 
-pub mod binary;
+pub use esp_wifi_sys as binary;
+#[cfg(feature = "captive-dns")]
+pub mod captive_dns;
+#[cfg(not(feature = "mock"))]
 pub mod compat;
+pub mod config;
+pub mod connection;
+pub mod credentials;
+#[cfg(feature = "dhcp-server")]
+pub mod dhcp_server;
+#[cfg(not(feature = "mock"))]
 pub mod log;
+pub mod loopback;
+#[cfg(feature = "ping")]
+pub mod ping;
+#[cfg(not(feature = "mock"))]
 pub mod preempt;
+#[cfg(not(feature = "mock"))]
 pub mod timer;
+#[cfg(not(feature = "mock"))]
 pub mod wifi;
+pub mod wifi_interface;
 
+// host-side fake driver, see the `mock` feature in the README
+#[cfg(feature = "mock")]
+#[path = "mock/mod.rs"]
+pub mod wifi;
+
+#[cfg(not(feature = "mock"))]
 extern "C" {
     // ROM functions, see esp32c3-link.x
     pub fn uart_tx_one_char(byte: u8) -> i32;
 }
+
+#[cfg(not(feature = "mock"))]
 pub struct Uart;
 
+#[cfg(not(feature = "mock"))]
 impl core::fmt::Write for Uart {
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
         Ok(for &b in s.as_bytes() {
@@ -1,4 +1,5 @@
 #[derive(PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum LogLevel {
     None,
     Debug,
@@ -8,20 +9,197 @@ pub enum LogLevel {
 
 pub const LOG_LEVEL: LogLevel = LogLevel::None;
 
+/// Log levels of the driver blobs - the values match `esp_log_level_t`
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BlobLogLevel {
+    None = 0,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Verbose,
+}
+
+impl BlobLogLevel {
+    pub(crate) fn from_raw(level: u32) -> BlobLogLevel {
+        match level {
+            0 => BlobLogLevel::None,
+            1 => BlobLogLevel::Error,
+            2 => BlobLogLevel::Warn,
+            3 => BlobLogLevel::Info,
+            4 => BlobLogLevel::Debug,
+            _ => BlobLogLevel::Verbose,
+        }
+    }
+}
+
+/// `log` target used for the output of the driver blobs
+pub const BLOB_LOG_TARGET: &str = "esp32c3_wifi_rs::blob";
+
+static mut BLOB_LOG_LEVEL: BlobLogLevel = BlobLogLevel::Verbose;
+
+/// Set the maximum level of the driver blob's output which gets forwarded
+///
+/// Plain `printf` output of the blobs is treated as `Info`.
+pub fn set_blob_log_level(level: BlobLogLevel) {
+    unsafe {
+        BLOB_LOG_LEVEL = level;
+    }
+}
+
+pub fn blob_log_level() -> BlobLogLevel {
+    unsafe { BLOB_LOG_LEVEL }
+}
+
+/// Forwards a message of the driver blobs
+///
+/// With the `log` feature the message is logged with the target [BLOB_LOG_TARGET],
+/// otherwise it's printed as is.
+#[cfg(feature = "log")]
+pub(crate) fn blob_log_message(level: BlobLogLevel, message: &str) {
+    let level = match level {
+        BlobLogLevel::None => return,
+        BlobLogLevel::Error => log::Level::Error,
+        BlobLogLevel::Warn => log::Level::Warn,
+        BlobLogLevel::Info => log::Level::Info,
+        BlobLogLevel::Debug => log::Level::Debug,
+        BlobLogLevel::Verbose => log::Level::Trace,
+    };
+
+    let message = message.trim_end_matches(|c| c == '\r' || c == '\n');
+    if !message.is_empty() {
+        log::log!(target: BLOB_LOG_TARGET, level, "{}", message);
+    }
+}
+
+#[cfg(not(feature = "log"))]
+pub(crate) fn blob_log_message(_level: BlobLogLevel, message: &str) {
+    print_message(format_args!("{}", message), false);
+}
+
+/// Writes a log line prefixed with the current task
+///
+/// Goes to the ROM UART or - with the `defmt` feature - to defmt.
+#[doc(hidden)]
+#[cfg(all(not(feature = "defmt"), not(feature = "bounded-critical-sections")))]
+pub fn log_message(_level: LogLevel, args: core::fmt::Arguments) {
+    use core::fmt::Write;
+
+    critical_section::with(|_| {
+        write!(crate::Uart, "{}: ", crate::preempt::current_task()).ok();
+        crate::Uart.write_fmt(args).ok();
+        write!(crate::Uart, "\r\n").ok();
+    });
+}
+
+/// Bytes written to the UART per critical section - about 1.4ms at 115200 baud once the
+/// FIFO is full
+#[cfg(all(not(feature = "defmt"), feature = "bounded-critical-sections"))]
+const LOG_CHUNK_LEN: usize = 16;
+
+/// Formats the line first and writes it in chunks - lines of other tasks may get
+/// interleaved at the chunk boundaries
+#[doc(hidden)]
+#[cfg(all(not(feature = "defmt"), feature = "bounded-critical-sections"))]
+pub fn log_message(_level: LogLevel, args: core::fmt::Arguments) {
+    use core::fmt::Write;
+
+    let mut buffer = LogBuffer::new();
+    write!(buffer, "{}: ", crate::preempt::current_task()).ok();
+    buffer.write_fmt(args).ok();
+
+    let line = buffer.as_str().as_bytes();
+    for chunk in line.chunks(LOG_CHUNK_LEN).chain([&b"\r\n"[..]]) {
+        critical_section::with(|_| {
+            for &b in chunk {
+                unsafe { crate::uart_tx_one_char(b) };
+            }
+        });
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "defmt")]
+pub fn log_message(level: LogLevel, args: core::fmt::Arguments) {
+    let mut buffer = LogBuffer::new();
+    core::fmt::Write::write_fmt(&mut buffer, args).ok();
+    let task = crate::preempt::current_task();
+
+    match level {
+        LogLevel::None => (),
+        LogLevel::Debug => defmt::debug!("{}: {=str}", task, buffer.as_str()),
+        LogLevel::Verbose | LogLevel::Trace => {
+            defmt::trace!("{}: {=str}", task, buffer.as_str())
+        }
+    }
+}
+
+/// Writes the given text as is
+///
+/// Goes to the ROM UART or - with the `defmt` feature - to defmt.
+#[doc(hidden)]
+#[cfg(not(feature = "defmt"))]
+pub fn print_message(args: core::fmt::Arguments, newline: bool) {
+    use core::fmt::Write;
+
+    crate::Uart.write_fmt(args).ok();
+    if newline {
+        write!(crate::Uart, "\r\n").ok();
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "defmt")]
+pub fn print_message(args: core::fmt::Arguments, _newline: bool) {
+    // defmt frames are always complete lines
+    let mut buffer = LogBuffer::new();
+    core::fmt::Write::write_fmt(&mut buffer, args).ok();
+    defmt::println!("{=str}", buffer.as_str());
+}
+
+/// Formatting buffer - silently truncates overlong messages
+#[cfg(any(feature = "defmt", feature = "bounded-critical-sections"))]
+struct LogBuffer {
+    buffer: [u8; 256],
+    len: usize,
+}
+
+#[cfg(any(feature = "defmt", feature = "bounded-critical-sections"))]
+impl LogBuffer {
+    fn new() -> LogBuffer {
+        LogBuffer {
+            buffer: [0u8; 256],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // only complete `&str`s are ever appended
+        unsafe { core::str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+}
+
+#[cfg(any(feature = "defmt", feature = "bounded-critical-sections"))]
+impl core::fmt::Write for LogBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut len = usize::min(s.len(), self.buffer.len() - self.len);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        self.buffer[self.len..][..len].copy_from_slice(&s.as_bytes()[..len]);
+        self.len += len;
+        Ok(())
+    }
+}
+
 #[macro_export]
 macro_rules! trace {
     ($($arg:tt)*) => {
         #[allow(unused_unsafe)]
         if unsafe { $crate::log::LOG_LEVEL } >= $crate::log::LogLevel::Trace {
-            critical_section::with(|_| {
-                use core::fmt::Write;
-
-                unsafe {
-                    write!(crate::Uart, "{}: ", $crate::preempt::current_task()).ok();
-                }
-                write!(crate::Uart, $($arg)*).ok();
-                write!(crate::Uart, "\r\n").ok();
-            });
+            $crate::log::log_message($crate::log::LogLevel::Trace, format_args!($($arg)*));
         }
     };
 }
@@ -31,15 +209,7 @@ macro_rules! verbose {
     ($($arg:tt)*) => {
         #[allow(unused_unsafe)]
         if $crate::log::LOG_LEVEL >= $crate::log::LogLevel::Verbose {
-            critical_section::with(|_| {
-                use core::fmt::Write;
-
-                unsafe {
-                    write!(crate::Uart, "{}: ", $crate::preempt::current_task()).ok();
-                }
-                write!(crate::Uart, $($arg)*).ok();
-                write!(crate::Uart, "\r\n").ok();
-            });
+            $crate::log::log_message($crate::log::LogLevel::Verbose, format_args!($($arg)*));
         }
     };
 }
@@ -49,15 +219,7 @@ macro_rules! debug {
     ($($arg:tt)*) => {
         #[allow(unused_unsafe)]
         if $crate::log::LOG_LEVEL >= $crate::log::LogLevel::Debug {
-            critical_section::with(|_| {
-                use core::fmt::Write;
-
-                unsafe {
-                    write!(crate::Uart, "{}: ", $crate::preempt::current_task()).ok();
-                }
-                write!(crate::Uart, $($arg)*).ok();
-                write!(crate::Uart, "\r\n").ok();
-            });
+            $crate::log::log_message($crate::log::LogLevel::Debug, format_args!($($arg)*));
         }
     };
 }
@@ -67,10 +229,7 @@ macro_rules! println {
     ($($arg:tt)*) => {
         #[allow(unused_unsafe)]
         unsafe {
-            use core::fmt::Write;
-
-            write!(crate::Uart, $($arg)*).ok();
-            write!(crate::Uart, "\r\n").ok();
+            $crate::log::print_message(format_args!($($arg)*), true);
         }
     };
 }
@@ -78,9 +237,9 @@ macro_rules! println {
 #[macro_export]
 macro_rules! print {
     ($($arg:tt)*) => {
-        use core::fmt::Write;
-
         #[allow(unused_unsafe)]
-        write!(crate::Uart, $($arg)*).ok();
+        unsafe {
+            $crate::log::print_message(format_args!($($arg)*), false);
+        }
     };
 }
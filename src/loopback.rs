@@ -0,0 +1,162 @@
+//! A network device which receives everything it transmits
+//!
+//! Can be used in place of `WifiDevice` to run a full smoltcp stack (sockets, protocols)
+//! without using the radio - on target or on the host together with the `mock` feature.
+//! Frames are kept in a fixed size queue, no allocator is needed.
+
+use heapless::{Deque, Vec};
+use smoltcp::phy::{Device, DeviceCapabilities, RxToken, TxToken};
+
+pub const LOOPBACK_MTU: usize = 1514;
+
+type Frame = Vec<u8, LOOPBACK_MTU>;
+
+/// Loopback device holding up to `N` frames which were sent but not yet received
+pub struct LoopbackDevice<const N: usize> {
+    queue: Deque<Frame, N>,
+    dropped: u32,
+}
+
+impl<const N: usize> LoopbackDevice<N> {
+    pub const fn new() -> LoopbackDevice<N> {
+        LoopbackDevice {
+            queue: Deque::new(),
+            dropped: 0,
+        }
+    }
+
+    /// Number of frames waiting to be received
+    pub fn pending(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Number of frames dropped because the queue was full
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+}
+
+impl<const N: usize> Default for LoopbackDevice<N> {
+    fn default() -> Self {
+        LoopbackDevice::new()
+    }
+}
+
+impl<'a, const N: usize> Device<'a> for LoopbackDevice<N> {
+    type RxToken = LoopbackRxToken;
+
+    type TxToken = LoopbackTxToken<'a, N>;
+
+    fn receive(&'a mut self) -> Option<(Self::RxToken, Self::TxToken)> {
+        let frame = self.queue.pop_front()?;
+
+        Some((LoopbackRxToken { frame }, LoopbackTxToken { device: self }))
+    }
+
+    fn transmit(&'a mut self) -> Option<Self::TxToken> {
+        Some(LoopbackTxToken { device: self })
+    }
+
+    fn capabilities(&self) -> DeviceCapabilities {
+        let mut caps = DeviceCapabilities::default();
+        caps.max_transmission_unit = LOOPBACK_MTU;
+        caps.max_burst_size = Some(1);
+        caps
+    }
+}
+
+pub struct LoopbackRxToken {
+    frame: Frame,
+}
+
+impl RxToken for LoopbackRxToken {
+    fn consume<R, F>(mut self, _timestamp: smoltcp::time::Instant, f: F) -> smoltcp::Result<R>
+    where
+        F: FnOnce(&mut [u8]) -> smoltcp::Result<R>,
+    {
+        f(&mut self.frame[..])
+    }
+}
+
+pub struct LoopbackTxToken<'a, const N: usize> {
+    device: &'a mut LoopbackDevice<N>,
+}
+
+impl<'a, const N: usize> TxToken for LoopbackTxToken<'a, N> {
+    fn consume<R, F>(
+        self,
+        _timestamp: smoltcp::time::Instant,
+        len: usize,
+        f: F,
+    ) -> smoltcp::Result<R>
+    where
+        F: FnOnce(&mut [u8]) -> smoltcp::Result<R>,
+    {
+        let mut frame = Frame::new();
+        if frame.resize_default(len).is_err() {
+            return Err(smoltcp::Error::Truncated);
+        }
+
+        let res = f(&mut frame[..])?;
+
+        if self.device.queue.push_back(frame).is_err() {
+            self.device.dropped += 1;
+        }
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smoltcp::time::Instant;
+
+    fn send(device: &mut LoopbackDevice<2>, payload: &[u8]) {
+        device
+            .transmit()
+            .unwrap()
+            .consume(Instant::from_millis(0), payload.len(), |buffer| {
+                buffer.copy_from_slice(payload);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn receives_what_was_sent() {
+        let mut device = LoopbackDevice::<2>::default();
+        send(&mut device, b"frame");
+        assert_eq!(device.pending(), 1);
+
+        let (rx, _tx) = device.receive().unwrap();
+        let received = rx
+            .consume(Instant::from_millis(0), |buffer| {
+                assert_eq!(buffer, b"frame");
+                Ok(buffer.len())
+            })
+            .unwrap();
+        assert_eq!(received, 5);
+        assert_eq!(device.pending(), 0);
+        assert!(device.receive().is_none());
+    }
+
+    #[test]
+    fn drops_frames_once_full() {
+        let mut device = LoopbackDevice::<2>::new();
+        send(&mut device, b"one");
+        send(&mut device, b"two");
+        assert_eq!(device.dropped(), 0);
+
+        send(&mut device, b"three");
+        assert_eq!(device.pending(), 2);
+        assert_eq!(device.dropped(), 1);
+
+        let (rx, _tx) = device.receive().unwrap();
+        rx.consume(Instant::from_millis(0), |buffer| {
+            assert_eq!(buffer, b"one");
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,646 @@
+//! Host-side stand-in for the `wifi` module
+//!
+//! With the `mock` feature `crate::wifi` is this module. It fakes the layer the rest of
+//! the crate sits on - the driver's lifecycle, scans, connecting and the frame queues of
+//! the [WifiDevice]s - so application logic (reconnect loops, provisioning flows, the
+//! network stack on top of a [WifiDevice], ...) can be tested on the host. The behavior is
+//! scripted via the `mock_*` functions. The state is per thread so tests running in
+//! parallel don't interfere.
+//!
+//! The configuration APIs of the driver (SoftAP setup, roaming, sniffing, ...) aren't
+//! available with `mock`.
+
+use std::{cell::RefCell, collections::VecDeque, mem::MaybeUninit, vec::Vec};
+
+use smoltcp::phy::{Device, DeviceCapabilities, RxToken, TxToken};
+
+#[path = "../wifi/duty_cycle.rs"]
+pub mod duty_cycle;
+#[cfg(any(feature = "embedded-svc", feature = "embedded-svc-0-22"))]
+#[path = "../wifi/embedded_svc.rs"]
+pub mod embedded_svc;
+#[path = "../wifi/error.rs"]
+pub mod error;
+#[path = "../wifi/hooks.rs"]
+pub mod hooks;
+#[path = "../wifi/types.rs"]
+pub mod types;
+
+pub use duty_cycle::{frame_airtime_us, DutyCycle};
+#[cfg(any(feature = "embedded-svc", feature = "embedded-svc-0-22"))]
+pub use embedded_svc::WifiController;
+pub use error::WifiError;
+pub use hooks::{clear_driver_hooks, set_driver_hooks, DriverHooks};
+use hooks::{hook_error, hook_event, hook_rx, hook_security_event, hook_tx};
+use types::CONFIG_FEATURE_WPA3_SAE_BIT;
+pub use types::{
+    AccessPointInfo, AuthMethod, Capabilities, Capability, PowerSaveMode, SecurityEvent,
+    WifiDeviceMode, WifiEvent, WifiMode,
+};
+
+/// What happens on the next call to [wifi_connect]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectOutcome {
+    /// The connection gets established
+    Connected,
+    /// `wifi_connect` succeeds but the connection attempt fails with the given
+    /// disconnect reason (`wifi_err_reason_t`)
+    Failed(u8),
+    /// `wifi_connect` returns the given error
+    Error(WifiError),
+}
+
+struct MockState {
+    initialized: bool,
+    started: bool,
+    connected: bool,
+    mode: WifiMode,
+    mac: [u8; 6],
+    ap_mac: [u8; 6],
+    scan_results: Vec<AccessPointInfo>,
+    connect_outcomes: VecDeque<ConnectOutcome>,
+    connect_attempts: Vec<(String, String)>,
+    last_disconnect_reason: Option<u8>,
+    /// Of the access point the station connected to last
+    bssid: [u8; 6],
+    power_save: PowerSaveMode,
+    rx_frames: VecDeque<Vec<u8>>,
+    tx_frames: VecDeque<Vec<u8>>,
+    rx_frames_ap: VecDeque<Vec<u8>>,
+    tx_frames_ap: VecDeque<Vec<u8>>,
+}
+
+impl MockState {
+    fn new() -> MockState {
+        MockState {
+            initialized: false,
+            started: false,
+            connected: false,
+            mode: WifiMode::Sta,
+            mac: [0x02, 0x00, 0x00, 0x00, 0x00, 0x01],
+            ap_mac: [0x02, 0x00, 0x00, 0x00, 0x00, 0x02],
+            scan_results: Vec::new(),
+            connect_outcomes: VecDeque::new(),
+            connect_attempts: Vec::new(),
+            last_disconnect_reason: None,
+            bssid: [0; 6],
+            power_save: PowerSaveMode::None,
+            rx_frames: VecDeque::new(),
+            tx_frames: VecDeque::new(),
+            rx_frames_ap: VecDeque::new(),
+            tx_frames_ap: VecDeque::new(),
+        }
+    }
+}
+
+std::thread_local! {
+    static STATE: RefCell<MockState> = RefCell::new(MockState::new());
+}
+
+fn with_state<R>(f: impl FnOnce(&mut MockState) -> R) -> R {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+/// Resets all scripted behavior and state of the current thread
+pub fn mock_reset() {
+    with_state(|state| *state = MockState::new());
+}
+
+/// Sets the access points returned by scans
+pub fn mock_set_scan_results(results: &[AccessPointInfo]) {
+    with_state(|state| state.scan_results = results.to_vec());
+}
+
+/// Queues the outcome of the next call to [wifi_connect]
+///
+/// Without a queued outcome connecting succeeds.
+pub fn mock_push_connect_outcome(outcome: ConnectOutcome) {
+    with_state(|state| state.connect_outcomes.push_back(outcome));
+}
+
+/// The ssid and password of every call to [wifi_connect]
+pub fn mock_connect_attempts() -> Vec<(String, String)> {
+    with_state(|state| state.connect_attempts.clone())
+}
+
+/// Simulates beacons no longer arriving - follow it with
+/// `mock_disconnect(WIFI_REASON_BEACON_TIMEOUT)` if the access point stays away
+pub fn mock_beacon_timeout() {
+    hook_event(WifiEvent::StaBeaconTimeout);
+}
+
+/// Simulates losing the connection with the given reason (`wifi_err_reason_t`)
+pub fn mock_disconnect(reason: u8) {
+    let bssid = with_state(|state| {
+        state.connected = false;
+        state.last_disconnect_reason = Some(reason);
+        state.bssid
+    });
+    hook_event(WifiEvent::StaDisconnected { reason, bssid });
+    if let Some(event) = SecurityEvent::from_disconnect_reason(reason) {
+        hook_security_event(event);
+    }
+}
+
+/// Simulates the access point changing its authentication method
+pub fn mock_authmode_change(old: AuthMethod, new: AuthMethod) {
+    hook_event(WifiEvent::StaAuthmodeChange);
+    hook_security_event(SecurityEvent::AuthmodeChanged { old, new });
+}
+
+pub fn mock_set_mac(mac: [u8; 6]) {
+    with_state(|state| state.mac = mac);
+}
+
+/// Queues a frame to be received by the station's [WifiDevice]
+pub fn mock_inject_frame(frame: &[u8]) {
+    with_state(|state| state.rx_frames.push_back(frame.to_vec()));
+}
+
+/// Takes the oldest frame sent via the station's [WifiDevice]
+pub fn mock_take_transmitted() -> Option<Vec<u8>> {
+    with_state(|state| state.tx_frames.pop_front())
+}
+
+/// Queues a frame to be received by the SoftAP's [WifiDevice]
+pub fn mock_inject_ap_frame(frame: &[u8]) {
+    with_state(|state| state.rx_frames_ap.push_back(frame.to_vec()));
+}
+
+/// Takes the oldest frame sent via the SoftAP's [WifiDevice]
+pub fn mock_take_ap_transmitted() -> Option<Vec<u8>> {
+    with_state(|state| state.tx_frames_ap.pop_front())
+}
+
+pub fn init_buffer() {}
+
+pub fn init_clocks() {}
+
+pub fn wifi_set_log_verbose() {}
+
+pub fn send_data_if_needed() {}
+
+pub fn wifi_init() -> Result<(), WifiError> {
+    with_state(|state| state.initialized = true);
+    hook_event(WifiEvent::WifiReady);
+    Ok(())
+}
+
+pub fn wifi_start() -> Result<(), WifiError> {
+    if !with_state(|state| state.initialized) {
+        return error(WifiError::NotInitialized);
+    }
+
+    with_state(|state| state.started = true);
+    hook_event(WifiEvent::StaStart);
+    Ok(())
+}
+
+pub fn wifi_stop() -> Result<(), WifiError> {
+    with_state(|state| {
+        state.started = false;
+        state.connected = false;
+    });
+    hook_event(WifiEvent::StaStop);
+    Ok(())
+}
+
+pub fn wifi_set_mode(mode: WifiMode) -> Result<(), WifiError> {
+    if !with_state(|state| state.initialized) {
+        return error(WifiError::NotInitialized);
+    }
+
+    with_state(|state| state.mode = mode);
+    Ok(())
+}
+
+pub fn wifi_get_mode() -> WifiMode {
+    with_state(|state| state.mode)
+}
+
+pub fn wifi_start_scan() -> Result<(), WifiError> {
+    if !with_state(|state| state.started) {
+        return error(WifiError::NotStarted);
+    }
+
+    hook_event(WifiEvent::ScanDone);
+    Ok(())
+}
+
+pub fn wifi_scan_n<const N: usize>() -> Result<heapless::Vec<AccessPointInfo, N>, WifiError> {
+    scan_n_with_total().map(|(result, _)| result)
+}
+
+pub(crate) fn scan_n_with_total<const N: usize>(
+) -> Result<(heapless::Vec<AccessPointInfo, N>, usize), WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    wifi_start_scan()?;
+
+    Ok(with_state(|state| {
+        (
+            state.scan_results.iter().take(N).cloned().collect(),
+            state.scan_results.len(),
+        )
+    }))
+}
+
+pub fn wifi_scan_into(
+    buffer: &mut [MaybeUninit<AccessPointInfo>],
+) -> Result<&mut [AccessPointInfo], WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    wifi_start_scan()?;
+
+    let count = with_state(|state| {
+        let count = usize::min(state.scan_results.len(), buffer.len());
+        for (slot, ap) in buffer.iter_mut().zip(state.scan_results.iter()) {
+            slot.write(ap.clone());
+        }
+        count
+    });
+
+    Ok(unsafe {
+        std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut AccessPointInfo, count)
+    })
+}
+
+pub fn wifi_connect(ssid: &str, password: &str) -> Result<(), WifiError> {
+    if !with_state(|state| state.started) {
+        return error(WifiError::NotStarted);
+    }
+
+    if !wifi_get_mode().is_sta() {
+        return error(WifiError::WrongMode);
+    }
+
+    if ssid.len() > 32 {
+        return error(WifiError::InvalidSsid);
+    }
+
+    if password.len() > 64 {
+        return error(WifiError::InvalidPassword);
+    }
+
+    let outcome = with_state(|state| {
+        state
+            .connect_attempts
+            .push((String::from(ssid), String::from(password)));
+
+        let outcome = state
+            .connect_outcomes
+            .pop_front()
+            .unwrap_or(ConnectOutcome::Connected);
+
+        state.bssid = state
+            .scan_results
+            .iter()
+            .find(|ap| ap.ssid.as_str() == ssid)
+            .map(|ap| ap.bssid)
+            .unwrap_or_default();
+
+        match outcome {
+            ConnectOutcome::Connected => {
+                state.connected = true;
+                state.last_disconnect_reason = None;
+            }
+            ConnectOutcome::Failed(reason) => {
+                state.connected = false;
+                state.last_disconnect_reason = Some(reason);
+            }
+            ConnectOutcome::Error(_) => (),
+        }
+
+        (outcome, state.bssid)
+    });
+    let (outcome, bssid) = outcome;
+
+    // hooks are called outside of `with_state` so they can use the mock functions
+    match outcome {
+        ConnectOutcome::Connected => {
+            hook_event(WifiEvent::StaConnected);
+            Ok(())
+        }
+        ConnectOutcome::Failed(reason) => {
+            hook_event(WifiEvent::StaDisconnected { reason, bssid });
+            if let Some(event) = SecurityEvent::from_disconnect_reason(reason) {
+                hook_security_event(event);
+            }
+            Ok(())
+        }
+        ConnectOutcome::Error(err) => error(err),
+    }
+}
+
+fn error(error: WifiError) -> Result<(), WifiError> {
+    hook_error(error);
+    Err(error)
+}
+
+/// The same as the real driver with its default feature caps
+pub fn get_capabilities() -> Capabilities {
+    Capabilities::from_feature_caps(CONFIG_FEATURE_WPA3_SAE_BIT)
+}
+
+/// `WIFI_REASON_ASSOC_LEAVE`
+const ASSOC_LEAVE: u8 = 8;
+
+pub fn wifi_disconnect() -> Result<(), WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return error(WifiError::WrongMode);
+    }
+
+    let (was_connected, bssid) = with_state(|state| {
+        let was_connected = state.connected;
+        state.connected = false;
+        if was_connected {
+            state.last_disconnect_reason = Some(ASSOC_LEAVE);
+        }
+        (was_connected, state.bssid)
+    });
+
+    if was_connected {
+        hook_event(WifiEvent::StaDisconnected {
+            reason: ASSOC_LEAVE,
+            bssid,
+        });
+    }
+    Ok(())
+}
+
+pub fn set_power_saving(mode: PowerSaveMode) -> Result<(), WifiError> {
+    with_state(|state| state.power_save = mode);
+    Ok(())
+}
+
+pub fn power_saving() -> PowerSaveMode {
+    with_state(|state| state.power_save)
+}
+
+pub fn is_connected() -> bool {
+    with_state(|state| state.connected)
+}
+
+pub fn last_disconnect_error() -> Option<WifiError> {
+    with_state(|state| {
+        state
+            .last_disconnect_reason
+            .map(WifiError::from_disconnect_reason)
+    })
+}
+
+pub fn get_sta_mac(mac: &mut [u8; 6]) {
+    with_state(|state| *mac = state.mac);
+}
+
+pub fn get_ap_mac(mac: &mut [u8; 6]) {
+    with_state(|state| *mac = state.ap_mac);
+}
+
+pub struct WifiDevice {
+    mode: WifiDeviceMode,
+}
+
+impl Default for WifiDevice {
+    fn default() -> Self {
+        WifiDevice::new()
+    }
+}
+
+impl WifiDevice {
+    pub fn new() -> WifiDevice {
+        WifiDevice::new_sta()
+    }
+
+    pub fn new_sta() -> WifiDevice {
+        WifiDevice {
+            mode: WifiDeviceMode::Sta,
+        }
+    }
+
+    pub fn new_ap() -> WifiDevice {
+        WifiDevice {
+            mode: WifiDeviceMode::Ap,
+        }
+    }
+
+    pub fn new_ap_sta() -> (WifiDevice, WifiDevice) {
+        (
+            WifiDevice {
+                mode: WifiDeviceMode::Sta,
+            },
+            WifiDevice {
+                mode: WifiDeviceMode::Ap,
+            },
+        )
+    }
+
+    pub fn mode(&self) -> WifiDeviceMode {
+        self.mode
+    }
+
+    /// The MAC address of the device's interface - use it as the ethernet address
+    pub fn mac_address(&self) -> [u8; 6] {
+        let mut mac = [0u8; 6];
+        match self.mode {
+            WifiDeviceMode::Sta => get_sta_mac(&mut mac),
+            WifiDeviceMode::Ap => get_ap_mac(&mut mac),
+        }
+        mac
+    }
+}
+
+impl<'a> Device<'a> for WifiDevice {
+    type RxToken = WifiRxToken;
+
+    type TxToken = WifiTxToken;
+
+    fn receive(&'a mut self) -> Option<(Self::RxToken, Self::TxToken)> {
+        let frame = with_state(|state| match self.mode {
+            WifiDeviceMode::Sta => state.rx_frames.pop_front(),
+            WifiDeviceMode::Ap => state.rx_frames_ap.pop_front(),
+        })?;
+        hook_rx(frame.len());
+
+        Some((WifiRxToken { frame }, WifiTxToken { mode: self.mode }))
+    }
+
+    fn transmit(&'a mut self) -> Option<Self::TxToken> {
+        Some(WifiTxToken { mode: self.mode })
+    }
+
+    fn capabilities(&self) -> DeviceCapabilities {
+        let mut caps = DeviceCapabilities::default();
+        caps.max_transmission_unit = 1514;
+        caps.max_burst_size = Some(1);
+        caps
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WifiRxToken {
+    frame: Vec<u8>,
+}
+
+impl RxToken for WifiRxToken {
+    fn consume<R, F>(mut self, _timestamp: smoltcp::time::Instant, f: F) -> smoltcp::Result<R>
+    where
+        F: FnOnce(&mut [u8]) -> smoltcp::Result<R>,
+    {
+        f(&mut self.frame[..])
+    }
+}
+
+#[derive(Debug)]
+pub struct WifiTxToken {
+    mode: WifiDeviceMode,
+}
+
+impl TxToken for WifiTxToken {
+    fn consume<R, F>(
+        self,
+        _timestamp: smoltcp::time::Instant,
+        len: usize,
+        f: F,
+    ) -> smoltcp::Result<R>
+    where
+        F: FnOnce(&mut [u8]) -> smoltcp::Result<R>,
+    {
+        let mut frame = vec![0u8; len];
+        let res = f(&mut frame[..]);
+
+        if res.is_ok() {
+            with_state(|state| match self.mode {
+                WifiDeviceMode::Sta => state.tx_frames.push_back(frame),
+                WifiDeviceMode::Ap => state.tx_frames_ap.push_back(frame),
+            });
+            hook_tx(len);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smoltcp::time::Instant;
+
+    fn started() {
+        mock_reset();
+        wifi_init().unwrap();
+        wifi_start().unwrap();
+    }
+
+    fn ap(ssid: &str, bssid: [u8; 6]) -> AccessPointInfo {
+        AccessPointInfo {
+            ssid: heapless::String::from(ssid),
+            bssid,
+            channel: 6,
+            signal_strength: -50,
+            auth_method: AuthMethod::WPA2Personal,
+        }
+    }
+
+    #[test]
+    fn connect_needs_a_started_driver() {
+        mock_reset();
+        wifi_init().unwrap();
+
+        assert_eq!(wifi_connect("net", "secret"), Err(WifiError::NotStarted));
+        assert!(!is_connected());
+    }
+
+    #[test]
+    fn scripted_connect_outcomes() {
+        started();
+        mock_push_connect_outcome(ConnectOutcome::Failed(15));
+        mock_push_connect_outcome(ConnectOutcome::Error(WifiError::InvalidArg));
+
+        wifi_connect("net", "wrong").unwrap();
+        assert!(!is_connected());
+        assert_eq!(
+            last_disconnect_error(),
+            Some(WifiError::from_disconnect_reason(15))
+        );
+
+        assert_eq!(wifi_connect("net", "secret"), Err(WifiError::InvalidArg));
+
+        wifi_connect("net", "secret").unwrap();
+        assert!(is_connected());
+        assert_eq!(last_disconnect_error(), None);
+        assert_eq!(mock_connect_attempts().len(), 3);
+
+        mock_disconnect(200);
+        assert!(!is_connected());
+    }
+
+    #[test]
+    fn connect_rejects_too_long_credentials() {
+        started();
+
+        let ssid = "s".repeat(33);
+        assert_eq!(wifi_connect(&ssid, ""), Err(WifiError::InvalidSsid));
+        let password = "p".repeat(65);
+        assert_eq!(
+            wifi_connect("net", &password),
+            Err(WifiError::InvalidPassword)
+        );
+        assert!(mock_connect_attempts().is_empty());
+    }
+
+    #[test]
+    fn scans_return_the_scripted_access_points() {
+        started();
+        mock_set_scan_results(&[ap("a", [1; 6]), ap("b", [2; 6])]);
+
+        let results = wifi_scan_n::<1>().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].ssid.as_str(), "a");
+
+        let (results, total) = scan_n_with_total::<1>().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(total, 2);
+
+        let mut buffer = [
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+        ];
+        assert_eq!(wifi_scan_into(&mut buffer).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn devices_use_the_frame_queues_of_their_interface() {
+        started();
+        let (mut sta, mut ap) = WifiDevice::new_ap_sta();
+
+        mock_inject_frame(&[1, 2, 3]);
+        assert!(ap.receive().is_none());
+        let (rx, _) = sta.receive().unwrap();
+        rx.consume(Instant::from_millis(0), |frame| {
+            assert_eq!(frame, &[1, 2, 3]);
+            Ok(())
+        })
+        .unwrap();
+
+        let tx = ap.transmit().unwrap();
+        tx.consume(Instant::from_millis(0), 2, |frame| {
+            frame.copy_from_slice(&[4, 5]);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(mock_take_transmitted(), None);
+        assert_eq!(mock_take_ap_transmitted(), Some(vec![4, 5]));
+    }
+
+    #[test]
+    fn capabilities_match_the_driver() {
+        assert!(get_capabilities().contains(Capability::WPA3));
+        assert!(!get_capabilities().contains(Capability::Enterprise));
+    }
+}
@@ -0,0 +1,163 @@
+//! ICMP echo ("ping") helper
+//!
+//! [Pinger] works with any smoltcp socket set. With [WifiInterface] the blocking
+//! [WifiInterface::ping] does everything in one call.
+
+use smoltcp::{
+    phy::ChecksumCapabilities,
+    socket::{
+        IcmpEndpoint, IcmpPacketMetadata, IcmpSocket, IcmpSocketBuffer, SocketHandle, SocketSet,
+    },
+    time::{Duration, Instant},
+    wire::{Icmpv4Packet, Icmpv4Repr, IpAddress, Ipv4Address},
+};
+
+#[cfg(not(feature = "mock"))]
+use crate::wifi_interface::timestamp;
+use crate::wifi_interface::WifiInterface;
+
+const PAYLOAD: &[u8] = b"esp32c3-wifi-rs ping";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PingError {
+    /// The request couldn't be queued
+    SendFailed,
+    /// No reply within the timeout
+    Timeout,
+}
+
+/// Memory used by the ICMP socket of a [Pinger]
+pub struct PingStorage {
+    rx_metadata: [IcmpPacketMetadata; 1],
+    rx_buffer: [u8; 128],
+    tx_metadata: [IcmpPacketMetadata; 1],
+    tx_buffer: [u8; 128],
+}
+
+impl PingStorage {
+    pub const fn new() -> PingStorage {
+        PingStorage {
+            rx_metadata: [IcmpPacketMetadata::EMPTY; 1],
+            rx_buffer: [0u8; 128],
+            tx_metadata: [IcmpPacketMetadata::EMPTY; 1],
+            tx_buffer: [0u8; 128],
+        }
+    }
+}
+
+impl Default for PingStorage {
+    fn default() -> Self {
+        PingStorage::new()
+    }
+}
+
+pub struct Pinger {
+    handle: SocketHandle,
+    ident: u16,
+    seq_no: u16,
+    sent_at: Option<Instant>,
+}
+
+impl Pinger {
+    /// Adds an ICMP socket to `sockets`
+    pub fn new<'a>(
+        sockets: &mut SocketSet<'a>,
+        storage: &'a mut PingStorage,
+        ident: u16,
+    ) -> Pinger {
+        let mut socket = IcmpSocket::new(
+            IcmpSocketBuffer::new(&mut storage.rx_metadata[..], &mut storage.rx_buffer[..]),
+            IcmpSocketBuffer::new(&mut storage.tx_metadata[..], &mut storage.tx_buffer[..]),
+        );
+        socket.bind(IcmpEndpoint::Ident(ident)).ok();
+
+        Pinger {
+            handle: sockets.add(socket),
+            ident,
+            seq_no: 0,
+            sent_at: None,
+        }
+    }
+
+    /// Queues an echo request - the reply is checked via [Pinger::poll_reply]
+    pub fn send(
+        &mut self,
+        sockets: &mut SocketSet,
+        target: Ipv4Address,
+        now: Instant,
+    ) -> Result<(), PingError> {
+        let mut socket = sockets.get::<IcmpSocket>(self.handle);
+
+        // drop stale replies
+        while socket.recv().is_ok() {}
+
+        self.seq_no = self.seq_no.wrapping_add(1);
+        let repr = Icmpv4Repr::EchoRequest {
+            ident: self.ident,
+            seq_no: self.seq_no,
+            data: PAYLOAD,
+        };
+
+        let buffer = socket
+            .send(repr.buffer_len(), IpAddress::Ipv4(target))
+            .map_err(|_| PingError::SendFailed)?;
+        repr.emit(
+            &mut Icmpv4Packet::new_unchecked(buffer),
+            &ChecksumCapabilities::default(),
+        );
+
+        self.sent_at = Some(now);
+        Ok(())
+    }
+
+    /// The round-trip time once the reply to the last request arrived
+    pub fn poll_reply(&mut self, sockets: &mut SocketSet, now: Instant) -> Option<Duration> {
+        let sent_at = self.sent_at?;
+        let mut socket = sockets.get::<IcmpSocket>(self.handle);
+
+        while let Ok((payload, _)) = socket.recv() {
+            let packet = match Icmpv4Packet::new_checked(payload) {
+                Ok(packet) => packet,
+                Err(_) => continue,
+            };
+
+            if let Ok(Icmpv4Repr::EchoReply { ident, seq_no, .. }) =
+                Icmpv4Repr::parse(&packet, &ChecksumCapabilities::default())
+            {
+                if ident == self.ident && seq_no == self.seq_no {
+                    self.sent_at = None;
+                    return Some(now - sent_at);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a> WifiInterface<'a> {
+    /// Sends an echo request and waits for the reply - returns the round-trip time
+    #[cfg(not(feature = "mock"))]
+    pub fn ping(
+        &mut self,
+        pinger: &mut Pinger,
+        target: Ipv4Address,
+        timeout: Duration,
+    ) -> Result<Duration, PingError> {
+        let start = timestamp();
+        pinger.send(self.sockets(), target, start)?;
+
+        loop {
+            self.poll();
+
+            if let Some(rtt) = pinger.poll_reply(self.sockets(), timestamp()) {
+                return Ok(rtt);
+            }
+
+            if timestamp() - start > timeout {
+                return Err(PingError::Timeout);
+            }
+        }
+    }
+}
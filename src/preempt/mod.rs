@@ -1,5 +1,7 @@
 use hal::interrupt::TrapFrame;
 
+use crate::config::{DRIVER_TASK_STACK_SIZE, TIMER_TASK_STACK_SIZE};
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct Context {
     trap_frame: TrapFrame,
@@ -7,13 +9,29 @@ pub struct Context {
     _running: bool,
 }
 
-const STACK_SIZE: usize = 8192 * 2; // TODO how much is enough? would be better to have this per task
-const MAX_TASK: usize = 3;
+/// Stack sizes of the tasks in the order they are created - the main task keeps the
+/// stack it was started with
+const STACK_SIZES: [usize; 2] = [DRIVER_TASK_STACK_SIZE, TIMER_TASK_STACK_SIZE];
+/// Names of the tasks in [STACK_SIZES] as in their `ESP32C3_WIFI_<name>_TASK_STACK_SIZE`
+const TASK_NAMES: [&str; STACK_SIZES.len()] = ["DRIVER", "TIMER"];
+const STACKS_SIZE: usize = DRIVER_TASK_STACK_SIZE + TIMER_TASK_STACK_SIZE;
+const MAX_TASK: usize = STACK_SIZES.len() + 1;
+
+// placed in the memory region selected by ESP32C3_WIFI_BUFFERS_REGION (see build.rs)
+#[link_section = ".wifi_buffers"]
+static mut TASK_STACK: [u8; STACKS_SIZE] = [0u8; STACKS_SIZE];
 
-static mut TASK_STACK: [u8; STACK_SIZE * MAX_TASK] = [0u8; STACK_SIZE * MAX_TASK];
+/// Stacks are filled with this at creation - overwritten bytes show how much was used
+const STACK_FILL: u8 = 0xa5;
+/// Bytes at the bottom of each stack checked on every task switch
+const STACK_GUARD_SIZE: usize = 64;
 
 static mut FIRST_SWITCH: bool = true;
 
+/// The first task found to have written into its stack guard
+static mut STACK_OVERFLOW: Option<usize> = None;
+static mut STACK_OVERFLOW_REPORTED: bool = false;
+
 static mut TASK_TOP: usize = 0;
 
 static mut CTX_NOW: usize = 0;
@@ -61,16 +79,81 @@ pub fn task_create(task: extern "C" fn()) -> usize {
         let i = TASK_TOP;
         TASK_TOP += 1;
         CTX_TASKS[i].pc = task as usize;
-        CTX_TASKS[i].trap_frame.sp = &TASK_STACK as *const _ as usize
-            + (STACK_SIZE as usize * i as usize)
-            + STACK_SIZE as usize
-            - 4;
+        let stack = task_stack(i);
+        core::ptr::write_bytes(stack, STACK_FILL, STACK_SIZES[i]);
+        CTX_TASKS[i].trap_frame.sp = stack as usize + STACK_SIZES[i] - 4;
 
         CTX_NOW = i;
         i
     }
 }
 
+unsafe fn task_stack(task: usize) -> *mut u8 {
+    let start: usize = STACK_SIZES[..task].iter().sum();
+    (core::ptr::addr_of_mut!(TASK_STACK) as *mut u8).add(start)
+}
+
+/// Bytes of the stack of `task` never used so far - `None` for tasks without a stack of
+/// their own (the main task)
+pub fn stack_free(task: usize) -> Option<usize> {
+    unsafe {
+        if task >= TASK_TOP || task >= STACK_SIZES.len() {
+            return None;
+        }
+
+        let stack = task_stack(task);
+        Some(
+            (0..STACK_SIZES[task])
+                .take_while(|&offset| stack.add(offset).read_volatile() == STACK_FILL)
+                .count(),
+        )
+    }
+}
+
+/// Notes the first task which wrote into the guard bytes at the bottom of its stack - it
+/// has overflown or is about to, which would corrupt the memory below
+///
+/// Runs on every task switch, i.e. in the timer interrupt - [report_stack_overflow] prints it.
+fn check_stacks() {
+    unsafe {
+        if let Some(_) = STACK_OVERFLOW {
+            return;
+        }
+
+        for task in 0..TASK_TOP.min(STACK_SIZES.len()) {
+            let stack = task_stack(task);
+            let intact =
+                (0..STACK_GUARD_SIZE).all(|offset| stack.add(offset).read_volatile() == STACK_FILL);
+            if !intact {
+                STACK_OVERFLOW = Some(task);
+                return;
+            }
+        }
+    }
+}
+
+/// The name of the first task which overflowed its stack, see [TASK_NAMES]
+pub fn stack_overflow() -> Option<&'static str> {
+    unsafe { STACK_OVERFLOW.map(|task| TASK_NAMES[task]) }
+}
+
+/// Prints a stack overflow found by [check_stacks] once - called from the timer task
+pub(crate) fn report_stack_overflow() {
+    unsafe {
+        if let Some(task) = STACK_OVERFLOW {
+            if !STACK_OVERFLOW_REPORTED {
+                STACK_OVERFLOW_REPORTED = true;
+                crate::println!(
+                    "!!! stack overflow in task {} - increase ESP32C3_WIFI_{}_TASK_STACK_SIZE (now {} bytes)",
+                    task,
+                    TASK_NAMES[task],
+                    STACK_SIZES[task]
+                );
+            }
+        }
+    }
+}
+
 fn task_create_from_mepc(mepc: usize) -> usize {
     unsafe {
         let i = TASK_TOP;
@@ -175,6 +258,8 @@ pub fn task_switch(trap_frame: &mut TrapFrame) {
 
         trap_frame_to_task(CTX_NOW, old_mepc, trap_frame);
 
+        check_stacks();
+
         next_task();
 
         let new_pc = task_to_trap_frame(CTX_NOW, trap_frame);
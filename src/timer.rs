@@ -7,9 +7,9 @@ use crate::{
         queue::SimpleQueue,
         timer_compat::{Timer, TIMERS},
     },
-    preempt::{task_create, task_switch},
+    preempt::{report_stack_overflow, task_create, task_switch},
     trace,
-    wifi::send_data_if_needed,
+    wifi::{deauth_rejected_stations, send_data_if_needed, watchdog::feed_watchdog},
     Uart,
 };
 use core::fmt::Write;
@@ -52,7 +52,7 @@ pub fn setup_timer_isr(peripherals: &Peripherals) {
     peripherals
         .SYSTIMER
         .target0_conf
-        .write(|w| unsafe { w.bits((1 << 30) | 20_000) });
+        .write(|w| unsafe { w.bits((1 << 30) | (crate::config::TICK_PERIOD_US * 16)) });
     // LOAD CONF VALUE
     peripherals
         .SYSTIMER
@@ -90,6 +90,7 @@ pub fn setup_timer_isr(peripherals: &Peripherals) {
 }
 
 pub fn init_tasks() {
+    // same order as the stack sizes and task names in `preempt`
     task_create(worker_task1);
     task_create(worker_task2);
 }
@@ -114,7 +115,8 @@ pub extern "C" fn worker_task2() {
             for i in 0..TIMERS.len() {
                 TIMERS[i] = match &TIMERS[i] {
                     Some(old) => {
-                        if old.active && get_systimer_count() >= old.expire {
+                        // leave due timers for the next round if there is no room to run them now
+                        if old.active && get_systimer_count() >= old.expire && !to_run.is_full() {
                             trace!("timer is due....");
                             let fnctn: fn(*mut crate::binary::c_types::c_void) =
                                 core::mem::transmute(old.timer_ptr);
@@ -152,6 +154,12 @@ pub extern "C" fn worker_task2() {
         }
 
         send_data_if_needed();
+
+        deauth_rejected_stations();
+
+        feed_watchdog();
+
+        report_stack_overflow();
     }
 }
 
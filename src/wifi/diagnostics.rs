@@ -0,0 +1,197 @@
+#![allow(non_upper_case_globals)]
+
+//! Compact health report of the driver
+//!
+//! The counters wrap around instead of overflowing - compare two reports with
+//! `wrapping_sub`.
+
+use crate::{
+    binary::include::*,
+    compat::malloc::{heap_stats, HeapStats},
+    preempt::{stack_free, stack_overflow},
+    timer::get_systimer_count,
+};
+
+use super::{
+    error::esp_result, is_connected, last_disconnect_error, types::WifiEvent, WifiError,
+    DATA_QUEUE_RX, LAST_EVENT, TX_QUEUED,
+};
+
+pub(crate) static mut RX_FRAMES: u32 = 0;
+pub(crate) static mut RX_DROPPED: u32 = 0;
+pub(crate) static mut TX_FRAMES: u32 = 0;
+pub(crate) static mut TX_ERRORS: u32 = 0;
+
+static mut TX_FAILED: u32 = 0;
+static mut BEACON_TIMEOUTS: u32 = 0;
+static mut DEAUTH_DISASSOC: u32 = 0;
+static mut LOCAL_DISCONNECTS: u32 = 0;
+
+#[cfg(feature = "bounded-critical-sections")]
+static mut BLOB_CRITICAL_SECTION_START: u64 = 0;
+#[cfg(feature = "bounded-critical-sections")]
+static mut MAX_BLOB_CRITICAL_SECTION: u64 = 0;
+
+/// Counters of link level problems
+///
+/// The driver doesn't expose its internal counters (retries, FCS errors, ...) - use
+/// [dump_mac_statistics] to get them printed.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MacCounters {
+    /// Frames which couldn't be delivered after all retries
+    pub tx_failed: u32,
+    /// Beacons from the AP were missed
+    pub beacon_timeouts: u32,
+    /// Deauthentications / disassociations by the access point
+    pub deauth_disassoc: u32,
+    /// Disconnects decided locally (leaving, beacon timeout, handshake timeout, AP not
+    /// found, ...)
+    pub local_disconnects: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Diagnostics {
+    /// The last event posted by the driver - `None` before the driver was started
+    pub state: Option<WifiEvent>,
+    pub connected: bool,
+    pub last_disconnect_error: Option<WifiError>,
+    /// Received frames waiting to be consumed
+    pub rx_queue_len: usize,
+    pub rx_queue_capacity: usize,
+    /// A frame is waiting to be handed to the driver
+    pub tx_queued: bool,
+    pub heap: HeapStats,
+    pub uptime_ms: u64,
+    pub rx_frames: u32,
+    /// Received frames dropped because the queue was full or the frame too large
+    pub rx_dropped: u32,
+    pub tx_frames: u32,
+    /// Frames the driver refused to send
+    pub tx_errors: u32,
+    pub mac: MacCounters,
+    /// Bytes of the driver and the timer task's stacks never used so far - see
+    /// `ESP32C3_WIFI_DRIVER_TASK_STACK_SIZE` and `ESP32C3_WIFI_TIMER_TASK_STACK_SIZE`
+    pub stack_free: [Option<usize>; 2],
+    /// The first task which wrote into the guard bytes at the bottom of its stack - `DRIVER`
+    /// or `TIMER`, raise its `ESP32C3_WIFI_<name>_TASK_STACK_SIZE`. Memory below the stack
+    /// may be corrupted already
+    pub stack_overflow: Option<&'static str>,
+    /// The longest time the blobs kept interrupts disabled - only measured with the
+    /// `bounded-critical-sections` feature
+    pub max_blob_critical_section_us: Option<u32>,
+}
+
+pub fn diagnostics() -> Diagnostics {
+    let (rx_queue_len, rx_queue_capacity) = critical_section::with(|_| unsafe {
+        match DATA_QUEUE_RX {
+            Some(ref queue) => (queue.len(), queue.capacity()),
+            None => (0, 0),
+        }
+    });
+
+    unsafe {
+        Diagnostics {
+            state: LAST_EVENT,
+            connected: is_connected(),
+            last_disconnect_error: last_disconnect_error(),
+            rx_queue_len,
+            rx_queue_capacity,
+            tx_queued: TX_QUEUED,
+            heap: heap_stats(),
+            uptime_ms: get_systimer_count() / 16_000,
+            rx_frames: RX_FRAMES,
+            rx_dropped: RX_DROPPED,
+            tx_frames: TX_FRAMES,
+            tx_errors: TX_ERRORS,
+            mac: MacCounters {
+                tx_failed: TX_FAILED,
+                beacon_timeouts: BEACON_TIMEOUTS,
+                deauth_disassoc: DEAUTH_DISASSOC,
+                local_disconnects: LOCAL_DISCONNECTS,
+            },
+            stack_free: [stack_free(0), stack_free(1)],
+            stack_overflow: stack_overflow(),
+            #[cfg(feature = "bounded-critical-sections")]
+            max_blob_critical_section_us: Some((MAX_BLOB_CRITICAL_SECTION / 16) as u32),
+            #[cfg(not(feature = "bounded-critical-sections"))]
+            max_blob_critical_section_us: None,
+        }
+    }
+}
+
+/// Called with interrupts disabled when the blobs enter their outermost critical section
+#[cfg(feature = "bounded-critical-sections")]
+pub(crate) fn blob_critical_section_entered() {
+    unsafe { BLOB_CRITICAL_SECTION_START = get_systimer_count() };
+}
+
+/// Called with interrupts still disabled when the blobs leave it
+#[cfg(feature = "bounded-critical-sections")]
+pub(crate) fn blob_critical_section_left() {
+    unsafe {
+        let duration = get_systimer_count().saturating_sub(BLOB_CRITICAL_SECTION_START);
+        MAX_BLOB_CRITICAL_SECTION = MAX_BLOB_CRITICAL_SECTION.max(duration);
+    }
+}
+
+/// Lets the driver print its internal statistics (buffers, rx/tx, hardware counters, ...)
+pub fn dump_mac_statistics() -> Result<(), WifiError> {
+    unsafe { esp_result(esp_wifi_statis_dump(WIFI_STATIS_ALL as u32)) }
+}
+
+pub(crate) fn count_tx_done(success: bool) {
+    if !success {
+        unsafe {
+            TX_FAILED = TX_FAILED.wrapping_add(1);
+        }
+    }
+}
+
+pub(crate) fn count_beacon_timeout() {
+    unsafe {
+        BEACON_TIMEOUTS = BEACON_TIMEOUTS.wrapping_add(1);
+    }
+}
+
+pub(crate) fn count_disconnect(reason: u8) {
+    unsafe {
+        if is_deauth_disassoc(reason) {
+            DEAUTH_DISASSOC = DEAUTH_DISASSOC.wrapping_add(1);
+        } else {
+            LOCAL_DISCONNECTS = LOCAL_DISCONNECTS.wrapping_add(1);
+        }
+    }
+}
+
+/// Reason codes the access point sends in deauthentication / disassociation frames
+///
+/// Leaving (`ASSOC_LEAVE`), the handshake timeouts of the supplicant and the driver's own
+/// reasons from 200 on are decided locally.
+fn is_deauth_disassoc(reason: u8) -> bool {
+    matches!(
+        reason as wifi_err_reason_t,
+        wifi_err_reason_t_WIFI_REASON_UNSPECIFIED
+            | wifi_err_reason_t_WIFI_REASON_AUTH_EXPIRE
+            | wifi_err_reason_t_WIFI_REASON_AUTH_LEAVE
+            | wifi_err_reason_t_WIFI_REASON_ASSOC_EXPIRE
+            | wifi_err_reason_t_WIFI_REASON_ASSOC_TOOMANY
+            | wifi_err_reason_t_WIFI_REASON_NOT_AUTHED
+            | wifi_err_reason_t_WIFI_REASON_NOT_ASSOCED
+            | wifi_err_reason_t_WIFI_REASON_ASSOC_NOT_AUTHED
+            | wifi_err_reason_t_WIFI_REASON_DISASSOC_PWRCAP_BAD
+            | wifi_err_reason_t_WIFI_REASON_DISASSOC_SUPCHAN_BAD
+            | wifi_err_reason_t_WIFI_REASON_IE_INVALID
+            | wifi_err_reason_t_WIFI_REASON_MIC_FAILURE
+            | wifi_err_reason_t_WIFI_REASON_IE_IN_4WAY_DIFFERS
+            | wifi_err_reason_t_WIFI_REASON_GROUP_CIPHER_INVALID
+            | wifi_err_reason_t_WIFI_REASON_PAIRWISE_CIPHER_INVALID
+            | wifi_err_reason_t_WIFI_REASON_AKMP_INVALID
+            | wifi_err_reason_t_WIFI_REASON_UNSUPP_RSN_IE_VERSION
+            | wifi_err_reason_t_WIFI_REASON_INVALID_RSN_IE_CAP
+            | wifi_err_reason_t_WIFI_REASON_802_1X_AUTH_FAILED
+            | wifi_err_reason_t_WIFI_REASON_CIPHER_SUITE_REJECTED
+            | wifi_err_reason_t_WIFI_REASON_INVALID_PMKID
+    )
+}
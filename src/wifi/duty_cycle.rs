@@ -0,0 +1,123 @@
+//! Airtime budget for injected frames
+//!
+//! Regulations limit how much of the time a device may transmit in some bands and
+//! regions. A [DutyCycle] given to `set_tx_duty_cycle` makes `wifi_transmit_raw` refuse
+//! frames with `WifiError::DutyCycleExceeded` once the budget is used up, so e.g. a
+//! beacon sender stays within the limit without tracking airtime itself.
+
+/// Fixed part of a frame's airtime - the long 802.11b preamble and PLCP header
+const PREAMBLE_US: u64 = 192;
+const FCS_LEN: u64 = 4;
+
+/// The airtime of a frame of `len` bytes (without FCS) in microseconds
+///
+/// Assumes 1 Mbit/s with long preamble, the slowest rate management frames are sent at,
+/// so the estimate never falls short of the actual airtime.
+pub fn frame_airtime_us(len: usize) -> u64 {
+    PREAMBLE_US + (len as u64 + FCS_LEN) * 8
+}
+
+/// Allows transmitting for `permille` thousandths of the time, averaged over `window_ms`
+///
+/// The unused budget accumulates up to a full window, so bursts up to
+/// `window_ms * permille / 1000` milliseconds of airtime are possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DutyCycle {
+    permille: u16,
+    window_ms: u32,
+    /// Airtime left in microseconds
+    budget_us: u64,
+    last_update_us: u64,
+}
+
+impl DutyCycle {
+    /// Starts with a full budget - `permille` is capped at 1000
+    pub const fn new(permille: u16, window_ms: u32) -> DutyCycle {
+        let permille = if permille > 1000 { 1000 } else { permille };
+        DutyCycle {
+            permille,
+            window_ms,
+            budget_us: window_ms as u64 * permille as u64,
+            last_update_us: 0,
+        }
+    }
+
+    pub fn permille(&self) -> u16 {
+        self.permille
+    }
+
+    pub fn window_ms(&self) -> u32 {
+        self.window_ms
+    }
+
+    /// Airtime left in microseconds as of the last [DutyCycle::try_transmit_at]
+    pub fn budget_us(&self) -> u64 {
+        self.budget_us
+    }
+
+    /// Takes the airtime of a frame of `len` bytes from the budget - `false` if not enough
+    /// is left, which leaves the budget unchanged
+    pub fn try_transmit_at(&mut self, now_us: u64, len: usize) -> bool {
+        let elapsed_us = now_us.saturating_sub(self.last_update_us);
+        let max_budget_us = self.window_ms as u64 * self.permille as u64;
+        self.budget_us = self
+            .budget_us
+            .saturating_add(elapsed_us * self.permille as u64 / 1000)
+            .min(max_budget_us);
+        self.last_update_us = now_us;
+
+        let airtime_us = frame_airtime_us(len);
+        if airtime_us > self.budget_us {
+            return false;
+        }
+
+        self.budget_us -= airtime_us;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exhausting_the_budget() {
+        // 10% of 100 ms - 10 ms of airtime
+        let mut duty_cycle = DutyCycle::new(100, 100);
+        let airtime_us = frame_airtime_us(100);
+        let frames = 10_000 / airtime_us;
+
+        for _ in 0..frames {
+            assert!(duty_cycle.try_transmit_at(0, 100));
+        }
+        assert_eq!(duty_cycle.budget_us(), 10_000 - frames * airtime_us);
+
+        let left = duty_cycle.budget_us();
+        assert!(!duty_cycle.try_transmit_at(0, 100));
+        assert_eq!(duty_cycle.budget_us(), left);
+    }
+
+    #[test]
+    fn window_roll_over() {
+        let mut duty_cycle = DutyCycle::new(100, 100);
+        while duty_cycle.try_transmit_at(0, 1000) {}
+        assert!(!duty_cycle.try_transmit_at(0, 1000));
+
+        // refills at 100 µs per ms
+        let airtime_us = frame_airtime_us(1000);
+        let refill_us = (airtime_us - duty_cycle.budget_us()) * 10;
+        assert!(!duty_cycle.try_transmit_at(refill_us - 10, 1000));
+        assert!(duty_cycle.try_transmit_at(refill_us, 1000));
+
+        // a full window later at most a full budget is back
+        duty_cycle.try_transmit_at(1_000_000, 0);
+        assert_eq!(duty_cycle.budget_us(), 10_000 - frame_airtime_us(0));
+    }
+
+    #[test]
+    fn permille_is_capped() {
+        assert_eq!(DutyCycle::new(2000, 10).permille(), 1000);
+        assert_eq!(DutyCycle::new(2000, 10).budget_us(), 10_000);
+    }
+}
@@ -0,0 +1,402 @@
+//! `embedded-svc` `Wifi` trait implementations
+//!
+//! - feature `embedded-svc`: the current trait generation (embedded-svc 0.28) including the
+//!   async trait
+//! - feature `embedded-svc-0-22`: the trait of embedded-svc 0.22
+//!
+//! Both can be enabled at the same time so applications can migrate one part at a time.
+//! Only the client mode is supported.
+
+use super::{
+    get_capabilities, is_connected, scan_n_with_total, wifi_connect, wifi_start, wifi_stop,
+    AuthMethod, Capability, WifiError,
+};
+
+/// Handle to the driver implementing the `embedded-svc` traits
+pub struct WifiController {
+    ssid: heapless::String<32>,
+    password: heapless::String<64>,
+    auth_method: AuthMethod,
+    configured: bool,
+    started: bool,
+}
+
+impl WifiController {
+    /// Expects the driver to be initialized via `wifi_init`
+    pub fn new() -> WifiController {
+        WifiController {
+            ssid: heapless::String::new(),
+            password: heapless::String::new(),
+            auth_method: AuthMethod::WPA2Personal,
+            configured: false,
+            started: false,
+        }
+    }
+
+    fn configure(&mut self, ssid: &str, password: &str, auth_method: AuthMethod) {
+        self.ssid.clear();
+        self.ssid.push_str(ssid).ok();
+        self.password.clear();
+        self.password.push_str(password).ok();
+        self.auth_method = auth_method;
+        self.configured = true;
+    }
+
+    fn start(&mut self) -> Result<(), WifiError> {
+        if !self.started {
+            wifi_start()?;
+            self.started = true;
+        }
+
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), WifiError> {
+        wifi_stop()?;
+        self.started = false;
+        Ok(())
+    }
+
+    fn connect(&mut self) -> Result<(), WifiError> {
+        if !self.configured {
+            return Err(WifiError::InvalidState);
+        }
+
+        wifi_connect(&self.ssid, &self.password)
+    }
+}
+
+impl Default for WifiController {
+    fn default() -> Self {
+        WifiController::new()
+    }
+}
+
+#[cfg(feature = "embedded-svc")]
+mod current {
+    use embedded_svc::wifi::{
+        asynch, AccessPointInfo as SvcAccessPointInfo, AuthMethod as SvcAuthMethod,
+        Capability as SvcCapability, ClientConfiguration, Configuration, Wifi,
+    };
+    use enumset::EnumSet;
+
+    use super::*;
+    use crate::wifi::{wifi_disconnect, AccessPointInfo};
+
+    fn svc_auth_method(auth_method: AuthMethod) -> SvcAuthMethod {
+        match auth_method {
+            AuthMethod::None => SvcAuthMethod::None,
+            AuthMethod::WEP => SvcAuthMethod::WEP,
+            AuthMethod::WPA => SvcAuthMethod::WPA,
+            AuthMethod::WPA2Personal => SvcAuthMethod::WPA2Personal,
+            AuthMethod::WPAWPA2Personal => SvcAuthMethod::WPAWPA2Personal,
+            AuthMethod::WPA2Enterprise => SvcAuthMethod::WPA2Enterprise,
+            AuthMethod::WPA3Personal => SvcAuthMethod::WPA3Personal,
+            AuthMethod::WPA2WPA3Personal => SvcAuthMethod::WPA2WPA3Personal,
+            AuthMethod::WAPIPersonal => SvcAuthMethod::WAPIPersonal,
+        }
+    }
+
+    fn auth_method(auth_method: SvcAuthMethod) -> AuthMethod {
+        match auth_method {
+            SvcAuthMethod::None => AuthMethod::None,
+            SvcAuthMethod::WEP => AuthMethod::WEP,
+            SvcAuthMethod::WPA => AuthMethod::WPA,
+            SvcAuthMethod::WPA2Personal => AuthMethod::WPA2Personal,
+            SvcAuthMethod::WPAWPA2Personal => AuthMethod::WPAWPA2Personal,
+            SvcAuthMethod::WPA2Enterprise => AuthMethod::WPA2Enterprise,
+            SvcAuthMethod::WPA3Personal => AuthMethod::WPA3Personal,
+            SvcAuthMethod::WPA2WPA3Personal => AuthMethod::WPA2WPA3Personal,
+            SvcAuthMethod::WAPIPersonal => AuthMethod::WAPIPersonal,
+        }
+    }
+
+    fn svc_access_point_info(ap: &AccessPointInfo) -> SvcAccessPointInfo {
+        let mut info = SvcAccessPointInfo {
+            bssid: ap.bssid,
+            channel: ap.channel,
+            signal_strength: ap.signal_strength,
+            auth_method: Some(svc_auth_method(ap.auth_method)),
+            ..Default::default()
+        };
+        info.ssid.push_str(&ap.ssid).ok();
+        info
+    }
+
+    fn capabilities() -> EnumSet<SvcCapability> {
+        let mut set = EnumSet::empty();
+        for capability in get_capabilities().iter() {
+            match capability {
+                Capability::Client => set.insert(SvcCapability::Client),
+                // the controller only handles client configurations so far
+                Capability::AccessPoint | Capability::Mixed => false,
+                // not represented in embedded-svc
+                Capability::Enterprise | Capability::WPA3 => false,
+            };
+        }
+        set
+    }
+
+    // embedded-svc 0.28 uses heapless 0.8 while the rest of the crate uses 0.7
+    fn scan<const N: usize>() -> Result<(heapless08::Vec<SvcAccessPointInfo, N>, usize), WifiError>
+    {
+        let (found, total) = scan_n_with_total::<N>()?;
+        let mut result = heapless08::Vec::new();
+        for ap in found.iter() {
+            result.push(svc_access_point_info(ap)).ok();
+        }
+        Ok((result, total))
+    }
+
+    impl WifiController {
+        fn configuration(&self) -> Configuration {
+            if !self.configured {
+                return Configuration::None;
+            }
+
+            let mut client = ClientConfiguration {
+                auth_method: svc_auth_method(self.auth_method),
+                ..Default::default()
+            };
+            client.ssid.push_str(&self.ssid).ok();
+            client.password.push_str(&self.password).ok();
+            Configuration::Client(client)
+        }
+
+        fn set_svc_configuration(&mut self, conf: &Configuration) -> Result<(), WifiError> {
+            match conf {
+                Configuration::Client(client) => {
+                    self.configure(
+                        &client.ssid,
+                        &client.password,
+                        auth_method(client.auth_method),
+                    );
+                    Ok(())
+                }
+                Configuration::None => {
+                    self.configured = false;
+                    Ok(())
+                }
+                _ => Err(WifiError::NotSupported),
+            }
+        }
+    }
+
+    impl Wifi for WifiController {
+        type Error = WifiError;
+
+        fn get_capabilities(&self) -> Result<EnumSet<SvcCapability>, Self::Error> {
+            Ok(capabilities())
+        }
+
+        fn get_configuration(&self) -> Result<Configuration, Self::Error> {
+            Ok(self.configuration())
+        }
+
+        fn set_configuration(&mut self, conf: &Configuration) -> Result<(), Self::Error> {
+            self.set_svc_configuration(conf)
+        }
+
+        fn start(&mut self) -> Result<(), Self::Error> {
+            WifiController::start(self)
+        }
+
+        fn stop(&mut self) -> Result<(), Self::Error> {
+            WifiController::stop(self)
+        }
+
+        fn connect(&mut self) -> Result<(), Self::Error> {
+            WifiController::connect(self)
+        }
+
+        fn disconnect(&mut self) -> Result<(), Self::Error> {
+            wifi_disconnect()
+        }
+
+        fn is_started(&self) -> Result<bool, Self::Error> {
+            Ok(self.started)
+        }
+
+        fn is_connected(&self) -> Result<bool, Self::Error> {
+            Ok(is_connected())
+        }
+
+        fn scan_n<const N: usize>(
+            &mut self,
+        ) -> Result<(heapless08::Vec<SvcAccessPointInfo, N>, usize), Self::Error> {
+            scan::<N>()
+        }
+    }
+
+    // the driver calls are blocking - the async trait just wraps them
+    impl asynch::Wifi for WifiController {
+        type Error = WifiError;
+
+        async fn get_capabilities(&self) -> Result<EnumSet<SvcCapability>, Self::Error> {
+            Ok(capabilities())
+        }
+
+        async fn get_configuration(&self) -> Result<Configuration, Self::Error> {
+            Ok(self.configuration())
+        }
+
+        async fn set_configuration(&mut self, conf: &Configuration) -> Result<(), Self::Error> {
+            self.set_svc_configuration(conf)
+        }
+
+        async fn start(&mut self) -> Result<(), Self::Error> {
+            WifiController::start(self)
+        }
+
+        async fn stop(&mut self) -> Result<(), Self::Error> {
+            WifiController::stop(self)
+        }
+
+        async fn connect(&mut self) -> Result<(), Self::Error> {
+            WifiController::connect(self)
+        }
+
+        async fn disconnect(&mut self) -> Result<(), Self::Error> {
+            wifi_disconnect()
+        }
+
+        async fn is_started(&self) -> Result<bool, Self::Error> {
+            Ok(self.started)
+        }
+
+        async fn is_connected(&self) -> Result<bool, Self::Error> {
+            Ok(is_connected())
+        }
+
+        async fn scan_n<const N: usize>(
+            &mut self,
+        ) -> Result<(heapless08::Vec<SvcAccessPointInfo, N>, usize), Self::Error> {
+            scan::<N>()
+        }
+    }
+}
+
+#[cfg(feature = "embedded-svc-0-22")]
+mod v0_22 {
+    use embedded_svc_0_22::wifi::{
+        AccessPointInfo as SvcAccessPointInfo, ApStatus, AuthMethod as SvcAuthMethod,
+        Capability as SvcCapability, ClientConfiguration, ClientConnectionStatus, ClientIpStatus,
+        ClientStatus, Configuration, Status, Wifi,
+    };
+    use enumset::EnumSet;
+
+    use super::*;
+
+    fn svc_auth_method(auth_method: AuthMethod) -> SvcAuthMethod {
+        match auth_method {
+            AuthMethod::None => SvcAuthMethod::None,
+            AuthMethod::WEP => SvcAuthMethod::WEP,
+            AuthMethod::WPA => SvcAuthMethod::WPA,
+            AuthMethod::WPA2Personal => SvcAuthMethod::WPA2Personal,
+            AuthMethod::WPAWPA2Personal => SvcAuthMethod::WPAWPA2Personal,
+            AuthMethod::WPA2Enterprise => SvcAuthMethod::WPA2Enterprise,
+            AuthMethod::WPA3Personal => SvcAuthMethod::WPA3Personal,
+            AuthMethod::WPA2WPA3Personal => SvcAuthMethod::WPA2WPA3Personal,
+            AuthMethod::WAPIPersonal => SvcAuthMethod::WAPIPersonal,
+        }
+    }
+
+    fn auth_method(auth_method: SvcAuthMethod) -> AuthMethod {
+        match auth_method {
+            SvcAuthMethod::None => AuthMethod::None,
+            SvcAuthMethod::WEP => AuthMethod::WEP,
+            SvcAuthMethod::WPA => AuthMethod::WPA,
+            SvcAuthMethod::WPA2Personal => AuthMethod::WPA2Personal,
+            SvcAuthMethod::WPAWPA2Personal => AuthMethod::WPAWPA2Personal,
+            SvcAuthMethod::WPA2Enterprise => AuthMethod::WPA2Enterprise,
+            SvcAuthMethod::WPA3Personal => AuthMethod::WPA3Personal,
+            SvcAuthMethod::WPA2WPA3Personal => AuthMethod::WPA2WPA3Personal,
+            SvcAuthMethod::WAPIPersonal => AuthMethod::WAPIPersonal,
+        }
+    }
+
+    impl Wifi for WifiController {
+        type Error = WifiError;
+
+        fn get_capabilities(&self) -> Result<EnumSet<SvcCapability>, Self::Error> {
+            let mut set = EnumSet::empty();
+            for capability in get_capabilities().iter() {
+                match capability {
+                    Capability::Client => set.insert(SvcCapability::Client),
+                    // the controller only handles client configurations so far
+                    Capability::AccessPoint | Capability::Mixed => false,
+                    // not represented in embedded-svc
+                    Capability::Enterprise | Capability::WPA3 => false,
+                };
+            }
+            Ok(set)
+        }
+
+        fn get_status(&self) -> Status {
+            // the IP configuration is not handled by the driver
+            let client = if !self.started {
+                ClientStatus::Stopped
+            } else if is_connected() {
+                ClientStatus::Started(ClientConnectionStatus::Connected(ClientIpStatus::Disabled))
+            } else {
+                ClientStatus::Started(ClientConnectionStatus::Disconnected)
+            };
+
+            Status(client, ApStatus::Stopped)
+        }
+
+        fn scan_n<const N: usize>(
+            &mut self,
+        ) -> Result<(heapless::Vec<SvcAccessPointInfo, N>, usize), Self::Error> {
+            let (found, total) = scan_n_with_total::<N>()?;
+            let mut result = heapless::Vec::new();
+            for ap in found.iter() {
+                let mut info = SvcAccessPointInfo {
+                    bssid: ap.bssid,
+                    channel: ap.channel,
+                    signal_strength: ap.signal_strength as u8,
+                    auth_method: svc_auth_method(ap.auth_method),
+                    ..Default::default()
+                };
+                info.ssid.push_str(&ap.ssid).ok();
+                result.push(info).ok();
+            }
+            Ok((result, total))
+        }
+
+        fn get_configuration(&self) -> Result<Configuration, Self::Error> {
+            if !self.configured {
+                return Ok(Configuration::None);
+            }
+
+            let mut client = ClientConfiguration {
+                auth_method: svc_auth_method(self.auth_method),
+                ip_conf: None,
+                ..Default::default()
+            };
+            client.ssid.push_str(&self.ssid).ok();
+            client.password.push_str(&self.password).ok();
+            Ok(Configuration::Client(client))
+        }
+
+        /// Starts the driver and connects
+        fn set_configuration(&mut self, conf: &Configuration) -> Result<(), Self::Error> {
+            match conf {
+                Configuration::Client(client) => {
+                    self.configure(
+                        &client.ssid,
+                        &client.password,
+                        auth_method(client.auth_method),
+                    );
+                    self.start()?;
+                    self.connect()
+                }
+                Configuration::None => {
+                    self.configured = false;
+                    self.stop()
+                }
+                _ => Err(WifiError::NotSupported),
+            }
+        }
+    }
+}
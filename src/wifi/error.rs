@@ -0,0 +1,146 @@
+#![allow(non_upper_case_globals)]
+
+use crate::binary::include::*;
+
+/// Errors reported by the WiFi driver
+///
+/// Most variants map to an `esp_err_t` returned by the driver. The variants from
+/// `ApNotFound` on are derived from the reason of a failed connection attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WifiError {
+    /// Generic failure (`ESP_FAIL`)
+    Failed,
+    NoMem,
+    InvalidArg,
+    InvalidState,
+    InvalidSize,
+    NotFound,
+    NotSupported,
+    Timeout,
+    NotInitialized,
+    NotStarted,
+    NotStopped,
+    InvalidInterface,
+    InvalidMode,
+    InvalidWifiState,
+    InvalidConnection,
+    Nvs,
+    InvalidMac,
+    InvalidSsid,
+    InvalidPassword,
+    WakeFailed,
+    WouldBlock,
+    NotConnected,
+    NotAssociated,
+    TxDisallowed,
+    /// The airtime budget of `set_tx_duty_cycle` is used up for now
+    DutyCycleExceeded,
+    /// The linked blob was built from different headers than the bindings
+    IncompatibleBlob,
+    /// The call needs another mode, e.g. connecting while in SoftAP mode - see
+    /// `wifi_set_mode`
+    WrongMode,
+    ApNotFound,
+    WrongPassword,
+    AuthenticationFailed,
+    AssociationFailed,
+    BeaconTimeout,
+    /// Disconnected for another reason - contains the `wifi_err_reason_t`
+    Disconnected(u8),
+    /// An `esp_err_t` not known to this crate
+    Unknown(i32),
+}
+
+impl WifiError {
+    pub fn from_esp_err(code: esp_err_t) -> WifiError {
+        if code == ESP_FAIL {
+            return WifiError::Failed;
+        }
+
+        match code as u32 {
+            ESP_ERR_NO_MEM => WifiError::NoMem,
+            ESP_ERR_INVALID_ARG => WifiError::InvalidArg,
+            ESP_ERR_INVALID_STATE => WifiError::InvalidState,
+            ESP_ERR_INVALID_SIZE => WifiError::InvalidSize,
+            ESP_ERR_NOT_FOUND => WifiError::NotFound,
+            ESP_ERR_NOT_SUPPORTED => WifiError::NotSupported,
+            ESP_ERR_TIMEOUT | ESP_ERR_WIFI_TIMEOUT => WifiError::Timeout,
+            ESP_ERR_WIFI_NOT_INIT => WifiError::NotInitialized,
+            ESP_ERR_WIFI_NOT_STARTED => WifiError::NotStarted,
+            ESP_ERR_WIFI_NOT_STOPPED => WifiError::NotStopped,
+            ESP_ERR_WIFI_IF => WifiError::InvalidInterface,
+            ESP_ERR_WIFI_MODE => WifiError::InvalidMode,
+            ESP_ERR_WIFI_STATE | ESP_ERR_WIFI_INIT_STATE | ESP_ERR_WIFI_STOP_STATE => {
+                WifiError::InvalidWifiState
+            }
+            ESP_ERR_WIFI_CONN => WifiError::InvalidConnection,
+            ESP_ERR_WIFI_NVS => WifiError::Nvs,
+            ESP_ERR_INVALID_MAC | ESP_ERR_WIFI_MAC => WifiError::InvalidMac,
+            ESP_ERR_WIFI_SSID => WifiError::InvalidSsid,
+            ESP_ERR_WIFI_PASSWORD => WifiError::InvalidPassword,
+            ESP_ERR_WIFI_WAKE_FAIL => WifiError::WakeFailed,
+            ESP_ERR_WIFI_WOULD_BLOCK => WifiError::WouldBlock,
+            ESP_ERR_WIFI_NOT_CONNECT => WifiError::NotConnected,
+            ESP_ERR_WIFI_NOT_ASSOC => WifiError::NotAssociated,
+            ESP_ERR_WIFI_TX_DISALLOW => WifiError::TxDisallowed,
+            _ => WifiError::Unknown(code),
+        }
+    }
+
+    /// Maps the reason of a `WIFI_EVENT_STA_DISCONNECTED` event
+    pub fn from_disconnect_reason(reason: u8) -> WifiError {
+        match reason as wifi_err_reason_t {
+            wifi_err_reason_t_WIFI_REASON_NO_AP_FOUND => WifiError::ApNotFound,
+            wifi_err_reason_t_WIFI_REASON_4WAY_HANDSHAKE_TIMEOUT
+            | wifi_err_reason_t_WIFI_REASON_HANDSHAKE_TIMEOUT => WifiError::WrongPassword,
+            wifi_err_reason_t_WIFI_REASON_AUTH_FAIL
+            | wifi_err_reason_t_WIFI_REASON_802_1X_AUTH_FAILED => WifiError::AuthenticationFailed,
+            wifi_err_reason_t_WIFI_REASON_ASSOC_FAIL => WifiError::AssociationFailed,
+            wifi_err_reason_t_WIFI_REASON_BEACON_TIMEOUT => WifiError::BeaconTimeout,
+            _ => WifiError::Disconnected(reason),
+        }
+    }
+
+    /// `true` if retrying the failed operation later might succeed
+    ///
+    /// Errors caused by invalid arguments, a wrong configuration or missing support
+    /// are not retryable.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            WifiError::NoMem
+                | WifiError::Timeout
+                | WifiError::WouldBlock
+                | WifiError::NotConnected
+                | WifiError::NotAssociated
+                | WifiError::TxDisallowed
+                | WifiError::DutyCycleExceeded
+                | WifiError::WakeFailed
+                | WifiError::ApNotFound
+                | WifiError::AssociationFailed
+                | WifiError::BeaconTimeout
+                | WifiError::Disconnected(_)
+        )
+    }
+}
+
+/// Turns an `esp_err_t` into a `Result`
+#[cfg(not(feature = "mock"))]
+pub(crate) fn esp_result(code: esp_err_t) -> Result<(), WifiError> {
+    if code == ESP_OK as esp_err_t {
+        Ok(())
+    } else {
+        Err(report_error(code))
+    }
+}
+
+/// Records a failure in the event log and passes it to `DriverHooks::on_error` - also
+/// for failures the driver recovers from, so they show without the `log` feature
+#[cfg(not(feature = "mock"))]
+pub(crate) fn report_error(code: esp_err_t) -> WifiError {
+    let error = WifiError::from_esp_err(code);
+    super::event_log::log_error(code);
+    super::hooks::hook_error(error);
+    error
+}
@@ -0,0 +1,193 @@
+//! Post-mortem log of the driver's last events
+//!
+//! The last [EVENT_LOG_LEN] events, disconnect reasons and failed driver calls are kept in
+//! `.noinit` RAM, which a software reset (e.g. resetting from the panic handler or a
+//! watchdog reset) leaves alone. After the reset [previous_event_log] returns what the
+//! radio did last, to attach it to a crash report. [event_log] returns the current run's
+//! log, e.g. to print it from the panic handler.
+//!
+//! After power-on the RAM holds garbage, which the magic number and the checksum reject.
+//! Received frames dropped for lack of space are not logged, they'd push out everything
+//! else under load - `diagnostics()` counts them.
+
+use core::{
+    mem::MaybeUninit,
+    ptr::{addr_of, addr_of_mut},
+};
+
+use crate::{binary::include::esp_err_t, timer::get_systimer_count};
+
+use super::{types::WifiEvent, WifiError};
+
+pub const EVENT_LOG_LEN: usize = 16;
+
+const MAGIC: u32 = 0x5749_4c47;
+
+const KIND_EVENT: u32 = 1;
+const KIND_DISCONNECTED: u32 = 2;
+const KIND_ERROR: u32 = 3;
+
+/// What got logged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LoggedEvent {
+    /// The driver posted an event - only its kind is kept, payloads are zeroed
+    Event(WifiEvent),
+    /// The station got disconnected or a connection attempt failed
+    Disconnected(WifiError),
+    /// A driver call failed
+    Error(WifiError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EventRecord {
+    /// Time since boot of the run which logged it
+    pub uptime_ms: u32,
+    pub event: LoggedEvent,
+}
+
+/// Only plain integers without padding, so garbage is never read as an invalid value
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct RawRecord {
+    uptime_ms: u32,
+    kind: u32,
+    code: i32,
+}
+
+#[repr(C)]
+struct RawLog {
+    magic: u32,
+    /// Index the next record goes to
+    next: u32,
+    len: u32,
+    checksum: u32,
+    records: [RawRecord; EVENT_LOG_LEN],
+}
+
+#[link_section = ".noinit.wifi_event_log"]
+static mut LOG: MaybeUninit<RawLog> = MaybeUninit::uninit();
+
+static mut STARTED: bool = false;
+static mut PREVIOUS: heapless::Vec<EventRecord, EVENT_LOG_LEN> = heapless::Vec::new();
+
+pub(crate) fn log_event(event_id: i32) {
+    log(KIND_EVENT, event_id);
+}
+
+pub(crate) fn log_disconnect(reason: u8) {
+    log(KIND_DISCONNECTED, reason as i32);
+}
+
+pub(crate) fn log_error(code: esp_err_t) {
+    log(KIND_ERROR, code);
+}
+
+/// The current run's log, oldest first
+pub fn event_log() -> heapless::Vec<EventRecord, EVENT_LOG_LEN> {
+    critical_section::with(|_| unsafe {
+        start();
+        records(&*raw_log())
+    })
+}
+
+/// The log as it was before the last reset, oldest first - empty after power-on
+pub fn previous_event_log() -> heapless::Vec<EventRecord, EVENT_LOG_LEN> {
+    critical_section::with(|_| unsafe {
+        start();
+        (*addr_of!(PREVIOUS)).clone()
+    })
+}
+
+fn log(kind: u32, code: i32) {
+    critical_section::with(|_| unsafe {
+        start();
+
+        let log = &mut *raw_log();
+        log.records[log.next as usize] = RawRecord {
+            uptime_ms: (get_systimer_count() / 16_000) as u32,
+            kind,
+            code,
+        };
+        log.next = (log.next + 1) % EVENT_LOG_LEN as u32;
+        log.len = (log.len + 1).min(EVENT_LOG_LEN as u32);
+        log.checksum = checksum(log);
+    });
+}
+
+/// Takes over what the previous run left and starts an empty log - called with
+/// interrupts disabled
+unsafe fn start() {
+    if STARTED {
+        return;
+    }
+    STARTED = true;
+
+    let log = raw_log();
+    // read volatile - as far as the compiler knows nobody wrote this memory yet
+    let magic = addr_of!((*log).magic).read_volatile();
+    let next = addr_of!((*log).next).read_volatile();
+    let len = addr_of!((*log).len).read_volatile();
+    let stored_checksum = addr_of!((*log).checksum).read_volatile();
+    if magic == MAGIC
+        && next < EVENT_LOG_LEN as u32
+        && len <= EVENT_LOG_LEN as u32
+        && stored_checksum == checksum(&*log)
+    {
+        PREVIOUS = records(&*log);
+    }
+
+    log.write(RawLog {
+        magic: MAGIC,
+        next: 0,
+        len: 0,
+        checksum: 0,
+        records: [RawRecord {
+            uptime_ms: 0,
+            kind: 0,
+            code: 0,
+        }; EVENT_LOG_LEN],
+    });
+    (*log).checksum = checksum(&*log);
+}
+
+unsafe fn raw_log() -> *mut RawLog {
+    addr_of_mut!(LOG).cast()
+}
+
+fn checksum(log: &RawLog) -> u32 {
+    let mut sum = log.next ^ log.len.rotate_left(8);
+    for record in log.records.iter() {
+        for word in [record.uptime_ms, record.kind, record.code as u32] {
+            sum = sum.rotate_left(5) ^ word;
+            sum = sum.wrapping_mul(0x0100_0193);
+        }
+    }
+    sum
+}
+
+fn records(log: &RawLog) -> heapless::Vec<EventRecord, EVENT_LOG_LEN> {
+    let mut records = heapless::Vec::new();
+    let first = (log.next + EVENT_LOG_LEN as u32 - log.len) % EVENT_LOG_LEN as u32;
+    for i in 0..log.len {
+        let record = log.records[((first + i) % EVENT_LOG_LEN as u32) as usize];
+        let event = match record.kind {
+            KIND_EVENT => {
+                LoggedEvent::Event(unsafe { WifiEvent::from_raw(record.code, core::ptr::null()) })
+            }
+            KIND_DISCONNECTED => {
+                LoggedEvent::Disconnected(WifiError::from_disconnect_reason(record.code as u8))
+            }
+            KIND_ERROR => LoggedEvent::Error(WifiError::from_esp_err(record.code)),
+            _ => continue,
+        };
+        records
+            .push(EventRecord {
+                uptime_ms: record.uptime_ms,
+                event,
+            })
+            .ok();
+    }
+    records
+}
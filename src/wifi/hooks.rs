@@ -0,0 +1,92 @@
+//! Instrumentation hooks
+//!
+//! Register a [DriverHooks] implementation to feed metrics or telemetry from inside the
+//! driver. The callbacks may be called from the driver's tasks and from interrupt context
+//! so they should be short and must not block.
+
+use super::{
+    types::{SecurityEvent, WifiEvent},
+    WifiError,
+};
+
+pub trait DriverHooks: Sync {
+    /// A frame of `len` bytes was handed to the driver for sending
+    fn on_tx(&self, _len: usize) {}
+
+    /// A frame of `len` bytes was received
+    fn on_rx(&self, _len: usize) {}
+
+    /// The driver posted an event
+    fn on_event(&self, _event: WifiEvent) {}
+
+    /// Something security relevant happened, e.g. a MIC failure or a failed rekey
+    fn on_security_event(&self, _event: SecurityEvent) {}
+
+    /// A driver call failed or a received frame had to be dropped
+    fn on_error(&self, _error: WifiError) {}
+}
+
+// the reference is two words, it's only accessed with interrupts disabled so it's never
+// seen half written
+#[cfg(not(feature = "mock"))]
+static mut HOOKS: Option<&'static dyn DriverHooks> = None;
+
+#[cfg(not(feature = "mock"))]
+fn with_hooks<R>(f: impl FnOnce(&mut Option<&'static dyn DriverHooks>) -> R) -> R {
+    critical_section::with(|_| unsafe { f(&mut *core::ptr::addr_of_mut!(HOOKS)) })
+}
+
+// per thread like the rest of the mock's state
+#[cfg(feature = "mock")]
+std::thread_local! {
+    static HOOKS: core::cell::RefCell<Option<&'static dyn DriverHooks>> =
+        core::cell::RefCell::new(None);
+}
+
+#[cfg(feature = "mock")]
+fn with_hooks<R>(f: impl FnOnce(&mut Option<&'static dyn DriverHooks>) -> R) -> R {
+    HOOKS.with(|hooks| f(&mut hooks.borrow_mut()))
+}
+
+pub fn set_driver_hooks(hooks: &'static dyn DriverHooks) {
+    with_hooks(|current| *current = Some(hooks));
+}
+
+pub fn clear_driver_hooks() {
+    with_hooks(|current| *current = None);
+}
+
+/// The registered hooks - called outside of the critical section
+fn hooks() -> Option<&'static dyn DriverHooks> {
+    with_hooks(|current| *current)
+}
+
+pub(crate) fn hook_tx(len: usize) {
+    if let Some(hooks) = hooks() {
+        hooks.on_tx(len);
+    }
+}
+
+pub(crate) fn hook_rx(len: usize) {
+    if let Some(hooks) = hooks() {
+        hooks.on_rx(len);
+    }
+}
+
+pub(crate) fn hook_event(event: WifiEvent) {
+    if let Some(hooks) = hooks() {
+        hooks.on_event(event);
+    }
+}
+
+pub(crate) fn hook_security_event(event: SecurityEvent) {
+    if let Some(hooks) = hooks() {
+        hooks.on_security_event(event);
+    }
+}
+
+pub(crate) fn hook_error(error: WifiError) {
+    if let Some(hooks) = hooks() {
+        hooks.on_error(error);
+    }
+}
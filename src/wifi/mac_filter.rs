@@ -0,0 +1,64 @@
+//! MAC address filter of the SoftAP
+//!
+//! Stations rejected by the filter are deauthenticated right after they associated and
+//! their frames are dropped before they reach the network stack.
+
+use super::WifiError;
+
+/// Maximum number of addresses in the filter
+pub const MAC_FILTER_SIZE: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MacFilterPolicy {
+    /// Every station can associate
+    Disabled,
+    /// Only the listed stations can associate
+    Allow,
+    /// The listed stations are rejected
+    Deny,
+}
+
+static mut POLICY: MacFilterPolicy = MacFilterPolicy::Disabled;
+static mut ADDRESSES: heapless::Vec<[u8; 6], MAC_FILTER_SIZE> = heapless::Vec::new();
+
+/// Sets the policy and the addresses it applies to
+///
+/// Fails with `InvalidSize` if there are more than [MAC_FILTER_SIZE] addresses. Already
+/// associated stations are not checked again.
+pub fn set_mac_filter(policy: MacFilterPolicy, addresses: &[[u8; 6]]) -> Result<(), WifiError> {
+    if addresses.len() > MAC_FILTER_SIZE {
+        return Err(WifiError::InvalidSize);
+    }
+
+    // the RX callback and the event handler check stations concurrently
+    critical_section::with(|_| unsafe {
+        ADDRESSES.clear();
+        ADDRESSES.extend_from_slice(addresses).ok();
+        POLICY = policy;
+    });
+
+    Ok(())
+}
+
+pub fn clear_mac_filter() {
+    critical_section::with(|_| unsafe {
+        POLICY = MacFilterPolicy::Disabled;
+        ADDRESSES.clear();
+    });
+}
+
+pub fn mac_filter_policy() -> MacFilterPolicy {
+    unsafe { POLICY }
+}
+
+/// Whether the filter lets the station with the given address associate
+pub fn is_station_allowed(mac: &[u8; 6]) -> bool {
+    critical_section::with(|_| unsafe {
+        match POLICY {
+            MacFilterPolicy::Disabled => true,
+            MacFilterPolicy::Allow => ADDRESSES.contains(mac),
+            MacFilterPolicy::Deny => !ADDRESSES.contains(mac),
+        }
+    })
+}
@@ -1,53 +1,144 @@
+pub mod diagnostics;
+pub mod duty_cycle;
+#[cfg(any(feature = "embedded-svc", feature = "embedded-svc-0-22"))]
+pub mod embedded_svc;
+pub mod error;
+pub mod event_log;
+pub mod hooks;
+pub mod mac_filter;
+pub mod neighbor;
 pub mod os_adapter;
+pub mod os_backend;
+pub mod rate_limit;
+pub mod raw;
+pub mod roaming;
+pub mod stations;
+pub mod types;
+pub mod version;
+pub mod watchdog;
+pub use diagnostics::{diagnostics, dump_mac_statistics, Diagnostics, MacCounters};
+pub use duty_cycle::{frame_airtime_us, DutyCycle};
+#[cfg(any(feature = "embedded-svc", feature = "embedded-svc-0-22"))]
+pub use embedded_svc::WifiController;
+use error::esp_result;
+pub use error::WifiError;
+pub use event_log::{event_log, previous_event_log, EventRecord, LoggedEvent, EVENT_LOG_LEN};
 use hal::Rng;
+pub use hooks::{clear_driver_hooks, set_driver_hooks, DriverHooks};
+use hooks::{hook_error, hook_rx, hook_tx};
+pub use mac_filter::{
+    clear_mac_filter, is_station_allowed, mac_filter_policy, set_mac_filter, MacFilterPolicy,
+};
+pub use neighbor::{neighbor_channels, parse_neighbor_report, Neighbor, NeighborList};
 pub use os_adapter::*;
+pub use os_backend::{set_os_backend, BuiltinOsBackend, OsBackend};
+pub use rate_limit::RateLimit;
+use roaming::TxFailureWindow;
+pub use roaming::{RoamThresholds, RoamTrigger};
 use smoltcp::phy::{Device, DeviceCapabilities, RxToken, TxToken};
+pub use stations::{ap_stations, set_station_rate_limit, station_rate_limit};
+use types::{least_congested_channel, CONFIG_FEATURE_WPA3_SAE_BIT};
+pub use types::{
+    AccessPointConfig, AccessPointInfo, ApChannel, AssociationInfo, AuthMethod, Capabilities,
+    Capability, Cipher, CountryInfo, CountryPolicy, PhyModes, PowerSaveMode, SecurityEvent,
+    SnifferFilter, SnifferFrame, SnifferPacketType, StationInfo, StationTraffic, WifiDeviceMode,
+    WifiEvent, WifiMode,
+};
+pub use version::{blob_versions, BlobVersions};
+pub use watchdog::{clear_watchdog_callback, set_watchdog_callback};
 mod phy_init_data;
 
+use core::mem::MaybeUninit;
+
+use embedded_hal::prelude::_embedded_hal_blocking_rng_Read;
+
 use crate::{
-    binary::include::{
-        __BindgenBitfieldUnit, esp_err_t, esp_interface_t_ESP_IF_WIFI_STA, esp_supplicant_init,
-        esp_wifi_connect, esp_wifi_init_internal, esp_wifi_internal_free_rx_buffer,
-        esp_wifi_internal_reg_rxcb, esp_wifi_internal_set_log_level, esp_wifi_internal_set_log_mod,
-        esp_wifi_internal_tx, esp_wifi_scan_start, esp_wifi_set_config, esp_wifi_set_country,
-        esp_wifi_set_mode, esp_wifi_set_ps, esp_wifi_set_tx_done_cb, esp_wifi_start, esp_wifi_stop,
-        g_wifi_default_wpa_crypto_funcs, u_int32_t, wifi_active_scan_time_t,
-        wifi_auth_mode_t_WIFI_AUTH_OPEN, wifi_config_t,
-        wifi_country_policy_t_WIFI_COUNTRY_POLICY_MANUAL, wifi_country_t, wifi_init_config_t,
-        wifi_interface_t_WIFI_IF_STA, wifi_log_level_t, wifi_log_module_t_WIFI_LOG_MODULE_ALL,
-        wifi_mode_t_WIFI_MODE_STA, wifi_osi_funcs_t, wifi_pmf_config_t,
-        wifi_ps_type_t_WIFI_PS_NONE, wifi_scan_config_t, wifi_scan_method_t_WIFI_FAST_SCAN,
-        wifi_scan_threshold_t, wifi_scan_time_t, wifi_scan_type_t_WIFI_SCAN_TYPE_ACTIVE,
-        wifi_sort_method_t_WIFI_CONNECT_AP_BY_SIGNAL, wifi_sta_config_t, wpa_crypto_funcs_t,
-        ESP_WIFI_OS_ADAPTER_MAGIC, ESP_WIFI_OS_ADAPTER_VERSION, WIFI_INIT_CONFIG_MAGIC,
-        WIFI_LOG_SUBMODULE_ALL,
+    binary::{
+        c_types::c_char,
+        header_md5::{ESP_WIFI_MD5, WIFI_CRYPTO_TYPES_MD5, WIFI_OS_ADAPTER_MD5, WIFI_TYPES_MD5},
+        include::{
+            __BindgenBitfieldUnit, esp_err_t, esp_interface_t_ESP_IF_WIFI_AP,
+            esp_interface_t_ESP_IF_WIFI_STA, esp_supplicant_init, esp_wifi_80211_tx,
+            esp_wifi_connect, esp_wifi_deauth_sta, esp_wifi_disconnect, esp_wifi_get_country,
+            esp_wifi_get_inactive_time, esp_wifi_get_max_tx_power, esp_wifi_init_internal,
+            esp_wifi_internal_crypto_funcs_md5_check, esp_wifi_internal_esp_wifi_md5_check,
+            esp_wifi_internal_free_rx_buffer, esp_wifi_internal_osi_funcs_md5_check,
+            esp_wifi_internal_reg_rxcb, esp_wifi_internal_set_fix_rate,
+            esp_wifi_internal_set_log_level, esp_wifi_internal_set_log_mod, esp_wifi_internal_tx,
+            esp_wifi_internal_wifi_type_md5_check, esp_wifi_scan_get_ap_num,
+            esp_wifi_scan_get_ap_records, esp_wifi_scan_start, esp_wifi_set_config,
+            esp_wifi_set_country, esp_wifi_set_inactive_time, esp_wifi_set_keep_alive_time,
+            esp_wifi_set_mac, esp_wifi_set_max_tx_power, esp_wifi_set_mode,
+            esp_wifi_set_promiscuous, esp_wifi_set_promiscuous_filter,
+            esp_wifi_set_promiscuous_rx_cb, esp_wifi_set_ps, esp_wifi_set_rssi_threshold,
+            esp_wifi_set_tx_done_cb, esp_wifi_sta_get_ap_info, esp_wifi_start, esp_wifi_stop,
+            g_wifi_default_wpa_crypto_funcs, phy_close_rf, u_int32_t, wifi_active_scan_time_t,
+            wifi_ap_config_t, wifi_ap_record_t, wifi_auth_mode_t_WIFI_AUTH_OPEN,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_CCMP, wifi_config_t, wifi_country_t,
+            wifi_init_config_t, wifi_interface_t_WIFI_IF_AP, wifi_interface_t_WIFI_IF_STA,
+            wifi_log_level_t, wifi_log_module_t_WIFI_LOG_MODULE_ALL, wifi_osi_funcs_t,
+            wifi_phy_rate_t_WIFI_PHY_RATE_1M_L, wifi_pmf_config_t, wifi_promiscuous_pkt_t,
+            wifi_promiscuous_pkt_type_t, wifi_scan_config_t, wifi_scan_method_t_WIFI_FAST_SCAN,
+            wifi_scan_threshold_t, wifi_scan_time_t, wifi_scan_type_t_WIFI_SCAN_TYPE_ACTIVE,
+            wifi_sort_method_t_WIFI_CONNECT_AP_BY_SIGNAL, wifi_sta_config_t, wpa_crypto_funcs_t,
+            ESP_WIFI_OS_ADAPTER_MAGIC, ESP_WIFI_OS_ADAPTER_VERSION, WIFI_INIT_CONFIG_MAGIC,
+            WIFI_LOG_SUBMODULE_ALL,
+        },
     },
     compat::queue::SimpleQueue,
+    config::{DYNAMIC_RX_BUF_NUM, DYNAMIC_TX_BUF_NUM, RX_QUEUE_SIZE, STATIC_RX_BUF_NUM},
     debug, print, println, verbose,
 };
 
 extern "C" {
     static mut s_wifi_task_hdl: u32;
-}
 
-static DUMP_PACKETS: bool = false;
+    // from esp_wifi_internal.h which is not part of the bindings
+    fn esp_wifi_sta_pmf_enabled() -> bool;
+    fn esp_wifi_internal_set_retry_counter(src: u32, lrc: u32) -> esp_err_t;
+    fn esp_wifi_internal_set_rts(rts: *const RtsConfig) -> esp_err_t;
+    fn esp_wifi_internal_get_rts(rts: *mut RtsConfig) -> esp_err_t;
+
+    // from esp_wifi_driver.h which is not part of the bindings - the PMK of the station's
+    // profile, derived from the passphrase
+    fn esp_wifi_sta_get_prof_pmk_internal() -> *const u8;
+
+    // from libpp, the PHY's noise floor in dBm
+    fn wDev_GetNoiseFloor() -> i8;
+
+    // from esp_rrm.h which is not part of the bindings
+    fn esp_rrm_send_neighbor_rep_request(
+        cb: Option<unsafe extern "C" fn(*mut crate::binary::c_types::c_void, *const u8, usize)>,
+        cb_ctx: *mut crate::binary::c_types::c_void,
+    ) -> i32;
+}
 
 struct DataFrame {
     len: usize,
     data: [u8; 2500],
 }
 
-static mut DATA_QUEUE_RX: Option<SimpleQueue<DataFrame, 3>> = None;
+/// A [SimpleQueue] always keeps one slot free - one more makes room for `RX_QUEUE_SIZE` frames
+const RX_QUEUE_SLOTS: usize = RX_QUEUE_SIZE + 1;
 
+static mut DATA_QUEUE_RX: Option<SimpleQueue<DataFrame, RX_QUEUE_SLOTS>> = None;
+static mut DATA_QUEUE_RX_AP: Option<SimpleQueue<DataFrame, RX_QUEUE_SLOTS>> = None;
+
+// placed in the memory region selected by ESP32C3_WIFI_BUFFERS_REGION (see build.rs)
+#[link_section = ".wifi_buffers"]
 pub static mut TX_BUFFER: [u8; 2500] = [0u8; 2500]; // should be a queue
 pub static mut TX_QUEUED: bool = false;
 pub static mut TX_QUEUED_DATA_LEN: u16 = 0;
+static mut TX_QUEUED_INTERFACE: WifiDeviceMode = WifiDeviceMode::Sta;
+
+static mut WIFI_MODE: WifiMode = WifiMode::Sta;
 
 static mut RANDOM_GENERATOR: Option<Rng> = None;
 
 pub fn init_buffer() {
     unsafe {
         DATA_QUEUE_RX = Some(SimpleQueue::new());
+        DATA_QUEUE_RX_AP = Some(SimpleQueue::new());
     }
 }
 
@@ -57,6 +148,26 @@ pub fn init_rng(rng: hal::pac::RNG) {
     }
 }
 
+/// Fills `buf` from the hardware RNG passed to [init_rng]
+///
+/// The RNG draws its entropy from the radio's noise so the values are truly random once
+/// the driver is started - good for seeding a network stack or TLS. Fails with
+/// `NotInitialized` before `init_rng` was called.
+pub fn fill_random(buf: &mut [u8]) -> Result<(), WifiError> {
+    critical_section::with(|_| unsafe {
+        match RANDOM_GENERATOR {
+            Some(ref mut rng) => rng.read(buf).map_err(|_| WifiError::Failed),
+            None => Err(WifiError::NotInitialized),
+        }
+    })
+}
+
+pub fn random_u64() -> Result<u64, WifiError> {
+    let mut buf = [0u8; 8];
+    fill_random(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
 pub fn wifi_set_log_verbose() {
     let g_wifi_log_submodule: u_int32_t = WIFI_LOG_SUBMODULE_ALL;
     let level: wifi_log_level_t = crate::binary::include::wifi_log_level_t_WIFI_LOG_VERBOSE;
@@ -116,7 +227,7 @@ static g_wifi_osi_funcs: wifi_osi_funcs_t = wifi_osi_funcs_t {
     _task_ms_to_tick: Some(task_ms_to_tick),
     _task_get_current_task: Some(task_get_current_task),
     _task_get_max_priority: Some(task_get_max_priority),
-    _malloc: Some(malloc),
+    _malloc: Some(adapter_malloc),
     _free: Some(free),
     _event_post: Some(event_post),
     _get_free_heap_size: Some(get_free_heap_size),
@@ -191,11 +302,6 @@ static g_wifi_osi_funcs: wifi_osi_funcs_t = wifi_osi_funcs_t {
     _magic: ESP_WIFI_OS_ADAPTER_MAGIC as i32,
 };
 
-const CONFIG_FEATURE_WPA3_SAE_BIT: u64 = 1 << 0;
-
-unsafe impl Sync for wifi_init_config_t {}
-unsafe impl Sync for wifi_osi_funcs_t {}
-
 #[no_mangle]
 static mut g_wifi_feature_caps: u64 = CONFIG_FEATURE_WPA3_SAE_BIT;
 
@@ -232,11 +338,11 @@ static mut G_CONFIG: wifi_init_config_t = wifi_init_config_t {
         ccmp_decrypt: None,
         ccmp_encrypt: None,
     },
-    static_rx_buf_num: 10,
-    dynamic_rx_buf_num: 32,
+    static_rx_buf_num: STATIC_RX_BUF_NUM as i32,
+    dynamic_rx_buf_num: DYNAMIC_RX_BUF_NUM as i32,
     tx_buf_type: 1, // offset 0x78
     static_tx_buf_num: 0,
-    dynamic_tx_buf_num: 32,
+    dynamic_tx_buf_num: DYNAMIC_TX_BUF_NUM as i32,
     cache_tx_buf_num: 0,
     csi_enable: 1,
     ampdu_rx_enable: 0,
@@ -253,44 +359,114 @@ static mut G_CONFIG: wifi_init_config_t = wifi_init_config_t {
     magic: WIFI_INIT_CONFIG_MAGIC as i32,
 };
 
+/// MAC addresses set via [set_sta_mac] and [set_ap_mac] - indexed by the type passed to
+/// `read_mac`
+pub(crate) static mut MAC_OVERRIDES: [Option<[u8; 6]>; 2] = [None, None];
+
+/// The MAC address of the station interface
+///
+/// Unless overridden with [set_sta_mac] it's the base address from the eFuse.
 pub fn get_sta_mac(mac: &mut [u8; 6]) {
     unsafe {
         read_mac(mac as *mut u8, 0);
     }
 }
 
-pub fn wifi_init() -> i32 {
+/// The MAC address of the SoftAP interface
+///
+/// Unless overridden with [set_ap_mac] it's derived from the base address by setting the
+/// locally administered bit, so both interfaces can share a L2 segment.
+pub fn get_ap_mac(mac: &mut [u8; 6]) {
+    unsafe {
+        read_mac(mac as *mut u8, 1);
+    }
+}
+
+/// Overrides the MAC address of the station interface - `None` restores the default
+///
+/// Set it before `wifi_init` or while the driver is stopped. Fails with `InvalidMac` for
+/// multicast addresses and the address of the SoftAP interface.
+pub fn set_sta_mac(mac: Option<[u8; 6]>) -> Result<(), WifiError> {
+    set_mac(0, wifi_interface_t_WIFI_IF_STA, mac)
+}
+
+/// Overrides the MAC address of the SoftAP interface - see [set_sta_mac]
+pub fn set_ap_mac(mac: Option<[u8; 6]>) -> Result<(), WifiError> {
+    set_mac(1, wifi_interface_t_WIFI_IF_AP, mac)
+}
+
+fn set_mac(
+    index: usize,
+    interface: crate::binary::include::wifi_interface_t,
+    mac: Option<[u8; 6]>,
+) -> Result<(), WifiError> {
+    let mut other = [0u8; 6];
+    unsafe { read_mac(other.as_mut_ptr(), 1 - index as u32) };
+
+    if let Some(mac) = mac {
+        if mac[0] & 0x01 != 0 || mac == other {
+            return Err(WifiError::InvalidMac);
+        }
+    }
+
+    unsafe {
+        let previous = MAC_OVERRIDES[index];
+        MAC_OVERRIDES[index] = mac;
+
+        let mut effective = [0u8; 6];
+        read_mac(effective.as_mut_ptr(), index as u32);
+
+        // before `wifi_init` or with the interface disabled the driver reads it via
+        // `read_mac` later
+        match esp_result(esp_wifi_set_mac(interface, effective.as_ptr())) {
+            Ok(()) | Err(WifiError::NotInitialized) | Err(WifiError::InvalidMode) => Ok(()),
+            Err(e) => {
+                MAC_OVERRIDES[index] = previous;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Checks that the linked blob was built from the headers the bindings were generated from
+fn check_blob_compatibility() -> Result<(), WifiError> {
+    let checks: [(unsafe extern "C" fn(*const c_char) -> esp_err_t, &str); 4] = [
+        (esp_wifi_internal_osi_funcs_md5_check, WIFI_OS_ADAPTER_MD5),
+        (
+            esp_wifi_internal_crypto_funcs_md5_check,
+            WIFI_CRYPTO_TYPES_MD5,
+        ),
+        (esp_wifi_internal_wifi_type_md5_check, WIFI_TYPES_MD5),
+        (esp_wifi_internal_esp_wifi_md5_check, ESP_WIFI_MD5),
+    ];
+
+    for (check, md5) in checks {
+        if unsafe { check(md5.as_ptr()) } != 0 {
+            debug!("blob doesn't match the header with md5 {}", md5);
+            return Err(WifiError::IncompatibleBlob);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn wifi_init() -> Result<(), WifiError> {
+    check_blob_compatibility()?;
+
     unsafe {
         G_CONFIG.wpa_crypto_funcs = g_wifi_default_wpa_crypto_funcs;
         G_CONFIG.feature_caps = g_wifi_feature_caps;
 
-        let cntry_code = [b'C', b'N', 0];
-        let country = wifi_country_t {
-            cc: cntry_code,
-            schan: 1,
-            nchan: 13,
-            max_tx_power: 20,
-            policy: wifi_country_policy_t_WIFI_COUNTRY_POLICY_MANUAL,
-        };
-
         wifi_set_log_verbose();
 
-        let res = esp_wifi_init_internal(&G_CONFIG);
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_init_internal(&G_CONFIG))?;
 
         wifi_set_log_verbose();
 
-        let res = esp_supplicant_init();
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_supplicant_init())?;
 
-        let res = esp_wifi_set_mode(wifi_mode_t_WIFI_MODE_STA);
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_set_mode(WifiMode::Sta.to_raw()))?;
+        WIFI_MODE = WifiMode::Sta;
 
         let mut cfg = wifi_config_t {
             sta: wifi_sta_config_t {
@@ -314,25 +490,16 @@ pub fn wifi_init() -> i32 {
                 _bitfield_1: __BindgenBitfieldUnit::new([0u8; 4usize]),
             },
         };
-        let res = esp_wifi_set_config(wifi_interface_t_WIFI_IF_STA, &mut cfg);
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_set_config(wifi_interface_t_WIFI_IF_STA, &mut cfg))?;
 
-        let res = esp_wifi_set_tx_done_cb(Some(esp_wifi_tx_done_cb));
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_set_tx_done_cb(Some(esp_wifi_tx_done_cb)))?;
 
-        let res = esp_wifi_set_country(&country);
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_set_country(&CountryInfo::default().to_raw()))?;
 
-        let res = esp_wifi_internal_reg_rxcb(esp_interface_t_ESP_IF_WIFI_STA, Some(recv_cb));
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_internal_reg_rxcb(
+            esp_interface_t_ESP_IF_WIFI_STA,
+            Some(recv_cb),
+        ))?;
 
         // unclear why? it should get initialized correctly by the driver
         // but apparently it gets the wrong - maybe we run init on the wrong task
@@ -341,7 +508,95 @@ pub fn wifi_init() -> i32 {
         debug!("&s_wifi_task_hdl = {:p}", &s_wifi_task_hdl);
         s_wifi_task_hdl = 0;
 
-        0
+        Ok(())
+    }
+}
+
+/// Overrides the regulatory settings chosen at build time
+///
+/// With `CountryPolicy::Manual` the settings are used regardless of what the access
+/// point advertises.
+pub fn wifi_set_country(country: &CountryInfo) -> Result<(), WifiError> {
+    unsafe { esp_result(esp_wifi_set_country(&country.to_raw())) }
+}
+
+/// The regulatory settings in effect - with `CountryPolicy::Auto` the ones of the
+/// connected access point
+pub fn wifi_get_country() -> Result<CountryInfo, WifiError> {
+    unsafe {
+        let mut country: wifi_country_t = core::mem::zeroed();
+        esp_result(esp_wifi_get_country(&mut country))?;
+        Ok(CountryInfo::from_raw(&country))
+    }
+}
+
+/// Switches between station, SoftAP and AP+STA mode
+///
+/// `wifi_init` selects station mode. Use one [WifiDevice] per active interface, see
+/// [WifiDevice::new_ap_sta].
+pub fn wifi_set_mode(mode: WifiMode) -> Result<(), WifiError> {
+    unsafe {
+        esp_result(esp_wifi_set_mode(mode.to_raw()))?;
+        WIFI_MODE = mode;
+
+        if mode.is_ap() {
+            esp_result(esp_wifi_internal_reg_rxcb(
+                esp_interface_t_ESP_IF_WIFI_AP,
+                Some(recv_cb_ap),
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn wifi_get_mode() -> WifiMode {
+    unsafe { WIFI_MODE }
+}
+
+/// Configures the SoftAP - the mode has to be set to `Ap` or `ApSta` before, otherwise it
+/// fails with `WrongMode`
+///
+/// With `ApChannel::Auto` this scans first, see [wifi_least_congested_channel]. Fails with
+/// `NotSupported` for authentication methods the SoftAP can't use, e.g. WPA3.
+pub fn wifi_configure_ap(config: &AccessPointConfig) -> Result<(), WifiError> {
+    if !wifi_get_mode().is_ap() {
+        return Err(WifiError::WrongMode);
+    }
+
+    if !config.auth_method.supported_by_ap() {
+        return Err(WifiError::NotSupported);
+    }
+
+    let channel = match config.channel {
+        ApChannel::Fixed(channel) => channel,
+        ApChannel::Auto => {
+            let channel = wifi_least_congested_channel()?;
+            debug!("picked channel {} for the SoftAP", channel);
+            channel
+        }
+    };
+
+    unsafe {
+        let mut cfg = wifi_config_t {
+            ap: wifi_ap_config_t {
+                ssid: [0; 32],
+                password: [0; 64],
+                ssid_len: config.ssid.len() as u8,
+                channel,
+                authmode: config.auth_method.to_raw(),
+                ssid_hidden: config.hidden as u8,
+                max_connection: config.max_connections,
+                beacon_interval: 100,
+                pairwise_cipher: wifi_cipher_type_t_WIFI_CIPHER_TYPE_CCMP,
+                ftm_responder: false,
+            },
+        };
+
+        cfg.ap.ssid[..config.ssid.len()].copy_from_slice(config.ssid.as_bytes());
+        cfg.ap.password[..config.password.len()].copy_from_slice(config.password.as_bytes());
+
+        esp_result(esp_wifi_set_config(wifi_interface_t_WIFI_IF_AP, &mut cfg))
     }
 }
 
@@ -350,52 +605,131 @@ unsafe extern "C" fn recv_cb(
     len: u16,
     eb: *mut crate::binary::c_types::c_void,
 ) -> esp_err_t {
-    critical_section::with(|_| {
-        if let Some(ref mut data_queue_rx) = DATA_QUEUE_RX {
-            if !data_queue_rx.is_full() {
-                let mut buf = [0u8; 2500];
-                let src = core::slice::from_raw_parts_mut(buffer as *mut u8, len as usize);
-                buf[..(len as usize)].copy_from_slice(src);
-                data_queue_rx.enqueue(DataFrame {
-                    len: len as usize,
-                    data: buf,
-                });
+    enqueue_received(&mut DATA_QUEUE_RX, buffer, len, eb)
+}
 
-                esp_wifi_internal_free_rx_buffer(eb);
-                verbose!("esp_wifi_internal_free_rx_buffer done");
-            }
+unsafe extern "C" fn recv_cb_ap(
+    buffer: *mut crate::binary::c_types::c_void,
+    len: u16,
+    eb: *mut crate::binary::c_types::c_void,
+) -> esp_err_t {
+    // don't let frames of stations rejected by the MAC filter through until they are gone
+    if len >= 12 {
+        let src = &*((buffer as *const u8).add(6) as *const [u8; 6]);
+        if !is_station_allowed(src) {
+            diagnostics::RX_DROPPED = diagnostics::RX_DROPPED.wrapping_add(1);
+            esp_wifi_internal_free_rx_buffer(eb);
+            return 0;
         }
+
+        stations::station_received(src, len as usize);
+    }
+
+    enqueue_received(&mut DATA_QUEUE_RX_AP, buffer, len, eb)
+}
+
+/// Association ids of stations rejected by the MAC filter which still need to be removed
+static mut REJECTED_STATIONS: heapless::Vec<u16, 16> = heapless::Vec::new();
+
+/// Called from the event handler - the deauthentication can't be done from the WiFi task
+pub(crate) fn reject_station(aid: u16) {
+    critical_section::with(|_| unsafe {
+        if REJECTED_STATIONS.push(aid).is_err() {
+            debug!("too many rejected stations - aid {} stays associated", aid);
+        }
+    });
+}
+
+/// Called from the timer task
+pub(crate) fn deauth_rejected_stations() {
+    let rejected = critical_section::with(|_| unsafe {
+        let rejected = REJECTED_STATIONS.clone();
+        REJECTED_STATIONS.clear();
+        rejected
     });
 
+    for aid in rejected {
+        debug!("deauthenticating rejected station {}", aid);
+        unsafe { esp_wifi_deauth_sta(aid) };
+    }
+}
+
+unsafe fn enqueue_received(
+    queue: &mut Option<SimpleQueue<DataFrame, RX_QUEUE_SLOTS>>,
+    buffer: *mut crate::binary::c_types::c_void,
+    len: u16,
+    eb: *mut crate::binary::c_types::c_void,
+) -> esp_err_t {
+    // only the enqueueing needs interrupts disabled - copying the frame, handing the buffer
+    // back and calling the hooks is done outside
+    let mut frame = None;
+    if len as usize <= 2500 {
+        let mut buf = [0u8; 2500];
+        let src = core::slice::from_raw_parts_mut(buffer as *mut u8, len as usize);
+        buf[..(len as usize)].copy_from_slice(src);
+        frame = Some(DataFrame {
+            len: len as usize,
+            data: buf,
+        });
+    }
+
+    // always hand the buffer back to the driver - otherwise it runs out of rx buffers
+    esp_wifi_internal_free_rx_buffer(eb);
+    verbose!("esp_wifi_internal_free_rx_buffer done");
+
+    let result = critical_section::with(|_| match (queue, frame) {
+        (Some(data_queue_rx), Some(frame)) if !data_queue_rx.is_full() => {
+            data_queue_rx.enqueue(frame);
+            diagnostics::RX_FRAMES = diagnostics::RX_FRAMES.wrapping_add(1);
+            Ok(true)
+        }
+        (Some(_), frame) => {
+            diagnostics::RX_DROPPED = diagnostics::RX_DROPPED.wrapping_add(1);
+            Err(if frame.is_none() {
+                WifiError::InvalidSize
+            } else {
+                WifiError::NoMem
+            })
+        }
+        (None, _) => Ok(false),
+    });
+
+    match result {
+        Ok(true) => hook_rx(len as usize),
+        Ok(false) => (),
+        Err(error) => {
+            debug!("rx queue full or frame too large - dropping {} bytes", len);
+            hook_error(error);
+        }
+    }
+
     0
 }
 
 unsafe extern "C" fn esp_wifi_tx_done_cb(
-    _ifidx: u8,
+    ifidx: u8,
     _data: *mut u8,
     _data_len: *mut u16,
-    _tx_status: bool,
+    tx_status: bool,
 ) {
     debug!("esp_wifi_tx_done_cb");
+    diagnostics::count_tx_done(tx_status);
+    if ifidx == wifi_interface_t_WIFI_IF_STA as u8 {
+        count_roam_tx(tx_status);
+    }
 }
 
-pub fn wifi_start() -> i32 {
+pub fn wifi_start() -> Result<(), WifiError> {
     unsafe {
-        let res = esp_wifi_start();
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_start())?;
 
-        let res = esp_wifi_set_ps(wifi_ps_type_t_WIFI_PS_NONE);
-        if res != 0 {
-            return res;
-        }
+        esp_result(esp_wifi_set_ps(POWER_SAVE.to_raw()))?;
     }
 
-    0
+    Ok(())
 }
 
-pub fn wifi_start_scan() -> i32 {
+pub fn wifi_start_scan() -> Result<(), WifiError> {
     let scan_time = wifi_scan_time_t {
         active: wifi_active_scan_time_t { min: 0, max: 0 },
         passive: 0,
@@ -410,10 +744,162 @@ pub fn wifi_start_scan() -> i32 {
         scan_time: scan_time,
     };
 
-    unsafe { esp_wifi_scan_start(&scan_config, true) }
+    unsafe { esp_result(esp_wifi_scan_start(&scan_config, true)) }
+}
+
+/// Scans and returns the channel with the least interference from other access points
+///
+/// Scanning needs the station interface, in SoftAP mode it's enabled for the duration of
+/// the scan.
+pub fn wifi_least_congested_channel() -> Result<u8, WifiError> {
+    let mode = wifi_get_mode();
+    if !mode.is_sta() {
+        unsafe { esp_result(esp_wifi_set_mode(WifiMode::ApSta.to_raw()))? };
+    }
+
+    let res = count_channels();
+
+    if !mode.is_sta() {
+        unsafe { esp_result(esp_wifi_set_mode(mode.to_raw()))? };
+    }
+
+    Ok(least_congested_channel(&res?))
+}
+
+/// Number of access points found per channel
+fn count_channels() -> Result<[u16; 14], WifiError> {
+    wifi_start_scan()?;
+
+    let mut occupied = [0u16; 14];
+
+    let mut bss_total: u16 = 0;
+    unsafe { esp_result(esp_wifi_scan_get_ap_num(&mut bss_total))? };
+    if bss_total == 0 {
+        return Ok(occupied);
+    }
+
+    unsafe {
+        let records = malloc((bss_total as usize * core::mem::size_of::<wifi_ap_record_t>()) as u32)
+            as *mut wifi_ap_record_t;
+        if records.is_null() {
+            return Err(WifiError::NoMem);
+        }
+
+        let res = esp_result(esp_wifi_scan_get_ap_records(&mut bss_total, records));
+
+        if res.is_ok() {
+            for i in 0..bss_total as usize {
+                let channel = (*records.add(i)).primary as usize;
+                if let Some(count) = occupied.get_mut(channel) {
+                    *count += 1;
+                }
+            }
+        }
+
+        free(records as *mut crate::binary::c_types::c_void);
+        res?;
+    }
+
+    Ok(occupied)
+}
+
+/// Scans for access points and returns up to `N` of the found ones
+pub fn wifi_scan_n<const N: usize>() -> Result<heapless::Vec<AccessPointInfo, N>, WifiError> {
+    scan_n_with_total().map(|(result, _)| result)
+}
+
+/// [wifi_scan_n] and how many access points were found, including the ones which didn't fit
+pub(crate) fn scan_n_with_total<const N: usize>(
+) -> Result<(heapless::Vec<AccessPointInfo, N>, usize), WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    wifi_start_scan()?;
+
+    // before fetching the records, that frees them
+    let mut found: u16 = 0;
+    unsafe { esp_result(esp_wifi_scan_get_ap_num(&mut found))? };
+
+    let mut result = heapless::Vec::new();
+    unsafe {
+        let mut records: [wifi_ap_record_t; N] = core::mem::zeroed();
+        let mut bss_total: u16 = N as u16;
+        esp_result(esp_wifi_scan_get_ap_records(
+            &mut bss_total,
+            &mut records as *mut wifi_ap_record_t,
+        ))?;
+
+        for record in records.iter().take(bss_total as usize) {
+            result.push(AccessPointInfo::from_record(record)).ok();
+        }
+    }
+
+    Ok((result, found as usize))
+}
+
+/// Scans for access points and writes as many of the found ones as fit into `buffer`
+///
+/// Returns the initialized part of `buffer`. The raw records are temporarily allocated
+/// from the driver's heap.
+pub fn wifi_scan_into(
+    buffer: &mut [MaybeUninit<AccessPointInfo>],
+) -> Result<&mut [AccessPointInfo], WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    wifi_start_scan()?;
+
+    let mut bss_total: u16 = 0;
+    unsafe { esp_result(esp_wifi_scan_get_ap_num(&mut bss_total))? };
+
+    let count = usize::min(bss_total as usize, buffer.len());
+    if count == 0 {
+        return Ok(&mut []);
+    }
+
+    unsafe {
+        let records = malloc((count * core::mem::size_of::<wifi_ap_record_t>()) as u32)
+            as *mut wifi_ap_record_t;
+        if records.is_null() {
+            return Err(WifiError::NoMem);
+        }
+
+        let mut bss_total = count as u16;
+        let res = esp_result(esp_wifi_scan_get_ap_records(&mut bss_total, records));
+
+        if res.is_ok() {
+            for (i, slot) in buffer.iter_mut().take(bss_total as usize).enumerate() {
+                slot.write(AccessPointInfo::from_record(&*records.add(i)));
+            }
+        }
+
+        free(records as *mut crate::binary::c_types::c_void);
+        res?;
+
+        Ok(core::slice::from_raw_parts_mut(
+            buffer.as_mut_ptr() as *mut AccessPointInfo,
+            bss_total as usize,
+        ))
+    }
 }
 
-pub fn wifi_connect(ssid: &str, password: &str) -> i32 {
+/// Fails with `WrongMode` unless the mode includes the station and with
+/// `InvalidSsid`/`InvalidPassword` if they are longer than 32/64 bytes
+pub fn wifi_connect(ssid: &str, password: &str) -> Result<(), WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    if ssid.len() > 32 {
+        return Err(WifiError::InvalidSsid);
+    }
+
+    if password.len() > 64 {
+        return Err(WifiError::InvalidPassword);
+    }
+
     unsafe {
         let mut cfg = wifi_config_t {
             sta: wifi_sta_config_t {
@@ -423,7 +909,7 @@ pub fn wifi_connect(ssid: &str, password: &str) -> i32 {
                 bssid_set: false,
                 bssid: [0; 6],
                 channel: 10,
-                listen_interval: 3,
+                listen_interval: LISTEN_INTERVAL,
                 sort_method: wifi_sort_method_t_WIFI_CONNECT_AP_BY_SIGNAL,
                 threshold: wifi_scan_threshold_t {
                     rssi: -99,
@@ -440,18 +926,611 @@ pub fn wifi_connect(ssid: &str, password: &str) -> i32 {
 
         cfg.sta.ssid[0..(ssid.len())].copy_from_slice(ssid.as_bytes());
         cfg.sta.password[0..(password.len())].copy_from_slice(password.as_bytes());
+        cfg.sta.set_rm_enabled(NEIGHBOR_REPORTS as u32);
+
+        esp_result(esp_wifi_set_config(wifi_interface_t_WIFI_IF_STA, &mut cfg))?;
+
+        esp_result(esp_wifi_connect())
+    }
+}
+
+/// Connects like [wifi_connect] but with the pairwise master key of [wifi_pmk] instead of
+/// the passphrase
+///
+/// Deriving the PMK from a passphrase takes 4096 rounds of HMAC-SHA1. Store the PMK of the
+/// first connection (e.g. in RTC memory across deep sleep) to skip that on reconnects.
+/// Only for WPA/WPA2-Personal networks - SAE (WPA3) needs the passphrase.
+pub fn wifi_connect_with_pmk(ssid: &str, pmk: &[u8; 32]) -> Result<(), WifiError> {
+    let hex = types::pmk_hex(pmk);
+    // hex digits are ASCII
+    wifi_connect(ssid, unsafe { core::str::from_utf8_unchecked(&hex) })
+}
+
+/// The pairwise master key of the current connection, for [wifi_connect_with_pmk]
+///
+/// Fails with `NotSupported` unless connected to a WPA/WPA2-Personal network. Within one
+/// boot the driver reuses the PMK on its own as long as SSID and passphrase don't change,
+/// and caches the PMKSAs of SAE and enterprise connections for reassociation.
+pub fn wifi_pmk() -> Result<[u8; 32], WifiError> {
+    let info = wifi_association_info()?;
+    if !matches!(
+        info.auth_method,
+        AuthMethod::WPA | AuthMethod::WPA2Personal | AuthMethod::WPAWPA2Personal
+    ) {
+        return Err(WifiError::NotSupported);
+    }
+
+    let profile_pmk = unsafe { esp_wifi_sta_get_prof_pmk_internal() };
+    if profile_pmk.is_null() {
+        return Err(WifiError::NotSupported);
+    }
+
+    let mut pmk = [0u8; 32];
+    unsafe {
+        pmk.copy_from_slice(core::slice::from_raw_parts(profile_pmk, 32));
+    }
+    Ok(pmk)
+}
+
+/// The capabilities negotiated with the access point - fails with `NotConnected` while
+/// the station isn't connected
+pub fn wifi_association_info() -> Result<AssociationInfo, WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    if !is_connected() {
+        return Err(WifiError::NotConnected);
+    }
+
+    unsafe {
+        let mut record: wifi_ap_record_t = core::mem::zeroed();
+        esp_result(esp_wifi_sta_get_ap_info(&mut record))?;
+        Ok(AssociationInfo::from_record(
+            &record,
+            esp_wifi_sta_pmf_enabled(),
+        ))
+    }
+}
+
+pub fn wifi_disconnect() -> Result<(), WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    unsafe { esp_result(esp_wifi_disconnect()) }
+}
+
+/// What the driver supports with the current build
+///
+/// WPA3 depends on the feature caps passed to the driver.
+pub fn get_capabilities() -> Capabilities {
+    Capabilities::from_feature_caps(unsafe { g_wifi_feature_caps })
+}
+
+static mut ROAM_THRESHOLDS: RoamThresholds = RoamThresholds::NEVER;
+static mut ROAM_TRIGGER: Option<RoamTrigger> = None;
+static mut ROAM_TX_FAILURES: TxFailureWindow = TxFailureWindow::new();
+
+/// RSSI threshold for a disabled [RoamThresholds::rssi_dbm] - the lowest the driver takes
+const RSSI_THRESHOLD_OFF: i32 = -100;
+
+fn rssi_threshold(thresholds: &RoamThresholds) -> i32 {
+    thresholds
+        .rssi_dbm
+        .map_or(RSSI_THRESHOLD_OFF, |rssi| rssi as i32)
+}
+
+/// Sets when [take_roam_trigger] suggests roaming - needs [wifi_init]
+pub fn set_roam_thresholds(thresholds: RoamThresholds) -> Result<(), WifiError> {
+    unsafe {
+        esp_result(esp_wifi_set_rssi_threshold(rssi_threshold(&thresholds)))?;
+
+        critical_section::with(|_| {
+            ROAM_THRESHOLDS = thresholds;
+            ROAM_TRIGGER = None;
+            ROAM_TX_FAILURES = TxFailureWindow::new();
+        });
+    }
 
-        let res = esp_wifi_set_config(wifi_interface_t_WIFI_IF_STA, &mut cfg);
-        if res != 0 {
-            return res;
+    Ok(())
+}
+
+pub fn roam_thresholds() -> RoamThresholds {
+    unsafe { ROAM_THRESHOLDS }
+}
+
+/// The threshold crossed since the last call
+///
+/// The driver reports a low RSSI only once, taking a [RoamTrigger::RssiLow] arms the
+/// threshold again.
+pub fn take_roam_trigger() -> Option<RoamTrigger> {
+    let trigger = critical_section::with(|_| unsafe { ROAM_TRIGGER.take() });
+
+    if let Some(RoamTrigger::RssiLow { .. }) = trigger {
+        unsafe {
+            let thresholds = ROAM_THRESHOLDS;
+            esp_result(esp_wifi_set_rssi_threshold(rssi_threshold(&thresholds))).ok();
+        }
+    }
+
+    trigger
+}
+
+pub(crate) fn roam_rssi_low(rssi: i32) {
+    debug!("rssi {} below the roaming threshold", rssi);
+    critical_section::with(|_| unsafe {
+        ROAM_TRIGGER = Some(RoamTrigger::RssiLow { rssi: rssi as i8 });
+    });
+}
+
+fn count_roam_tx(success: bool) {
+    critical_section::with(|_| unsafe {
+        let thresholds = ROAM_THRESHOLDS;
+        if let Some(percent) = ROAM_TX_FAILURES.count(&thresholds, success) {
+            debug!(
+                "{}% of the frames failed - above the roaming threshold",
+                percent
+            );
+            ROAM_TRIGGER = Some(RoamTrigger::TxFailures { percent });
         }
+    });
+}
+
+/// Default of [set_keepalive_interval] used by the driver
+pub const DEFAULT_KEEPALIVE_INTERVAL_MS: u32 = 10_000;
+
+static mut KEEPALIVE_INTERVAL_MS: u32 = DEFAULT_KEEPALIVE_INTERVAL_MS;
+
+/// Sets how often the station sends a null data frame to the AP while idle
+///
+/// Some routers drop the association or NAT state of quiet stations - a shorter interval
+/// keeps them alive at the cost of some power. Can be called at any time.
+pub fn set_keepalive_interval(interval_ms: u32) {
+    unsafe {
+        KEEPALIVE_INTERVAL_MS = interval_ms;
+        // the driver expects microseconds
+        esp_wifi_set_keep_alive_time(interval_ms.saturating_mul(1000));
+    }
+}
+
+pub fn keepalive_interval() -> u32 {
+    unsafe { KEEPALIVE_INTERVAL_MS }
+}
 
-        esp_wifi_connect()
+/// Default of [set_beacon_timeout] used by the driver
+pub const DEFAULT_BEACON_TIMEOUT_S: u16 = 6;
+/// The shortest beacon timeout the driver accepts
+pub const MIN_BEACON_TIMEOUT_S: u16 = 3;
+
+/// Sets how long the station waits for a beacon before considering the link lost
+///
+/// With access points sending a beacon every 102.4ms, the default of 6s are about 60
+/// missed beacons. When the time is up `WifiEvent::StaBeaconTimeout` is emitted as an
+/// early warning - the driver then probes the access point and disconnects with
+/// `WifiError::BeaconTimeout` only if it doesn't answer. Needs the driver started, fails
+/// with `InvalidArg` below [MIN_BEACON_TIMEOUT_S].
+pub fn set_beacon_timeout(seconds: u16) -> Result<(), WifiError> {
+    if seconds < MIN_BEACON_TIMEOUT_S {
+        return Err(WifiError::InvalidArg);
+    }
+
+    unsafe {
+        esp_result(esp_wifi_set_inactive_time(
+            wifi_interface_t_WIFI_IF_STA,
+            seconds,
+        ))
     }
 }
 
-pub fn wifi_stop() -> i32 {
-    unsafe { esp_wifi_stop() }
+pub fn beacon_timeout() -> Result<u16, WifiError> {
+    let mut seconds = 0;
+    unsafe {
+        esp_result(esp_wifi_get_inactive_time(
+            wifi_interface_t_WIFI_IF_STA,
+            &mut seconds,
+        ))?
+    };
+    Ok(seconds)
+}
+
+static mut RETRY_LIMITS: (u8, u8) = (0, 0);
+
+/// Caps the transmission attempts of unicast frames
+///
+/// `short` applies to frames sent without RTS/CTS, `long` to the ones protected by it.
+/// Lower limits bound the time a frame can occupy the air at the cost of more frames
+/// lost. Can be called at any time.
+pub fn set_retry_limits(short: u8, long: u8) -> Result<(), WifiError> {
+    unsafe {
+        esp_result(esp_wifi_internal_set_retry_counter(
+            short as u32,
+            long as u32,
+        ))?;
+        RETRY_LIMITS = (short, long);
+    }
+
+    Ok(())
+}
+
+/// The short and long retry limits set via [set_retry_limits] - `(0, 0)` until it's
+/// called, the driver's defaults aren't readable
+pub fn retry_limits() -> (u8, u8) {
+    unsafe { RETRY_LIMITS }
+}
+
+// the layout of libpp - esp_wifi_internal_get_rts / set_rts (lmac.o) copy a u16 at
+// offset 0 and u8s at 2, 3 and 4, in the order of its log output. Only the threshold is
+// touched
+#[repr(C)]
+#[derive(Default)]
+struct RtsConfig {
+    threshold: u16,
+    mpdu_retry: u8,
+    long_ampdu: u8,
+    short_ampdu: u8,
+}
+
+/// The largest frame size - a threshold above it disables RTS/CTS
+pub const RTS_THRESHOLD_OFF: u16 = 2347;
+
+/// Protects frames longer than `threshold` bytes with RTS/CTS
+///
+/// Helps with hidden stations in dense environments at the cost of airtime. Use
+/// [RTS_THRESHOLD_OFF] to disable it again. Can be called at any time.
+pub fn set_rts_threshold(threshold: u16) -> Result<(), WifiError> {
+    unsafe {
+        let mut config = RtsConfig::default();
+        esp_result(esp_wifi_internal_get_rts(&mut config))?;
+        config.threshold = threshold;
+        esp_result(esp_wifi_internal_set_rts(&config))
+    }
+}
+
+pub fn rts_threshold() -> Result<u16, WifiError> {
+    unsafe {
+        let mut config = RtsConfig::default();
+        esp_result(esp_wifi_internal_get_rts(&mut config))?;
+        Ok(config.threshold)
+    }
+}
+
+/// Maximum TX power in units of 0.25dBm (20dBm) - used for long-distance links
+const MAX_TX_POWER: i8 = 84;
+
+/// Interfaces with long-distance tuning and the TX power to restore afterwards
+static mut LONG_DISTANCE: [bool; 2] = [false, false];
+static mut TX_POWER_BEFORE_LONG_DISTANCE: i8 = 0;
+
+/// Tunes an interface for point-to-point links over hundreds of meters
+///
+/// Fixes the rate at 1 Mbit/s (802.11b, long preamble) and raises the TX power to the
+/// maximum the country allows. The blobs don't allow extending the ACK timeout or the
+/// slot time, so links beyond a few hundred meters still lose frames to ACK timeouts.
+/// When both ends run this crate `WIFI_PROTOCOL_LR` (via `raw::esp_wifi_set_protocol`)
+/// adds Espressif's long range mode. Needs the driver started.
+pub fn set_long_distance(interface: WifiDeviceMode, enabled: bool) -> Result<(), WifiError> {
+    let (index, ifx) = match interface {
+        WifiDeviceMode::Sta => (0, wifi_interface_t_WIFI_IF_STA),
+        WifiDeviceMode::Ap => (1, wifi_interface_t_WIFI_IF_AP),
+    };
+
+    unsafe {
+        if LONG_DISTANCE[index] == enabled {
+            return Ok(());
+        }
+
+        esp_result(esp_wifi_internal_set_fix_rate(
+            ifx,
+            enabled,
+            wifi_phy_rate_t_WIFI_PHY_RATE_1M_L,
+        ))?;
+
+        let other = LONG_DISTANCE[1 - index];
+        if enabled && !other {
+            esp_result(esp_wifi_get_max_tx_power(
+                &mut TX_POWER_BEFORE_LONG_DISTANCE,
+            ))?;
+            esp_result(esp_wifi_set_max_tx_power(MAX_TX_POWER))?;
+        } else if !enabled && !other {
+            esp_result(esp_wifi_set_max_tx_power(TX_POWER_BEFORE_LONG_DISTANCE))?;
+        }
+
+        LONG_DISTANCE[index] = enabled;
+    }
+
+    Ok(())
+}
+
+pub fn long_distance(interface: WifiDeviceMode) -> bool {
+    unsafe {
+        match interface {
+            WifiDeviceMode::Sta => LONG_DISTANCE[0],
+            WifiDeviceMode::Ap => LONG_DISTANCE[1],
+        }
+    }
+}
+
+/// The noise floor of the current channel in dBm
+///
+/// Read from the PHY when it calibrates, so it's only meaningful with the driver started.
+/// The blobs don't measure the channel's busy time - use the number of access points
+/// found by a scan (see [wifi_least_congested_channel]) to compare channels instead.
+pub fn noise_floor() -> i8 {
+    unsafe { wDev_GetNoiseFloor() }
+}
+
+static mut TX_DUTY_CYCLE: Option<DutyCycle> = None;
+
+/// Limits the airtime of [wifi_transmit_raw] - `None` removes the limit
+pub fn set_tx_duty_cycle(duty_cycle: Option<DutyCycle>) {
+    critical_section::with(|_| unsafe { TX_DUTY_CYCLE = duty_cycle });
+}
+
+/// The limit set via [set_tx_duty_cycle] including the budget left
+pub fn tx_duty_cycle() -> Option<DutyCycle> {
+    critical_section::with(|_| unsafe { TX_DUTY_CYCLE })
+}
+
+/// Sends a raw 802.11 frame (without FCS) via `interface`
+///
+/// The driver fills in the sequence number. Fails with `DutyCycleExceeded` if the frame
+/// doesn't fit into the budget of [set_tx_duty_cycle]. Needs the driver started.
+pub fn wifi_transmit_raw(interface: WifiDeviceMode, frame: &[u8]) -> Result<(), WifiError> {
+    let ifx = match interface {
+        WifiDeviceMode::Sta => wifi_interface_t_WIFI_IF_STA,
+        WifiDeviceMode::Ap => wifi_interface_t_WIFI_IF_AP,
+    };
+
+    // concurrent senders must not spend the same budget
+    let now_us = crate::timer::get_systimer_count() / 16;
+    let allowed = critical_section::with(|_| unsafe {
+        match TX_DUTY_CYCLE {
+            Some(ref mut duty_cycle) => duty_cycle.try_transmit_at(now_us, frame.len()),
+            None => true,
+        }
+    });
+    if !allowed {
+        debug!("raw frame of {} bytes exceeds the duty cycle", frame.len());
+        return Err(WifiError::DutyCycleExceeded);
+    }
+
+    unsafe {
+        esp_result(esp_wifi_80211_tx(
+            ifx,
+            frame.as_ptr() as *const crate::binary::c_types::c_void,
+            frame.len() as i32,
+            true,
+        ))
+    }
+}
+
+static mut PROMISCUOUS: bool = false;
+static mut SNIFFER_CALLBACK: Option<fn(&SnifferFrame)> = None;
+
+/// Receives the frames captured in promiscuous mode - called from the driver's task, so
+/// it should be short
+pub fn set_sniffer_callback(callback: Option<fn(&SnifferFrame)>) {
+    unsafe { SNIFFER_CALLBACK = callback };
+}
+
+/// Switches promiscuous (sniffer) mode on or off
+///
+/// Works any time after [wifi_init], also while the station is connected - the radio
+/// keeps its channel and the connection keeps passing traffic. Only frames of the
+/// connection's channel are passed on then, frames seen while a scan visits other
+/// channels are dropped. The frames go to the callback of [set_sniffer_callback].
+pub fn set_promiscuous(enabled: bool) -> Result<(), WifiError> {
+    unsafe {
+        if enabled == PROMISCUOUS {
+            return Ok(());
+        }
+
+        if enabled {
+            esp_result(esp_wifi_set_promiscuous_rx_cb(Some(promiscuous_rx)))?;
+        }
+        esp_result(esp_wifi_set_promiscuous(enabled))?;
+        PROMISCUOUS = enabled;
+    }
+
+    Ok(())
+}
+
+pub fn is_promiscuous() -> bool {
+    unsafe { PROMISCUOUS }
+}
+
+/// Selects the kinds of frames promiscuous mode captures - needs [wifi_init]
+///
+/// Leaving out data frames keeps the load low while the connection carries traffic.
+pub fn set_sniffer_filter(filter: SnifferFilter) -> Result<(), WifiError> {
+    unsafe { esp_result(esp_wifi_set_promiscuous_filter(&filter.to_raw())) }
+}
+
+unsafe extern "C" fn promiscuous_rx(
+    buf: *mut crate::binary::c_types::c_void,
+    packet_type: wifi_promiscuous_pkt_type_t,
+) {
+    let callback = match SNIFFER_CALLBACK {
+        Some(callback) => callback,
+        None => return,
+    };
+
+    let packet = &*(buf as *const wifi_promiscuous_pkt_t);
+    if let Some(channel) = connected_channel() {
+        if packet.rx_ctrl.channel() != channel as u32 {
+            return;
+        }
+    }
+
+    let packet_type = SnifferPacketType::from_raw(packet_type);
+    // `sig_len` includes the FCS, misc packets come without payload
+    let len = match packet_type {
+        SnifferPacketType::Misc => 0,
+        _ => (packet.rx_ctrl.sig_len() as usize).saturating_sub(4),
+    };
+
+    callback(&SnifferFrame {
+        packet_type,
+        rssi: packet.rx_ctrl.rssi() as i8,
+        channel: packet.rx_ctrl.channel() as u8,
+        timestamp_us: packet.rx_ctrl.timestamp(),
+        data: core::slice::from_raw_parts(packet.payload.as_ptr(), len),
+    });
+}
+
+static mut POWER_SAVE: PowerSaveMode = PowerSaveMode::None;
+
+/// Lets the radio sleep while the station is idle
+///
+/// The driver wakes it up for the DTIM beacons and whenever there is something to send.
+/// [crate::wifi_interface::WifiInterface::set_auto_power_save] switches automatically.
+pub fn set_power_saving(mode: PowerSaveMode) -> Result<(), WifiError> {
+    unsafe {
+        if mode == POWER_SAVE {
+            return Ok(());
+        }
+
+        esp_result(esp_wifi_set_ps(mode.to_raw()))?;
+        POWER_SAVE = mode;
+    }
+
+    Ok(())
+}
+
+pub fn power_saving() -> PowerSaveMode {
+    unsafe { POWER_SAVE }
+}
+
+/// Default of [set_listen_interval]
+pub const DEFAULT_LISTEN_INTERVAL: u16 = 3;
+
+/// Listen interval set by [apply_low_power_preset]
+pub const LOW_POWER_LISTEN_INTERVAL: u16 = 10;
+
+static mut LISTEN_INTERVAL: u16 = DEFAULT_LISTEN_INTERVAL;
+
+/// Sets how many beacon intervals the station sleeps through with
+/// `PowerSaveMode::MaxModem` - takes effect with the next [wifi_connect]
+///
+/// Longer intervals save power but delay frames buffered by the access point.
+pub fn set_listen_interval(interval: u16) {
+    unsafe {
+        LISTEN_INTERVAL = interval.max(1);
+    }
+}
+
+pub fn listen_interval() -> u16 {
+    unsafe { LISTEN_INTERVAL }
+}
+
+/// Configures the station for devices sending a few packets per minute - call it
+/// before [wifi_connect]
+///
+/// Selects `PowerSaveMode::MaxModem` with [LOW_POWER_LISTEN_INTERVAL]. Building with
+/// `ESP32C3_WIFI_PRESET=low-power` additionally shrinks the buffer pools and slows down
+/// the scheduler tick. Don't combine it with automatic power saving of the
+/// [crate::wifi_interface::WifiInterface] which switches to `MinModem`.
+pub fn apply_low_power_preset() -> Result<(), WifiError> {
+    set_listen_interval(LOW_POWER_LISTEN_INTERVAL);
+    set_power_saving(PowerSaveMode::MaxModem)
+}
+
+static mut NEIGHBOR_REPORTS: bool = false;
+static mut NEIGHBOR_REPORT: Option<NeighborList> = None;
+
+/// Announces radio measurement (802.11k) support so the access point answers
+/// [wifi_request_neighbor_report] - takes effect with the next [wifi_connect]
+pub fn set_neighbor_reports(enabled: bool) {
+    unsafe {
+        NEIGHBOR_REPORTS = enabled;
+    }
+}
+
+/// Asks the access point for its neighbors - the answer is picked up with
+/// [take_neighbor_report]
+///
+/// Fails with `InvalidState` if neighbor reports weren't enabled when connecting.
+pub fn wifi_request_neighbor_report() -> Result<(), WifiError> {
+    if !wifi_get_mode().is_sta() {
+        return Err(WifiError::WrongMode);
+    }
+
+    if !is_connected() {
+        return Err(WifiError::NotConnected);
+    }
+
+    unsafe {
+        if !NEIGHBOR_REPORTS {
+            return Err(WifiError::InvalidState);
+        }
+
+        if esp_rrm_send_neighbor_rep_request(Some(neighbor_report_cb), core::ptr::null_mut()) != 0 {
+            return Err(WifiError::Failed);
+        }
+    }
+
+    Ok(())
+}
+
+/// The neighbors from the last answer - empty if the access point had none to report
+pub fn take_neighbor_report() -> Option<NeighborList> {
+    critical_section::with(|_| unsafe { NEIGHBOR_REPORT.take() })
+}
+
+unsafe extern "C" fn neighbor_report_cb(
+    _ctx: *mut crate::binary::c_types::c_void,
+    report: *const u8,
+    report_len: usize,
+) {
+    let neighbors = if report.is_null() {
+        NeighborList::new()
+    } else {
+        parse_neighbor_report(core::slice::from_raw_parts(report, report_len))
+    };
+    debug!("neighbor report with {} entries", neighbors.len());
+
+    critical_section::with(|_| NEIGHBOR_REPORT = Some(neighbors));
+}
+
+pub fn wifi_stop() -> Result<(), WifiError> {
+    unsafe { esp_result(esp_wifi_stop()) }
+}
+
+/// How long [wifi_prepare_for_deep_sleep] waits for pending frames and the disassociation
+pub const DEEP_SLEEP_TEARDOWN_TIMEOUT_MS: u64 = 500;
+
+/// Tears the driver down in the right order before entering deep sleep
+///
+/// Sends the frame queued by the network stack, leaves the access point (and
+/// deauthenticates the stations of the SoftAP) so it doesn't keep stale state, stops the
+/// driver and its timers and powers down the RF. Waits at most
+/// [DEEP_SLEEP_TEARDOWN_TIMEOUT_MS] for the frames to go out. `wifi_init` is needed
+/// after waking up.
+pub fn wifi_prepare_for_deep_sleep() -> Result<(), WifiError> {
+    let deadline = crate::timer::get_systimer_count() + DEEP_SLEEP_TEARDOWN_TIMEOUT_MS * 16_000;
+    let wait_until = |done: &dyn Fn() -> bool| {
+        while !done() && crate::timer::get_systimer_count() < deadline {}
+    };
+
+    wait_until(&|| critical_section::with(|_| unsafe { !TX_QUEUED }));
+
+    if is_connected() {
+        wifi_disconnect()?;
+        wait_until(&|| !is_connected());
+    }
+
+    if wifi_get_mode().is_ap() {
+        // aid 0 deauthenticates all stations
+        unsafe { esp_result(esp_wifi_deauth_sta(0))? };
+    }
+
+    wifi_stop()?;
+
+    crate::compat::timer_compat::compat_timer_disarm_all();
+    unsafe { phy_close_rf() };
+
+    Ok(())
 }
 
 pub fn init_clocks() {
@@ -478,11 +1557,73 @@ pub fn init_clocks() {
     }
 }
 
-pub struct WifiDevice {}
+unsafe fn rx_queue(
+    mode: WifiDeviceMode,
+) -> &'static mut Option<SimpleQueue<DataFrame, RX_QUEUE_SLOTS>> {
+    match mode {
+        WifiDeviceMode::Sta => &mut DATA_QUEUE_RX,
+        WifiDeviceMode::Ap => &mut DATA_QUEUE_RX_AP,
+    }
+}
+
+/// A smoltcp device sending and receiving on one interface
+///
+/// Received frames are queued per interface so in AP+STA mode each device can be used by
+/// its own network stack (e.g. a DHCP client on the station, a static subnet on the AP).
+pub struct WifiDevice {
+    mode: WifiDeviceMode,
+}
+
+impl Default for WifiDevice {
+    fn default() -> Self {
+        WifiDevice::new()
+    }
+}
 
 impl WifiDevice {
+    /// The device of the station interface
     pub fn new() -> WifiDevice {
-        WifiDevice {}
+        WifiDevice::new_sta()
+    }
+
+    /// The device of the station interface - for station mode
+    pub fn new_sta() -> WifiDevice {
+        WifiDevice {
+            mode: WifiDeviceMode::Sta,
+        }
+    }
+
+    /// The device of the SoftAP interface - for SoftAP mode
+    pub fn new_ap() -> WifiDevice {
+        WifiDevice {
+            mode: WifiDeviceMode::Ap,
+        }
+    }
+
+    /// The devices of the station and the SoftAP interface - for AP+STA mode
+    pub fn new_ap_sta() -> (WifiDevice, WifiDevice) {
+        (
+            WifiDevice {
+                mode: WifiDeviceMode::Sta,
+            },
+            WifiDevice {
+                mode: WifiDeviceMode::Ap,
+            },
+        )
+    }
+
+    pub fn mode(&self) -> WifiDeviceMode {
+        self.mode
+    }
+
+    /// The MAC address of the device's interface - use it as the ethernet address
+    pub fn mac_address(&self) -> [u8; 6] {
+        let mut mac = [0u8; 6];
+        match self.mode {
+            WifiDeviceMode::Sta => get_sta_mac(&mut mac),
+            WifiDeviceMode::Ap => get_ap_mac(&mut mac),
+        }
+        mac
     }
 }
 
@@ -494,7 +1635,7 @@ impl<'a> Device<'a> for WifiDevice {
 
     fn receive(&'a mut self) -> Option<(Self::RxToken, Self::TxToken)> {
         let available = unsafe {
-            if let Some(ref data_queue_rx) = DATA_QUEUE_RX {
+            if let Some(data_queue_rx) = rx_queue(self.mode) {
                 !data_queue_rx.is_empty()
             } else {
                 false
@@ -502,14 +1643,17 @@ impl<'a> Device<'a> for WifiDevice {
         };
 
         if available {
-            Some((WifiRxToken::default(), WifiTxToken::default()))
+            Some((
+                WifiRxToken { mode: self.mode },
+                WifiTxToken { mode: self.mode },
+            ))
         } else {
             None
         }
     }
 
     fn transmit(&'a mut self) -> Option<Self::TxToken> {
-        Some(WifiTxToken::default())
+        Some(WifiTxToken { mode: self.mode })
     }
 
     fn capabilities(&self) -> smoltcp::phy::DeviceCapabilities {
@@ -520,8 +1664,10 @@ impl<'a> Device<'a> for WifiDevice {
     }
 }
 
-#[derive(Debug, Default)]
-pub struct WifiRxToken {}
+#[derive(Debug)]
+pub struct WifiRxToken {
+    mode: WifiDeviceMode,
+}
 
 impl RxToken for WifiRxToken {
     fn consume<R, F>(self, _timestamp: smoltcp::time::Instant, f: F) -> smoltcp::Result<R>
@@ -530,7 +1676,7 @@ impl RxToken for WifiRxToken {
     {
         let mut result: Option<smoltcp::Result<R>> = None;
         unsafe {
-            if let Some(ref mut data_queue_rx) = DATA_QUEUE_RX {
+            if let Some(data_queue_rx) = rx_queue(self.mode) {
                 if !data_queue_rx.is_empty() {
                     let element = data_queue_rx.dequeue();
 
@@ -539,7 +1685,7 @@ impl RxToken for WifiRxToken {
                             let buffer =
                                 core::slice::from_raw_parts(&data.data as *const u8, data.len);
                             verbose!("received {:?}", _timestamp);
-                            dump_packet_info(&buffer);
+                            dump_packet_info("rx", &buffer);
                             Some(f(&mut data.data[..]))
                         }
                         None => Some(Err(smoltcp::Error::Exhausted)),
@@ -556,8 +1702,26 @@ impl RxToken for WifiRxToken {
     }
 }
 
-#[derive(Debug, Default)]
-pub struct WifiTxToken {}
+#[derive(Debug)]
+pub struct WifiTxToken {
+    mode: WifiDeviceMode,
+}
+
+impl WifiTxToken {
+    /// Applies the rate limit of the destination station to the frame in `TX_BUFFER`
+    fn may_send(&self, len: usize) -> bool {
+        let dst = unsafe { &*(TX_BUFFER.as_ptr() as *const [u8; 6]) };
+        if self.mode != WifiDeviceMode::Ap || len < 6 || dst[0] & 0x01 != 0 {
+            return true;
+        }
+
+        let may_send = stations::station_may_send(dst, len);
+        if !may_send {
+            debug!("dropping {} bytes - station over its rate limit", len);
+        }
+        may_send
+    }
+}
 
 impl TxToken for WifiTxToken {
     fn consume<R, F>(
@@ -569,17 +1733,21 @@ impl TxToken for WifiTxToken {
     where
         F: FnOnce(&mut [u8]) -> smoltcp::Result<R>,
     {
+        // the buffer is shared by all devices - don't overwrite a frame not sent yet
+        if critical_section::with(|_| unsafe { TX_QUEUED }) {
+            return Err(smoltcp::Error::Exhausted);
+        }
+
         let res = unsafe { f(&mut TX_BUFFER[..len]) };
 
         match res {
+            // dropped like a frame lost on the air
+            Ok(_) if !self.may_send(len) => (),
             Ok(_) => {
                 critical_section::with(|_| unsafe {
-                    if !TX_QUEUED {
-                        TX_QUEUED_DATA_LEN = len as u16;
-                        TX_QUEUED = true;
-                    } else {
-                        // Err(smoltcp::Error::Exhausted)
-                    }
+                    TX_QUEUED_DATA_LEN = len as u16;
+                    TX_QUEUED_INTERFACE = self.mode;
+                    TX_QUEUED = true;
                 });
             }
             Err(_) => (),
@@ -592,33 +1760,74 @@ impl TxToken for WifiTxToken {
 pub fn send_data_if_needed() {
     let to_send = critical_section::with(|_| unsafe {
         if TX_QUEUED {
-            debug!("sending... {} bytes", TX_QUEUED_DATA_LEN);
-            dump_packet_info(&TX_BUFFER);
             TX_QUEUED = false;
-            Some((TX_BUFFER, TX_QUEUED_DATA_LEN))
+            Some((TX_BUFFER, TX_QUEUED_DATA_LEN, TX_QUEUED_INTERFACE))
         } else {
             None
         }
     });
 
-    if let Some((data, len)) = to_send {
+    if let Some((data, len, interface)) = to_send {
+        debug!("sending... {} bytes", len);
+        dump_packet_info("tx", &data[..len as usize]);
+
+        let interface = match interface {
+            WifiDeviceMode::Sta => wifi_interface_t_WIFI_IF_STA,
+            WifiDeviceMode::Ap => wifi_interface_t_WIFI_IF_AP,
+        };
+
         unsafe {
             let _res = esp_wifi_internal_tx(
-                wifi_interface_t_WIFI_IF_STA,
+                interface,
                 &data as *const _ as *mut crate::binary::c_types::c_void,
                 len,
             );
             debug!("esp_wifi_internal_tx {}", _res);
+
+            diagnostics::TX_FRAMES = diagnostics::TX_FRAMES.wrapping_add(1);
+            hook_tx(len as usize);
+            // group addressed frames aren't counted per station
+            if _res == 0 && interface == wifi_interface_t_WIFI_IF_AP && data[0] & 0x01 == 0 {
+                let dst = &*(data.as_ptr() as *const [u8; 6]);
+                stations::station_sent(dst, len as usize);
+            }
+            if _res != 0 {
+                diagnostics::TX_ERRORS = diagnostics::TX_ERRORS.wrapping_add(1);
+                event_log::log_error(_res);
+                hook_error(WifiError::from_esp_err(_res));
+            }
         }
     }
 }
 
-fn dump_packet_info(buffer: &[u8]) {
-    if !DUMP_PACKETS {
-        return;
+#[cfg(not(feature = "dump-packets"))]
+#[inline(always)]
+fn dump_packet_info(_direction: &str, _buffer: &[u8]) {}
+
+/// Prints a summary of the headers and a hexdump of a frame
+#[cfg(feature = "dump-packets")]
+fn dump_packet_info(direction: &str, buffer: &[u8]) {
+    println!("{} {} bytes", direction, buffer.len());
+
+    dump_packet_headers(buffer);
+
+    for (i, line) in buffer.chunks(16).enumerate() {
+        print!("{:04x}: ", i * 16);
+        for b in line {
+            print!("{:02x} ", b);
+        }
+        println!("");
     }
+}
 
-    let ef = smoltcp::wire::EthernetFrame::new_unchecked(buffer);
+/// Stops at the first header which doesn't parse - received frames can be truncated or
+/// malformed
+#[cfg(feature = "dump-packets")]
+fn dump_packet_headers(buffer: &[u8]) {
+    let ef = match smoltcp::wire::EthernetFrame::new_checked(buffer) {
+        Ok(ef) => ef,
+        Err(_) => return,
+    };
     println!(
         "src={:x?} dst={:x?} type={:x?}",
         ef.src_addr(),
@@ -627,7 +1836,10 @@ fn dump_packet_info(buffer: &[u8]) {
     );
     match ef.ethertype() {
         smoltcp::wire::EthernetProtocol::Ipv4 => {
-            let ip = smoltcp::wire::Ipv4Packet::new_unchecked(ef.payload());
+            let ip = match smoltcp::wire::Ipv4Packet::new_checked(ef.payload()) {
+                Ok(ip) => ip,
+                Err(_) => return,
+            };
             println!(
                 "src={:?} dst={:?} proto={:x?}",
                 ip.src_addr(),
@@ -640,11 +1852,15 @@ fn dump_packet_info(buffer: &[u8]) {
                 smoltcp::wire::IpProtocol::Icmp => {}
                 smoltcp::wire::IpProtocol::Igmp => {}
                 smoltcp::wire::IpProtocol::Tcp => {
-                    let tp = smoltcp::wire::TcpPacket::new_unchecked(ip.payload());
-                    println!("src={:?} dst={:?}", tp.src_port(), tp.dst_port());
+                    if let Ok(tp) = smoltcp::wire::TcpPacket::new_checked(ip.payload()) {
+                        println!("src={:?} dst={:?}", tp.src_port(), tp.dst_port());
+                    }
                 }
                 smoltcp::wire::IpProtocol::Udp => {
-                    let up = smoltcp::wire::UdpPacket::new_unchecked(ip.payload());
+                    let up = match smoltcp::wire::UdpPacket::new_checked(ip.payload()) {
+                        Ok(up) => up,
+                        Err(_) => return,
+                    };
                     println!("src={:?} dst={:?}", up.src_port(), up.dst_port());
 
                     for c in up.payload() {
@@ -654,6 +1870,7 @@ fn dump_packet_info(buffer: &[u8]) {
                             print!(".");
                         }
                     }
+                    println!("");
                 }
                 smoltcp::wire::IpProtocol::Ipv6Route => {}
                 smoltcp::wire::IpProtocol::Ipv6Frag => {}
@@ -664,13 +1881,14 @@ fn dump_packet_info(buffer: &[u8]) {
             }
         }
         smoltcp::wire::EthernetProtocol::Arp => {
-            let ap = smoltcp::wire::ArpPacket::new_unchecked(ef.payload());
-            println!(
-                "src={:x?} dst={:x?} src proto addr={:x?}",
-                ap.source_hardware_addr(),
-                ap.target_hardware_addr(),
-                ap.source_protocol_addr()
-            );
+            if let Ok(ap) = smoltcp::wire::ArpPacket::new_checked(ef.payload()) {
+                println!(
+                    "src={:x?} dst={:x?} src proto addr={:x?}",
+                    ap.source_hardware_addr(),
+                    ap.target_hardware_addr(),
+                    ap.source_protocol_addr()
+                );
+            }
         }
         smoltcp::wire::EthernetProtocol::Ipv6 => {}
         smoltcp::wire::EthernetProtocol::Unknown(_) => {}
@@ -0,0 +1,71 @@
+//! 802.11k neighbor reports
+//!
+//! The access point knows its neighbors - asking it via `wifi_request_neighbor_report`
+//! gives the candidates for roaming, so a roam scan can be restricted to the channels
+//! they use instead of sweeping all of them. The answer is picked up with
+//! `take_neighbor_report`. Requests need `set_neighbor_reports(true)` before connecting.
+
+/// Maximum number of neighbors kept from a report
+pub const MAX_NEIGHBORS: usize = 8;
+
+const NEIGHBOR_REPORT_EID: u8 = 52;
+/// BSSID, BSSID information, operating class, channel, PHY type
+const NEIGHBOR_REPORT_MIN_LEN: usize = 13;
+
+/// An access point from a neighbor report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Neighbor {
+    pub bssid: [u8; 6],
+    /// Reachability, security and capabilities as defined by 802.11
+    pub bssid_info: u32,
+    pub operating_class: u8,
+    pub channel: u8,
+    pub phy_type: u8,
+}
+
+pub type NeighborList = heapless::Vec<Neighbor, MAX_NEIGHBORS>;
+
+/// Parses the neighbor report elements - malformed elements end the list
+pub fn parse_neighbor_report(mut data: &[u8]) -> NeighborList {
+    let mut neighbors = NeighborList::new();
+
+    while data.len() >= 2 {
+        let (id, len) = (data[0], data[1] as usize);
+        if data.len() < 2 + len {
+            break;
+        }
+
+        let element = &data[2..2 + len];
+        if id == NEIGHBOR_REPORT_EID && len >= NEIGHBOR_REPORT_MIN_LEN {
+            let mut bssid = [0u8; 6];
+            bssid.copy_from_slice(&element[..6]);
+
+            let neighbor = Neighbor {
+                bssid,
+                bssid_info: u32::from_le_bytes([element[6], element[7], element[8], element[9]]),
+                operating_class: element[10],
+                channel: element[11],
+                phy_type: element[12],
+            };
+            if neighbors.push(neighbor).is_err() {
+                break;
+            }
+        }
+
+        data = &data[2 + len..];
+    }
+
+    neighbors
+}
+
+/// The distinct channels of the neighbors - what a roam scan needs to cover
+pub fn neighbor_channels(neighbors: &[Neighbor]) -> heapless::Vec<u8, MAX_NEIGHBORS> {
+    let mut channels = heapless::Vec::new();
+    for neighbor in neighbors {
+        if !channels.contains(&neighbor.channel) {
+            channels.push(neighbor.channel).ok();
+        }
+    }
+    channels
+}
@@ -3,26 +3,54 @@ use embedded_hal::prelude::_embedded_hal_blocking_rng_Read;
 use crate::{
     binary::include::*,
     compat::{
-        common::{
-            create_recursive_mutex, create_wifi_queue, lock_mutex, receive_queued, sem_create,
-            sem_delete, sem_give, sem_take, send_queued, syslog, thread_sem_get, unlock_mutex,
-            StrBuf,
-        },
-        malloc::calloc,
-        timer_compat::{
-            compat_esp_timer_create, compat_timer_arm, compat_timer_arm_us, compat_timer_disarm,
-            compat_timer_done, compat_timer_setfn,
-        },
-        work_queue::queue_work,
+        common::{syslog, StrBuf},
+        malloc::{calloc_for, heap_stats, malloc_for, realloc_for, Subsystem},
+        timer_compat::compat_esp_timer_create,
     },
+    debug,
+    log::BlobLogLevel,
     trace,
-    wifi::{phy_init_data::PHY_INIT_DATA_DEFAULT, RANDOM_GENERATOR},
+    wifi::{
+        diagnostics::{count_beacon_timeout, count_disconnect},
+        error::report_error,
+        event_log::{log_disconnect, log_error, log_event},
+        hooks::{hook_event, hook_security_event},
+        is_station_allowed,
+        os_backend::os_backend,
+        phy_init_data::PHY_INIT_DATA_DEFAULT,
+        reject_station, roam_rssi_low,
+        stations::{station_associated, station_left},
+        AuthMethod, SecurityEvent, WifiError, WifiEvent, RANDOM_GENERATOR,
+    },
 };
 
 pub static mut WIFI_STATE: i32 = -1;
 
+pub(crate) static mut LAST_EVENT: Option<WifiEvent> = None;
+
+static mut STA_CONNECTED: bool = false;
+
+static mut LAST_DISCONNECT_REASON: Option<u8> = None;
+
+/// Channel of the access point the station is connected to
+static mut STA_CHANNEL: u8 = 0;
+
 pub fn is_connected() -> bool {
-    unsafe { WIFI_STATE == wifi_event_t_WIFI_EVENT_STA_CONNECTED as i32 }
+    unsafe { STA_CONNECTED }
+}
+
+/// The channel of the access point while the station is connected
+pub(crate) fn connected_channel() -> Option<u8> {
+    if is_connected() {
+        Some(unsafe { STA_CHANNEL })
+    } else {
+        None
+    }
+}
+
+/// The reason of the last disconnect (or failed connection attempt) as an error
+pub fn last_disconnect_error() -> Option<WifiError> {
+    unsafe { LAST_DISCONNECT_REASON.map(WifiError::from_disconnect_reason) }
 }
 
 /****************************************************************************
@@ -61,6 +89,63 @@ pub unsafe extern "C" fn esp_event_send_internal(
     // probably also need to look at event_base
     WIFI_STATE = event_id;
 
+    if event_id == wifi_event_t_WIFI_EVENT_STA_DISCONNECTED as i32 && !event_data.is_null() {
+        let event = &*(event_data as *const wifi_event_sta_disconnected_t);
+        LAST_DISCONNECT_REASON = Some(event.reason);
+        count_disconnect(event.reason);
+        log_disconnect(event.reason);
+        if let Some(security_event) = SecurityEvent::from_disconnect_reason(event.reason) {
+            hook_security_event(security_event);
+        }
+    } else if event_id == wifi_event_t_WIFI_EVENT_STA_AUTHMODE_CHANGE as i32
+        && !event_data.is_null()
+    {
+        let event = &*(event_data as *const wifi_event_sta_authmode_change_t);
+        hook_security_event(SecurityEvent::AuthmodeChanged {
+            old: AuthMethod::from_raw(event.old_mode),
+            new: AuthMethod::from_raw(event.new_mode),
+        });
+    } else if event_id == wifi_event_t_WIFI_EVENT_STA_BSS_RSSI_LOW as i32 && !event_data.is_null() {
+        let event = &*(event_data as *const wifi_event_bss_rssi_low_t);
+        roam_rssi_low(event.rssi);
+    } else if event_id == wifi_event_t_WIFI_EVENT_STA_BEACON_TIMEOUT as i32 {
+        count_beacon_timeout();
+    } else if event_id == wifi_event_t_WIFI_EVENT_STA_CONNECTED as i32 {
+        LAST_DISCONNECT_REASON = None;
+        if !event_data.is_null() {
+            STA_CHANNEL = (*(event_data as *const wifi_event_sta_connected_t)).channel;
+        }
+    } else if event_id == wifi_event_t_WIFI_EVENT_AP_STACONNECTED as i32 && !event_data.is_null() {
+        let event = &*(event_data as *const wifi_event_ap_staconnected_t);
+        if is_station_allowed(&event.mac) {
+            station_associated(&event.mac);
+        } else {
+            reject_station(event.aid as u16);
+        }
+    } else if event_id == wifi_event_t_WIFI_EVENT_AP_STADISCONNECTED as i32 && !event_data.is_null()
+    {
+        let event = &*(event_data as *const wifi_event_ap_stadisconnected_t);
+        station_left(&event.mac);
+    }
+
+    // other events (scan done, SoftAP events, ...) don't change the station's connection
+    if event_id == wifi_event_t_WIFI_EVENT_STA_CONNECTED as i32 {
+        STA_CONNECTED = true;
+    } else if event_id == wifi_event_t_WIFI_EVENT_STA_DISCONNECTED as i32
+        || event_id == wifi_event_t_WIFI_EVENT_STA_STOP as i32
+    {
+        STA_CONNECTED = false;
+    }
+
+    // disconnects got logged with their reason above
+    if event_id != wifi_event_t_WIFI_EVENT_STA_DISCONNECTED as i32 || event_data.is_null() {
+        log_event(event_id);
+    }
+
+    let event = WifiEvent::from_raw(event_id, event_data);
+    LAST_EVENT = Some(event);
+    hook_event(event);
+
     0
 }
 
@@ -160,7 +245,10 @@ pub unsafe extern "C" fn set_isr(
         1 => {
             ISR_INTERRUPT_1 = (f, arg);
         }
-        _ => panic!("set_isr - unsupported interrupt number {}", n),
+        _ => {
+            debug!("set_isr - unsupported interrupt number {} - ignored", n);
+            report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+        }
     }
 }
 
@@ -182,7 +270,11 @@ pub unsafe extern "C" fn ints_on(mask: u32) {
 
     let cpuint = match mask {
         2 => 1,
-        _ => panic!("ints_on mask {} not handled", mask),
+        _ => {
+            debug!("ints_on mask {} not handled - ignored", mask);
+            report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+            return;
+        }
     };
 
     trace!("ints_on n={}", cpuint);
@@ -289,6 +381,11 @@ pub unsafe extern "C" fn wifi_int_disable(
     };
     riscv::interrupt::disable();
 
+    #[cfg(feature = "bounded-critical-sections")]
+    if res == 1 {
+        super::diagnostics::blob_critical_section_entered();
+    }
+
     trace!(
         "wifi_int_disable wifi_int_mux {:p} - return {}",
         wifi_int_mux,
@@ -324,6 +421,9 @@ pub unsafe extern "C" fn wifi_int_restore(
     );
 
     if tmp == 1 {
+        #[cfg(feature = "bounded-critical-sections")]
+        super::diagnostics::blob_critical_section_left();
+
         riscv::interrupt::enable();
     }
 }
@@ -362,7 +462,7 @@ pub unsafe extern "C" fn task_yield_from_isr() {
  ****************************************************************************/
 pub unsafe extern "C" fn semphr_create(max: u32, init: u32) -> *mut crate::binary::c_types::c_void {
     trace!("semphr_create - max {} init {}", max, init);
-    sem_create(max, init)
+    os_backend().semphr_create(max, init)
 }
 
 /****************************************************************************
@@ -380,7 +480,7 @@ pub unsafe extern "C" fn semphr_create(max: u32, init: u32) -> *mut crate::binar
  ****************************************************************************/
 pub unsafe extern "C" fn semphr_delete(semphr: *mut crate::binary::c_types::c_void) {
     trace!("semphr_delete {:p}", semphr);
-    sem_delete(semphr);
+    os_backend().semphr_delete(semphr);
 }
 
 /****************************************************************************
@@ -402,7 +502,7 @@ pub unsafe extern "C" fn semphr_take(
     tick: u32,
 ) -> i32 {
     trace!(">>>> semphr_take {:p} block_time_tick {}", semphr, tick);
-    sem_take(semphr, tick)
+    os_backend().semphr_take(semphr, tick)
 }
 
 /****************************************************************************
@@ -419,7 +519,7 @@ pub unsafe extern "C" fn semphr_take(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn semphr_give(semphr: *mut crate::binary::c_types::c_void) -> i32 {
-    sem_give(semphr)
+    os_backend().semphr_give(semphr)
 }
 
 /****************************************************************************
@@ -436,7 +536,7 @@ pub unsafe extern "C" fn semphr_give(semphr: *mut crate::binary::c_types::c_void
  *
  ****************************************************************************/
 pub unsafe extern "C" fn wifi_thread_semphr_get() -> *mut crate::binary::c_types::c_void {
-    thread_sem_get()
+    os_backend().thread_semphr_get()
 }
 
 /****************************************************************************
@@ -453,7 +553,8 @@ pub unsafe extern "C" fn wifi_thread_semphr_get() -> *mut crate::binary::c_types
  *
  ****************************************************************************/
 pub unsafe extern "C" fn mutex_create() -> *mut crate::binary::c_types::c_void {
-    todo!("mutex_create")
+    // a recursive mutex also does for a plain one
+    os_backend().recursive_mutex_create()
 }
 
 /****************************************************************************
@@ -470,7 +571,7 @@ pub unsafe extern "C" fn mutex_create() -> *mut crate::binary::c_types::c_void {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn recursive_mutex_create() -> *mut crate::binary::c_types::c_void {
-    create_recursive_mutex()
+    os_backend().recursive_mutex_create()
 }
 
 /****************************************************************************
@@ -486,8 +587,8 @@ pub unsafe extern "C" fn recursive_mutex_create() -> *mut crate::binary::c_types
  *   None
  *
  ****************************************************************************/
-pub unsafe extern "C" fn mutex_delete(_mutex: *mut crate::binary::c_types::c_void) {
-    todo!("mutex_delete")
+pub unsafe extern "C" fn mutex_delete(mutex: *mut crate::binary::c_types::c_void) {
+    trace!("mutex_delete {:p} - not implemented - doing nothing", mutex);
 }
 
 /****************************************************************************
@@ -504,7 +605,7 @@ pub unsafe extern "C" fn mutex_delete(_mutex: *mut crate::binary::c_types::c_voi
  *
  ****************************************************************************/
 pub unsafe extern "C" fn mutex_lock(mutex: *mut crate::binary::c_types::c_void) -> i32 {
-    lock_mutex(mutex)
+    os_backend().mutex_lock(mutex)
 }
 
 /****************************************************************************
@@ -521,7 +622,7 @@ pub unsafe extern "C" fn mutex_lock(mutex: *mut crate::binary::c_types::c_void)
  *
  ****************************************************************************/
 pub unsafe extern "C" fn mutex_unlock(mutex: *mut crate::binary::c_types::c_void) -> i32 {
-    unlock_mutex(mutex)
+    os_backend().mutex_unlock(mutex)
 }
 
 /****************************************************************************
@@ -539,10 +640,16 @@ pub unsafe extern "C" fn mutex_unlock(mutex: *mut crate::binary::c_types::c_void
  *
  ****************************************************************************/
 pub unsafe extern "C" fn queue_create(
-    _queue_len: u32,
-    _item_size: u32,
+    queue_len: u32,
+    item_size: u32,
 ) -> *mut crate::binary::c_types::c_void {
-    todo!("queue_create")
+    // only the queue of the WiFi task is supported, see `wifi_create_queue`
+    debug!(
+        "queue_create len={} size={} failed - not supported",
+        queue_len, item_size
+    );
+    report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+    core::ptr::null_mut()
 }
 
 /****************************************************************************
@@ -558,8 +665,8 @@ pub unsafe extern "C" fn queue_create(
  *   None
  *
  ****************************************************************************/
-pub unsafe extern "C" fn queue_delete(_queue: *mut crate::binary::c_types::c_void) {
-    todo!("queue_delete")
+pub unsafe extern "C" fn queue_delete(queue: *mut crate::binary::c_types::c_void) {
+    trace!("queue_delete {:p} - not implemented - doing nothing", queue);
 }
 
 /****************************************************************************
@@ -582,7 +689,7 @@ pub unsafe extern "C" fn queue_send(
     item: *mut crate::binary::c_types::c_void,
     block_time_tick: u32,
 ) -> i32 {
-    send_queued(queue, item, block_time_tick)
+    os_backend().queue_send(queue, item, block_time_tick)
 }
 
 /****************************************************************************
@@ -626,11 +733,11 @@ pub unsafe extern "C" fn queue_send_from_isr(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn queue_send_to_back(
-    _queue: *mut crate::binary::c_types::c_void,
-    _item: *mut crate::binary::c_types::c_void,
-    _block_time_tick: u32,
+    queue: *mut crate::binary::c_types::c_void,
+    item: *mut crate::binary::c_types::c_void,
+    block_time_tick: u32,
 ) -> i32 {
-    todo!("queue_send_to_back")
+    queue_send(queue, item, block_time_tick)
 }
 
 /****************************************************************************
@@ -649,11 +756,12 @@ pub unsafe extern "C" fn queue_send_to_back(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn queue_send_to_front(
-    _queue: *mut crate::binary::c_types::c_void,
-    _item: *mut crate::binary::c_types::c_void,
-    _block_time_tick: u32,
+    queue: *mut crate::binary::c_types::c_void,
+    item: *mut crate::binary::c_types::c_void,
+    block_time_tick: u32,
 ) -> i32 {
-    todo!("queue_send_to_front")
+    debug!("queue_send_to_front - not supported - sending to the back");
+    queue_send(queue, item, block_time_tick)
 }
 
 /****************************************************************************
@@ -676,7 +784,7 @@ pub unsafe extern "C" fn queue_recv(
     item: *mut crate::binary::c_types::c_void,
     block_time_tick: u32,
 ) -> i32 {
-    receive_queued(queue, item, block_time_tick)
+    os_backend().queue_recv(queue, item, block_time_tick)
 }
 
 /****************************************************************************
@@ -692,8 +800,8 @@ pub unsafe extern "C" fn queue_recv(
  *   Message number
  *
  ****************************************************************************/
-pub unsafe extern "C" fn queue_msg_waiting(_queue: *mut crate::binary::c_types::c_void) -> u32 {
-    todo!("queue_msg_waiting")
+pub unsafe extern "C" fn queue_msg_waiting(queue: *mut crate::binary::c_types::c_void) -> u32 {
+    os_backend().queue_msg_waiting(queue)
 }
 
 /****************************************************************************
@@ -704,7 +812,9 @@ pub unsafe extern "C" fn queue_msg_waiting(_queue: *mut crate::binary::c_types::
  *
  ****************************************************************************/
 pub unsafe extern "C" fn event_group_create() -> *mut crate::binary::c_types::c_void {
-    todo!("event_group_create")
+    debug!("event_group_create failed - not supported");
+    report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+    core::ptr::null_mut()
 }
 
 /****************************************************************************
@@ -715,7 +825,7 @@ pub unsafe extern "C" fn event_group_create() -> *mut crate::binary::c_types::c_
  *
  ****************************************************************************/
 pub unsafe extern "C" fn event_group_delete(_event: *mut crate::binary::c_types::c_void) {
-    todo!("event_group_delete")
+    trace!("event_group_delete - not implemented");
 }
 
 /****************************************************************************
@@ -729,7 +839,8 @@ pub unsafe extern "C" fn event_group_set_bits(
     _event: *mut crate::binary::c_types::c_void,
     _bits: u32,
 ) -> u32 {
-    todo!("event_group_set_bits")
+    debug!("event_group_set_bits - not supported");
+    0
 }
 
 /****************************************************************************
@@ -743,7 +854,8 @@ pub unsafe extern "C" fn event_group_clear_bits(
     _event: *mut crate::binary::c_types::c_void,
     _bits: u32,
 ) -> u32 {
-    todo!("event_group_clear_bits")
+    debug!("event_group_clear_bits - not supported");
+    0
 }
 
 /****************************************************************************
@@ -760,7 +872,8 @@ pub unsafe extern "C" fn event_group_wait_bits(
     _wait_for_all_bits: crate::binary::c_types::c_int,
     _block_time_tick: u32,
 ) -> u32 {
-    todo!("event_group_wait_bits")
+    debug!("event_group_wait_bits - not supported");
+    0
 }
 
 /****************************************************************************
@@ -803,9 +916,7 @@ pub unsafe extern "C" fn task_create_pinned_to_core(
         core_id
     );
 
-    *(task_handle as *mut usize) = crate::preempt::current_task();
-
-    queue_work(
+    os_backend().task_create(
         task_func,
         name,
         stack_depth,
@@ -813,8 +924,7 @@ pub unsafe extern "C" fn task_create_pinned_to_core(
         prio,
         task_handle,
         core_id,
-    );
-    1
+    )
 }
 
 /****************************************************************************
@@ -837,14 +947,14 @@ pub unsafe extern "C" fn task_create_pinned_to_core(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn task_create(
-    _task_func: *mut crate::binary::c_types::c_void,
-    _name: *const crate::binary::c_types::c_char,
-    _stack_depth: u32,
-    _param: *mut crate::binary::c_types::c_void,
-    _prio: u32,
-    _task_handle: *mut crate::binary::c_types::c_void,
+    task_func: *mut crate::binary::c_types::c_void,
+    name: *const crate::binary::c_types::c_char,
+    stack_depth: u32,
+    param: *mut crate::binary::c_types::c_void,
+    prio: u32,
+    task_handle: *mut crate::binary::c_types::c_void,
 ) -> i32 {
-    todo!("task_create");
+    task_create_pinned_to_core(task_func, name, stack_depth, param, prio, task_handle, 0)
 }
 
 /****************************************************************************
@@ -861,8 +971,12 @@ pub unsafe extern "C" fn task_create(
  *   None
  *
  ****************************************************************************/
-pub unsafe extern "C" fn task_delete(_task_handle: *mut crate::binary::c_types::c_void) {
-    todo!("task_delete")
+pub unsafe extern "C" fn task_delete(task_handle: *mut crate::binary::c_types::c_void) {
+    debug!(
+        "task_delete {:p} - not supported - doing nothing",
+        task_handle
+    );
+    report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
 }
 
 /****************************************************************************
@@ -880,14 +994,7 @@ pub unsafe extern "C" fn task_delete(_task_handle: *mut crate::binary::c_types::
  ****************************************************************************/
 pub unsafe extern "C" fn task_delay(tick: u32) {
     trace!("task_delay tick {}", tick);
-    let mut now = crate::timer::get_systimer_count();
-    let timeout = now + tick as u64;
-    loop {
-        if now > timeout {
-            break;
-        }
-        now = crate::timer::get_systimer_count();
-    }
+    os_backend().task_delay(tick);
 }
 
 /****************************************************************************
@@ -905,7 +1012,7 @@ pub unsafe extern "C" fn task_delay(tick: u32) {
  ****************************************************************************/
 pub unsafe extern "C" fn task_ms_to_tick(ms: u32) -> i32 {
     trace!("task_ms_to_tick ms {}", ms);
-    (ms * 16_000) as i32
+    os_backend().task_ms_to_tick(ms)
 }
 
 /****************************************************************************
@@ -922,7 +1029,7 @@ pub unsafe extern "C" fn task_ms_to_tick(ms: u32) -> i32 {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn task_get_current_task() -> *mut crate::binary::c_types::c_void {
-    let res = crate::preempt::current_task() as *mut crate::binary::c_types::c_void;
+    let res = os_backend().current_task();
     trace!("task get current task - return {:p}", res);
 
     res
@@ -966,6 +1073,14 @@ pub unsafe extern "C" fn malloc(
     crate::compat::malloc::malloc(size as u32) as *mut crate::binary::c_types::c_void
 }
 
+/// `esp_malloc` as handed to the driver - the same as [malloc] but accounted to
+/// [Subsystem::Wifi]
+pub unsafe extern "C" fn adapter_malloc(
+    size: crate::binary::c_types::c_uint,
+) -> *mut crate::binary::c_types::c_void {
+    malloc_for(Subsystem::Wifi, size as u32) as *mut crate::binary::c_types::c_void
+}
+
 /****************************************************************************
  * Name: esp_free
  *
@@ -1008,7 +1123,10 @@ pub unsafe extern "C" fn event_post(
     _event_data_size: size_t,
     _ticks_to_wait: u32,
 ) -> i32 {
-    todo!("event_post")
+    // the driver reports its events through `esp_event_send_internal`
+    debug!("event_post - not supported");
+    report_error(ESP_ERR_NOT_SUPPORTED as esp_err_t);
+    -1
 }
 
 /****************************************************************************
@@ -1025,7 +1143,8 @@ pub unsafe extern "C" fn event_post(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn get_free_heap_size() -> u32 {
-    todo!("get_free_heap_size")
+    let stats = heap_stats();
+    (stats.size - stats.used) as u32
 }
 
 /****************************************************************************
@@ -1042,7 +1161,7 @@ pub unsafe extern "C" fn get_free_heap_size() -> u32 {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn rand() -> u32 {
-    todo!("rand")
+    random()
 }
 
 /****************************************************************************
@@ -1053,7 +1172,8 @@ pub unsafe extern "C" fn rand() -> u32 {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn dport_access_stall_other_cpu_start_wrap() {
-    todo!("dport_access_stall_other_cpu_start_wrap")
+    // single core - nothing to stall
+    trace!("dport_access_stall_other_cpu_start_wrap - no-op")
 }
 
 /****************************************************************************
@@ -1064,7 +1184,7 @@ pub unsafe extern "C" fn dport_access_stall_other_cpu_start_wrap() {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn dport_access_stall_other_cpu_end_wrap() {
-    todo!("dport_access_stall_other_cpu_end_wrap")
+    trace!("dport_access_stall_other_cpu_end_wrap - no-op")
 }
 /****************************************************************************
  * Name: wifi_apb80m_request
@@ -1228,6 +1348,12 @@ pub unsafe extern "C" fn read_mac(mac: *mut u8, type_: u32) -> crate::binary::c_
         }
     }
 
+    if let Some(Some(addr)) = crate::wifi::MAC_OVERRIDES.get(type_ as usize) {
+        for i in 0..6 {
+            mac.offset(i).write_volatile(addr[i as usize]);
+        }
+    }
+
     0
 }
 
@@ -1251,7 +1377,7 @@ pub unsafe extern "C" fn timer_arm(
     tmout: u32,
     repeat: bool,
 ) {
-    compat_timer_arm(ptimer, tmout, repeat);
+    os_backend().timer_arm(ptimer, tmout, repeat);
 }
 
 /****************************************************************************
@@ -1268,7 +1394,7 @@ pub unsafe extern "C" fn timer_arm(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn timer_disarm(ptimer: *mut crate::binary::c_types::c_void) {
-    compat_timer_disarm(ptimer);
+    os_backend().timer_disarm(ptimer);
 }
 
 /****************************************************************************
@@ -1285,7 +1411,7 @@ pub unsafe extern "C" fn timer_disarm(ptimer: *mut crate::binary::c_types::c_voi
  *
  ****************************************************************************/
 pub unsafe extern "C" fn timer_done(ptimer: *mut crate::binary::c_types::c_void) {
-    compat_timer_done(ptimer);
+    os_backend().timer_done(ptimer);
 }
 
 /****************************************************************************
@@ -1308,7 +1434,7 @@ pub unsafe extern "C" fn timer_setfn(
     pfunction: *mut crate::binary::c_types::c_void,
     parg: *mut crate::binary::c_types::c_void,
 ) {
-    compat_timer_setfn(ptimer, pfunction, parg);
+    os_backend().timer_setfn(ptimer, pfunction, parg);
 }
 
 /****************************************************************************
@@ -1331,7 +1457,7 @@ pub unsafe extern "C" fn timer_arm_us(
     us: u32,
     repeat: bool,
 ) {
-    compat_timer_arm_us(ptimer, us, repeat);
+    os_backend().timer_arm_us(ptimer, us, repeat);
 }
 
 /****************************************************************************
@@ -1400,7 +1526,7 @@ pub unsafe extern "C" fn wifi_clock_disable() {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn wifi_rtc_enable_iso() {
-    todo!("wifi_rtc_enable_iso")
+    trace!("wifi_rtc_enable_iso - not implemented")
 }
 
 /****************************************************************************
@@ -1411,7 +1537,7 @@ pub unsafe extern "C" fn wifi_rtc_enable_iso() {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn wifi_rtc_disable_iso() {
-    todo!("wifi_rtc_disable_iso")
+    trace!("wifi_rtc_disable_iso - not implemented")
 }
 
 /****************************************************************************
@@ -1453,7 +1579,8 @@ pub unsafe extern "C" fn nvs_set_i8(
     _key: *const crate::binary::c_types::c_char,
     _value: i8,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_set_i8")
+    debug!("nvs_set_i8 failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1476,7 +1603,8 @@ pub unsafe extern "C" fn nvs_get_i8(
     _key: *const crate::binary::c_types::c_char,
     _out_value: *mut i8,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_get_i8")
+    debug!("nvs_get_i8 failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1499,7 +1627,8 @@ pub unsafe extern "C" fn nvs_set_u8(
     _key: *const crate::binary::c_types::c_char,
     _value: u8,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_set_u8")
+    debug!("nvs_set_u8 failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1522,7 +1651,8 @@ pub unsafe extern "C" fn nvs_get_u8(
     _key: *const crate::binary::c_types::c_char,
     _out_value: *mut u8,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_get_u8")
+    debug!("nvs_get_u8 failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1545,7 +1675,8 @@ pub unsafe extern "C" fn nvs_set_u16(
     _key: *const crate::binary::c_types::c_char,
     _value: u16,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_set_u16")
+    debug!("nvs_set_u16 failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1568,7 +1699,8 @@ pub unsafe extern "C" fn nvs_get_u16(
     _key: *const crate::binary::c_types::c_char,
     _out_value: *mut u16,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_get_u16")
+    debug!("nvs_get_u16 failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1591,7 +1723,8 @@ pub unsafe extern "C" fn nvs_open(
     _open_mode: u32,
     _out_handle: *mut u32,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_open")
+    debug!("nvs_open failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1608,7 +1741,7 @@ pub unsafe extern "C" fn nvs_open(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn nvs_close(_handle: u32) {
-    todo!("nvs_close")
+    trace!("nvs_close - not implemented")
 }
 
 /****************************************************************************
@@ -1619,7 +1752,8 @@ pub unsafe extern "C" fn nvs_close(_handle: u32) {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn nvs_commit(_handle: u32) -> crate::binary::c_types::c_int {
-    todo!("nvs_commit")
+    debug!("nvs_commit failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1644,7 +1778,8 @@ pub unsafe extern "C" fn nvs_set_blob(
     _value: *const crate::binary::c_types::c_void,
     _length: size_t,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_set_blob")
+    debug!("nvs_set_blob failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1669,7 +1804,8 @@ pub unsafe extern "C" fn nvs_get_blob(
     _out_value: *mut crate::binary::c_types::c_void,
     _length: *mut size_t,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_get_blob")
+    debug!("nvs_get_blob failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1690,7 +1826,8 @@ pub unsafe extern "C" fn nvs_erase_key(
     _handle: u32,
     _key: *const crate::binary::c_types::c_char,
 ) -> crate::binary::c_types::c_int {
-    todo!("nvs_erase_key")
+    debug!("nvs_erase_key failed - NVS is not supported");
+    -1
 }
 
 /****************************************************************************
@@ -1707,8 +1844,13 @@ pub unsafe extern "C" fn nvs_erase_key(
  *   0 if success or -1 if fail
  *
  ****************************************************************************/
-pub unsafe extern "C" fn get_random(_buf: *mut u8, _len: size_t) -> crate::binary::c_types::c_int {
-    todo!("get_random")
+pub unsafe extern "C" fn get_random(buf: *mut u8, len: size_t) -> crate::binary::c_types::c_int {
+    trace!("get_random");
+
+    match crate::wifi::fill_random(core::slice::from_raw_parts_mut(buf, len as usize)) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
 }
 
 /****************************************************************************
@@ -1725,9 +1867,11 @@ pub unsafe extern "C" fn get_random(_buf: *mut u8, _len: size_t) -> crate::binar
  *
  ****************************************************************************/
 pub unsafe extern "C" fn get_time(
-    _t: *mut crate::binary::c_types::c_void,
+    t: *mut crate::binary::c_types::c_void,
 ) -> crate::binary::c_types::c_int {
-    todo!("get_time")
+    trace!("get_time");
+    write_timeval(t as *mut timeval);
+    0
 }
 
 /****************************************************************************
@@ -1738,7 +1882,7 @@ pub unsafe extern "C" fn random() -> crate::binary::c_types::c_ulong {
 
     if let Some(ref mut rng) = RANDOM_GENERATOR {
         let mut buffer = [0u8; 4];
-        rng.read(&mut buffer).unwrap();
+        rng.read(&mut buffer).ok();
         u32::from_le_bytes(buffer)
     } else {
         0
@@ -1826,7 +1970,7 @@ pub unsafe extern "C" fn log_timestamp() -> u32 {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn malloc_internal(size: size_t) -> *mut crate::binary::c_types::c_void {
-    crate::compat::malloc::malloc(size as u32) as *mut crate::binary::c_types::c_void
+    malloc_for(Subsystem::Wifi, size as u32) as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1844,10 +1988,11 @@ pub unsafe extern "C" fn malloc_internal(size: size_t) -> *mut crate::binary::c_
  *
  ****************************************************************************/
 pub unsafe extern "C" fn realloc_internal(
-    _ptr: *mut crate::binary::c_types::c_void,
-    _size: size_t,
+    ptr: *mut crate::binary::c_types::c_void,
+    size: size_t,
 ) -> *mut crate::binary::c_types::c_void {
-    todo!("realloc_internal")
+    realloc_for(Subsystem::Wifi, ptr as *const u8, size as u32)
+        as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1868,7 +2013,7 @@ pub unsafe extern "C" fn calloc_internal(
     n: size_t,
     size: size_t,
 ) -> *mut crate::binary::c_types::c_void {
-    calloc(n as u32, size as u32) as *mut crate::binary::c_types::c_void
+    calloc_for(Subsystem::Wifi, n as u32, size as u32) as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1885,7 +2030,7 @@ pub unsafe extern "C" fn calloc_internal(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn zalloc_internal(size: size_t) -> *mut crate::binary::c_types::c_void {
-    calloc(size as u32, 1u32) as *mut crate::binary::c_types::c_void
+    calloc_for(Subsystem::Wifi, size as u32, 1u32) as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1902,7 +2047,7 @@ pub unsafe extern "C" fn zalloc_internal(size: size_t) -> *mut crate::binary::c_
  *
  ****************************************************************************/
 pub unsafe extern "C" fn wifi_malloc(size: size_t) -> *mut crate::binary::c_types::c_void {
-    malloc(size as u32)
+    malloc_for(Subsystem::Wifi, size as u32) as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1920,10 +2065,11 @@ pub unsafe extern "C" fn wifi_malloc(size: size_t) -> *mut crate::binary::c_type
  *
  ****************************************************************************/
 pub unsafe extern "C" fn wifi_realloc(
-    _ptr: *mut crate::binary::c_types::c_void,
-    _size: size_t,
+    ptr: *mut crate::binary::c_types::c_void,
+    size: size_t,
 ) -> *mut crate::binary::c_types::c_void {
-    todo!("wifi_realloc")
+    realloc_for(Subsystem::Wifi, ptr as *const u8, size as u32)
+        as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1945,7 +2091,7 @@ pub unsafe extern "C" fn wifi_calloc(
     size: size_t,
 ) -> *mut crate::binary::c_types::c_void {
     trace!("wifi_calloc {} {}", n, size);
-    calloc(n as u32, size as u32) as *mut crate::binary::c_types::c_void
+    calloc_for(Subsystem::Wifi, n as u32, size as u32) as *mut crate::binary::c_types::c_void
 }
 
 /****************************************************************************
@@ -1983,7 +2129,7 @@ pub unsafe extern "C" fn wifi_create_queue(
     queue_len: crate::binary::c_types::c_int,
     item_size: crate::binary::c_types::c_int,
 ) -> *mut crate::binary::c_types::c_void {
-    create_wifi_queue(queue_len, item_size)
+    os_backend().wifi_queue_create(queue_len, item_size)
 }
 
 /****************************************************************************
@@ -2049,7 +2195,7 @@ pub unsafe extern "C" fn coex_enable() -> crate::binary::c_types::c_int {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn coex_disable() {
-    todo!("coex_disable")
+    trace!("coex_disable - not implemented");
 }
 
 /****************************************************************************
@@ -2178,7 +2324,8 @@ pub unsafe extern "C" fn coex_schm_status_bit_set(_type_: u32, _status: u32) {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn coex_schm_interval_set(_interval: u32) -> crate::binary::c_types::c_int {
-    todo!("coex_schm_interval_set")
+    trace!("coex_schm_interval_set - not implemented");
+    0
 }
 
 /****************************************************************************
@@ -2189,7 +2336,8 @@ pub unsafe extern "C" fn coex_schm_interval_set(_interval: u32) -> crate::binary
  *
  ****************************************************************************/
 pub unsafe extern "C" fn coex_schm_interval_get() -> u32 {
-    todo!("coex_schm_interval_get")
+    trace!("coex_schm_interval_get - not implemented");
+    0
 }
 
 /****************************************************************************
@@ -2200,7 +2348,8 @@ pub unsafe extern "C" fn coex_schm_interval_get() -> u32 {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn coex_schm_curr_period_get() -> u8 {
-    todo!("coex_schm_curr_period_get")
+    trace!("coex_schm_curr_period_get - not implemented");
+    0
 }
 
 /****************************************************************************
@@ -2211,13 +2360,15 @@ pub unsafe extern "C" fn coex_schm_curr_period_get() -> u8 {
  *
  ****************************************************************************/
 pub unsafe extern "C" fn coex_schm_curr_phase_get() -> *mut crate::binary::c_types::c_void {
-    todo!("coex_schm_curr_phase_get")
+    trace!("coex_schm_curr_phase_get - not implemented");
+    core::ptr::null_mut()
 }
 
 pub unsafe extern "C" fn coex_schm_curr_phase_idx_set(
     _idx: crate::binary::c_types::c_int,
 ) -> crate::binary::c_types::c_int {
-    todo!("coex_schm_curr_phase_idx_set")
+    trace!("coex_schm_curr_phase_idx_set - not implemented");
+    0
 }
 
 /****************************************************************************
@@ -2228,7 +2379,8 @@ pub unsafe extern "C" fn coex_schm_curr_phase_idx_set(
  *
  ****************************************************************************/
 pub unsafe extern "C" fn coex_schm_curr_phase_idx_get() -> crate::binary::c_types::c_int {
-    todo!("coex_schm_curr_phase_idx_get")
+    trace!("coex_schm_curr_phase_idx_get - not implemented");
+    0
 }
 
 /****************************************************************************
@@ -2268,22 +2420,22 @@ pub unsafe extern "C" fn sprintf(dst: *mut u8, format: *const u8, args: ...) ->
 
 #[no_mangle]
 pub unsafe extern "C" fn printf(s: *const u8, args: ...) {
-    syslog(0, s, args);
+    syslog(BlobLogLevel::Info as u32, s, args);
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn phy_printf(s: *const u8, args: ...) {
-    syslog(0, s, args);
+    syslog(BlobLogLevel::Info as u32, s, args);
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn net80211_printf(s: *const u8, args: ...) {
-    syslog(0, s, args);
+    syslog(BlobLogLevel::Info as u32, s, args);
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn pp_printf(s: *const u8, args: ...) {
-    syslog(0, s, args);
+    syslog(BlobLogLevel::Info as u32, s, args);
 }
 
 // #define ESP_EVENT_DEFINE_BASE(id) esp_event_base_t id = #id
@@ -2294,12 +2446,21 @@ static mut WIFI_EVENT: esp_event_base_t = unsafe { &EVT };
 // stuff needed by wpa-supplicant
 #[no_mangle]
 pub unsafe extern "C" fn __assert_func(
-    _file: *const u8,
-    _line: u32,
-    _func: *const u8,
-    _failed_expr: *const u8,
-) {
-    todo!("__assert_func");
+    file: *const u8,
+    line: u32,
+    func: *const u8,
+    failed_expr: *const u8,
+) -> ! {
+    // noreturn for the callers - the blobs must not run on after a failed assertion
+    log_error(ESP_FAIL);
+    crate::println!(
+        "assertion failed in {} at {}:{}: {}",
+        StrBuf::from(func).as_str_ref(),
+        StrBuf::from(file).as_str_ref(),
+        line,
+        StrBuf::from(failed_expr).as_str_ref()
+    );
+    panic!("assertion failed in the WiFi driver");
 }
 
 #[no_mangle]
@@ -2331,8 +2492,21 @@ pub unsafe extern "C" fn ets_timer_arm(
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn gettimeofday(_tv: *const (), _tz: *const ()) {
-    todo!("gettimeofday");
+pub unsafe extern "C" fn gettimeofday(tv: *mut timeval, _tz: *const ()) -> i32 {
+    trace!("gettimeofday");
+    write_timeval(tv);
+    0
+}
+
+/// Time since boot - there is no wall clock
+unsafe fn write_timeval(tv: *mut timeval) {
+    if tv.is_null() {
+        return;
+    }
+
+    let us = crate::timer::get_systimer_count() / 16;
+    (*tv).tv_sec = (us / 1_000_000) as time_t;
+    (*tv).tv_usec = (us % 1_000_000) as suseconds_t;
 }
 
 #[no_mangle]
@@ -2341,23 +2515,28 @@ pub unsafe extern "C" fn esp_fill_random(dst: *mut u8, len: u32) {
     let dst = core::slice::from_raw_parts_mut(dst, len as usize);
 
     if let Some(ref mut rng) = RANDOM_GENERATOR {
-        rng.read(dst).unwrap();
+        rng.read(dst).ok();
     }
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn esp_timer_stop(_handle: *mut ()) {
-    todo!("esp_timer_stop");
+pub unsafe extern "C" fn esp_timer_stop(handle: *mut ()) {
+    os_backend().timer_disarm(handle as *mut crate::binary::c_types::c_void);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn esp_timer_delete(_handle: *mut ()) {
-    todo!("esp_timer_delete");
+pub unsafe extern "C" fn esp_timer_delete(handle: *mut ()) {
+    os_backend().timer_done(handle as *mut crate::binary::c_types::c_void);
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn esp_timer_start_once(_handle: *mut (), _timeout_us: u64) -> i32 {
-    todo!("esp_timer_start_once");
+pub unsafe extern "C" fn esp_timer_start_once(handle: *mut (), timeout_us: u64) -> i32 {
+    os_backend().timer_arm_us(
+        handle as *mut crate::binary::c_types::c_void,
+        timeout_us.min(u32::MAX as u64) as u32,
+        false,
+    );
+    0
 }
 
 #[no_mangle]
@@ -2369,6 +2548,17 @@ pub unsafe extern "C" fn esp_timer_create(
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn strrchr(_s: *const (), _c: u32) -> *const u8 {
-    todo!("strrchr");
+pub unsafe extern "C" fn strrchr(s: *const u8, c: u32) -> *const u8 {
+    let c = c as u8;
+    let mut last = core::ptr::null();
+    let mut p = s;
+    loop {
+        if *p == c {
+            last = p;
+        }
+        if *p == 0 {
+            return last;
+        }
+        p = p.offset(1);
+    }
 }
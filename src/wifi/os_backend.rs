@@ -0,0 +1,163 @@
+//! The OS primitives the blobs run on
+//!
+//! The OS adapter functions handed to the blobs forward semaphores, mutexes, queues, tasks
+//! and timers to an [OsBackend]. By default that's the crate's own implementation on top
+//! of the `preempt` scheduler. Implement the trait to back them with another RTOS or
+//! executor instead - every method defaults to the built-in implementation, so only the
+//! primitives of interest need to be overridden. Handles only need to be understood by
+//! the backend that created them.
+//!
+//! Install the backend with [set_os_backend] before `wifi_init`. The crate's own tasks
+//! (`init_tasks`) still run on the built-in scheduler.
+
+use crate::{
+    binary::c_types::{c_char, c_void},
+    compat::{
+        common::{
+            create_recursive_mutex, create_wifi_queue, lock_mutex, queued_count, receive_queued,
+            sem_create, sem_delete, sem_give, sem_take, send_queued, thread_sem_get, unlock_mutex,
+        },
+        timer_compat::{
+            compat_timer_arm, compat_timer_arm_us, compat_timer_disarm, compat_timer_done,
+            compat_timer_setfn,
+        },
+        work_queue::queue_work,
+    },
+};
+
+/// Semaphores, mutexes and queues return 1 on success and 0 on failure. Block times are
+/// in the backend's ticks (see [OsBackend::task_ms_to_tick]), `u32::MAX` waits forever.
+pub trait OsBackend: Sync {
+    fn semphr_create(&self, max: u32, init: u32) -> *mut c_void {
+        sem_create(max, init)
+    }
+
+    fn semphr_delete(&self, semphr: *mut c_void) {
+        sem_delete(semphr)
+    }
+
+    fn semphr_take(&self, semphr: *mut c_void, block_time_tick: u32) -> i32 {
+        sem_take(semphr, block_time_tick)
+    }
+
+    fn semphr_give(&self, semphr: *mut c_void) -> i32 {
+        sem_give(semphr)
+    }
+
+    /// A semaphore private to the calling task
+    fn thread_semphr_get(&self) -> *mut c_void {
+        thread_sem_get()
+    }
+
+    fn recursive_mutex_create(&self) -> *mut c_void {
+        create_recursive_mutex()
+    }
+
+    fn mutex_lock(&self, mutex: *mut c_void) -> i32 {
+        lock_mutex(mutex)
+    }
+
+    fn mutex_unlock(&self, mutex: *mut c_void) -> i32 {
+        unlock_mutex(mutex)
+    }
+
+    /// The queue of the WiFi task - the built-in one supports a single queue with items
+    /// of up to 8 bytes
+    fn wifi_queue_create(&self, queue_len: i32, item_size: i32) -> *mut c_void {
+        create_wifi_queue(queue_len, item_size)
+    }
+
+    fn queue_send(&self, queue: *mut c_void, item: *mut c_void, block_time_tick: u32) -> i32 {
+        send_queued(queue, item, block_time_tick)
+    }
+
+    fn queue_recv(&self, queue: *mut c_void, item: *mut c_void, block_time_tick: u32) -> i32 {
+        receive_queued(queue, item, block_time_tick)
+    }
+
+    /// Number of items waiting in the queue
+    fn queue_msg_waiting(&self, queue: *mut c_void) -> u32 {
+        queued_count(queue)
+    }
+
+    /// Starts `task_func(param)` as a task and stores its handle in `task_handle` -
+    /// returns 1 on success
+    ///
+    /// The built-in implementation runs it on the driver task of the `preempt` scheduler.
+    #[allow(clippy::too_many_arguments)]
+    fn task_create(
+        &self,
+        task_func: *mut c_void,
+        name: *const c_char,
+        stack_depth: u32,
+        param: *mut c_void,
+        prio: u32,
+        task_handle: *mut c_void,
+        core_id: u32,
+    ) -> i32 {
+        unsafe { *(task_handle as *mut usize) = crate::preempt::current_task() };
+        queue_work(
+            task_func,
+            name,
+            stack_depth,
+            param,
+            prio,
+            task_handle,
+            core_id,
+        );
+        1
+    }
+
+    fn task_delay(&self, tick: u32) {
+        let timeout = crate::timer::get_systimer_count() + tick as u64;
+        while crate::timer::get_systimer_count() <= timeout {}
+    }
+
+    /// The built-in ticks are the ones of the systimer (16MHz)
+    fn task_ms_to_tick(&self, ms: u32) -> i32 {
+        (ms * 16_000) as i32
+    }
+
+    fn current_task(&self) -> *mut c_void {
+        crate::preempt::current_task() as *mut c_void
+    }
+
+    /// `ptimer` points to an `ets_timer` set up via [OsBackend::timer_setfn]
+    fn timer_setfn(&self, ptimer: *mut c_void, pfunction: *mut c_void, parg: *mut c_void) {
+        compat_timer_setfn(ptimer, pfunction, parg)
+    }
+
+    fn timer_arm(&self, ptimer: *mut c_void, tmout_ms: u32, repeat: bool) {
+        compat_timer_arm(ptimer, tmout_ms, repeat)
+    }
+
+    fn timer_arm_us(&self, ptimer: *mut c_void, us: u32, repeat: bool) {
+        compat_timer_arm_us(ptimer, us, repeat)
+    }
+
+    fn timer_disarm(&self, ptimer: *mut c_void) {
+        compat_timer_disarm(ptimer)
+    }
+
+    fn timer_done(&self, ptimer: *mut c_void) {
+        compat_timer_done(ptimer)
+    }
+}
+
+/// The crate's own implementation
+pub struct BuiltinOsBackend;
+
+impl OsBackend for BuiltinOsBackend {}
+
+static mut OS_BACKEND: &'static dyn OsBackend = &BuiltinOsBackend;
+
+/// Replaces the OS primitives used by the blobs - has to be called before `wifi_init`
+pub fn set_os_backend(backend: &'static dyn OsBackend) {
+    unsafe {
+        OS_BACKEND = backend;
+    }
+}
+
+pub(crate) fn os_backend() -> &'static dyn OsBackend {
+    unsafe { OS_BACKEND }
+}
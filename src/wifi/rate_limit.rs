@@ -0,0 +1,69 @@
+//! Throughput limit per SoftAP station
+//!
+//! With a [RateLimit] given to `set_station_rate_limit` every station associated with the
+//! SoftAP gets a budget of its own. Unicast frames to a station which used its budget up
+//! are dropped before they reach the driver - TCP backs off as on a congested link - so a
+//! single greedy client can't take all of the airtime from the others.
+
+/// `bytes_per_s` on average, bursts up to `burst_bytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RateLimit {
+    bytes_per_s: u32,
+    burst_bytes: u32,
+}
+
+impl RateLimit {
+    /// `burst_bytes` should hold at least one full frame (1514 bytes) or nothing gets
+    /// through
+    pub const fn new(bytes_per_s: u32, burst_bytes: u32) -> RateLimit {
+        RateLimit {
+            bytes_per_s,
+            burst_bytes,
+        }
+    }
+
+    pub fn bytes_per_s(&self) -> u32 {
+        self.bytes_per_s
+    }
+
+    pub fn burst_bytes(&self) -> u32 {
+        self.burst_bytes
+    }
+}
+
+/// The budget of one station - in millionths of a byte so short intervals still add up
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TokenBucket {
+    tokens: u64,
+    last_update_us: u64,
+}
+
+impl TokenBucket {
+    /// Starts with a full budget
+    pub(crate) fn new(limit: &RateLimit, now_us: u64) -> TokenBucket {
+        TokenBucket {
+            tokens: limit.burst_bytes as u64 * 1_000_000,
+            last_update_us: now_us,
+        }
+    }
+
+    /// Takes `len` bytes from the budget - `false` if not enough is left, which leaves the
+    /// budget unchanged
+    pub(crate) fn try_take_at(&mut self, limit: &RateLimit, now_us: u64, len: usize) -> bool {
+        let elapsed_us = now_us.saturating_sub(self.last_update_us);
+        self.tokens = self
+            .tokens
+            .saturating_add(elapsed_us.saturating_mul(limit.bytes_per_s as u64))
+            .min(limit.burst_bytes as u64 * 1_000_000);
+        self.last_update_us = now_us;
+
+        let needed = len as u64 * 1_000_000;
+        if needed > self.tokens {
+            return false;
+        }
+
+        self.tokens -= needed;
+        true
+    }
+}
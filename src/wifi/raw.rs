@@ -0,0 +1,43 @@
+//! Driver calls the safe API doesn't wrap yet
+//!
+//! The plain bindings of the `esp_wifi_*` configuration calls, so a missing knob doesn't
+//! need a fork of the crate. All of them are `unsafe` and the crate doesn't know about
+//! changes made through them - e.g. [esp_wifi_set_channel] isn't reflected by
+//! `wifi_configure_ap`. The types and constants they take are in
+//! [crate::binary::include].
+//!
+//! Calls the crate's own state depends on (init, start/stop, mode, station and SoftAP
+//! config, connect, power saving, country, MAC) are deliberately left out - use the safe
+//! functions for them.
+//!
+//! ```ignore
+//! unsafe { raw::check(raw::esp_wifi_set_inactive_time(raw::WIFI_IF_AP, 30))? };
+//! ```
+
+use super::{error::esp_result, WifiError};
+use crate::binary::include::{esp_err_t, wifi_interface_t};
+
+pub use crate::binary::include::{
+    esp_wifi_80211_tx, esp_wifi_ap_get_sta_aid, esp_wifi_clear_fast_connect,
+    esp_wifi_config_11b_rate, esp_wifi_config_espnow_rate, esp_wifi_ftm_initiate_session,
+    esp_wifi_get_ant, esp_wifi_get_ant_gpio, esp_wifi_get_bandwidth, esp_wifi_get_channel,
+    esp_wifi_get_config, esp_wifi_get_event_mask, esp_wifi_get_inactive_time,
+    esp_wifi_get_max_tx_power, esp_wifi_get_promiscuous, esp_wifi_get_promiscuous_ctrl_filter,
+    esp_wifi_get_promiscuous_filter, esp_wifi_get_protocol, esp_wifi_get_tsf_time,
+    esp_wifi_internal_get_negotiated_bandwidth, esp_wifi_internal_get_negotiated_channel,
+    esp_wifi_internal_set_fix_rate, esp_wifi_scan_stop, esp_wifi_set_ant, esp_wifi_set_ant_gpio,
+    esp_wifi_set_bandwidth, esp_wifi_set_channel, esp_wifi_set_connectionless_wake_interval,
+    esp_wifi_set_csi, esp_wifi_set_csi_config, esp_wifi_set_csi_rx_cb, esp_wifi_set_event_mask,
+    esp_wifi_set_inactive_time, esp_wifi_set_max_tx_power, esp_wifi_set_promiscuous,
+    esp_wifi_set_promiscuous_ctrl_filter, esp_wifi_set_promiscuous_filter,
+    esp_wifi_set_promiscuous_rx_cb, esp_wifi_set_protocol, esp_wifi_set_rssi_threshold,
+    esp_wifi_set_sleep_delay_time, esp_wifi_set_vendor_ie, esp_wifi_set_vendor_ie_cb,
+};
+
+pub const WIFI_IF_STA: wifi_interface_t = crate::binary::include::wifi_interface_t_WIFI_IF_STA;
+pub const WIFI_IF_AP: wifi_interface_t = crate::binary::include::wifi_interface_t_WIFI_IF_AP;
+
+/// Turns the result of a call into a [WifiError]
+pub fn check(result: esp_err_t) -> Result<(), WifiError> {
+    esp_result(result)
+}
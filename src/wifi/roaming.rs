@@ -0,0 +1,80 @@
+//! When to look for a better access point
+//!
+//! The driver never roams on its own. With [RoamThresholds] given to `set_roam_thresholds`
+//! it flags a [RoamTrigger] once the signal of the access point or the share of frames it
+//! doesn't acknowledge crosses a threshold - `take_roam_trigger` picks it up, e.g. to scan
+//! for a better access point and reconnect. A vehicle moving between access points wants
+//! thresholds which trigger early, a sensor which never moves wants [RoamThresholds::NEVER]
+//! to stick to its access point.
+
+/// `None` disables a threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RoamThresholds {
+    /// Signal of the connected access point below which to roam, -100 to 0 dBm
+    pub rssi_dbm: Option<i8>,
+    /// Share of frames to the access point in percent which failed despite all retries
+    pub tx_failure_percent: Option<u8>,
+    /// Number of sent frames the failure share is taken over
+    pub tx_window_frames: u16,
+}
+
+impl RoamThresholds {
+    /// Never suggests roaming
+    pub const NEVER: RoamThresholds = RoamThresholds {
+        rssi_dbm: None,
+        tx_failure_percent: None,
+        tx_window_frames: 32,
+    };
+}
+
+impl Default for RoamThresholds {
+    fn default() -> RoamThresholds {
+        RoamThresholds::NEVER
+    }
+}
+
+/// Which threshold got crossed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RoamTrigger {
+    /// The signal of the access point dropped below [RoamThresholds::rssi_dbm]
+    RssiLow { rssi: i8 },
+    /// More than [RoamThresholds::tx_failure_percent] of the last window of frames failed
+    TxFailures { percent: u8 },
+}
+
+/// Failed frames of the current window
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TxFailureWindow {
+    sent: u16,
+    failed: u16,
+}
+
+impl TxFailureWindow {
+    pub(crate) const fn new() -> TxFailureWindow {
+        TxFailureWindow { sent: 0, failed: 0 }
+    }
+
+    /// Counts a sent frame - the failure share if it completes a window above the
+    /// threshold
+    pub(crate) fn count(&mut self, thresholds: &RoamThresholds, success: bool) -> Option<u8> {
+        let max_percent = thresholds.tx_failure_percent?;
+
+        self.sent += 1;
+        if !success {
+            self.failed += 1;
+        }
+        if self.sent < thresholds.tx_window_frames.max(1) {
+            return None;
+        }
+
+        let percent = (self.failed as u32 * 100 / self.sent as u32) as u8;
+        *self = TxFailureWindow::new();
+        if percent > max_percent {
+            Some(percent)
+        } else {
+            None
+        }
+    }
+}
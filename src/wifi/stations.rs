@@ -0,0 +1,169 @@
+//! Link information of the stations associated with the SoftAP
+
+use crate::{
+    binary::include::{esp_wifi_ap_get_sta_list, wifi_sta_list_t, ESP_WIFI_MAX_CONN_NUM},
+    timer::get_systimer_count,
+};
+
+use super::{
+    error::esp_result,
+    rate_limit::{RateLimit, TokenBucket},
+    types::StationTraffic,
+    PhyModes, StationInfo, WifiError,
+};
+
+const MAX_STATIONS: usize = ESP_WIFI_MAX_CONN_NUM as usize;
+
+#[derive(Clone, Copy)]
+struct Station {
+    mac: [u8; 6],
+    /// Time (ms) of the last frame received
+    last_activity: u64,
+    traffic: StationTraffic,
+    /// Filled up when a [RateLimit] is set
+    budget: Option<TokenBucket>,
+}
+
+/// The stations associated with the SoftAP
+static mut STATIONS: [Option<Station>; MAX_STATIONS] = [None; MAX_STATIONS];
+
+static mut RATE_LIMIT: Option<RateLimit> = None;
+
+fn now_ms() -> u64 {
+    get_systimer_count() / 16_000
+}
+
+/// Limits the throughput to each station associated with the SoftAP - `None` removes the
+/// limit
+pub fn set_station_rate_limit(limit: Option<RateLimit>) {
+    critical_section::with(|_| unsafe {
+        RATE_LIMIT = limit;
+        for station in STATIONS.iter_mut().flatten() {
+            station.budget = None;
+        }
+    });
+}
+
+pub fn station_rate_limit() -> Option<RateLimit> {
+    unsafe { RATE_LIMIT }
+}
+
+/// Called before a unicast frame to `mac` is queued on the SoftAP interface - `false` if
+/// it has to be dropped because of the rate limit
+pub(crate) fn station_may_send(mac: &[u8; 6], len: usize) -> bool {
+    let now_us = get_systimer_count() / 16;
+    critical_section::with(|_| unsafe {
+        let limit = match RATE_LIMIT {
+            Some(ref limit) => limit,
+            None => return true,
+        };
+
+        for station in STATIONS.iter_mut().flatten() {
+            if station.mac == *mac {
+                let budget = station
+                    .budget
+                    .get_or_insert_with(|| TokenBucket::new(limit, now_us));
+                if budget.try_take_at(limit, now_us, len) {
+                    return true;
+                }
+
+                station.traffic.tx_rate_limited = station.traffic.tx_rate_limited.wrapping_add(1);
+                return false;
+            }
+        }
+
+        true
+    })
+}
+
+/// Called from the event handler
+pub(crate) fn station_associated(mac: &[u8; 6]) {
+    let now = now_ms();
+    critical_section::with(|_| unsafe {
+        let index = STATIONS
+            .iter()
+            .position(|entry| matches!(entry, Some(station) if station.mac == *mac))
+            .or_else(|| STATIONS.iter().position(|entry| entry.is_none()));
+
+        if let Some(index) = index {
+            STATIONS[index] = Some(Station {
+                mac: *mac,
+                last_activity: now,
+                traffic: StationTraffic::default(),
+                budget: None,
+            });
+        }
+    });
+}
+
+/// Called from the event handler
+pub(crate) fn station_left(mac: &[u8; 6]) {
+    critical_section::with(|_| unsafe {
+        for entry in STATIONS.iter_mut() {
+            if matches!(entry, Some(station) if station.mac == *mac) {
+                *entry = None;
+            }
+        }
+    });
+}
+
+/// Called for every frame received on the SoftAP interface
+pub(crate) fn station_received(mac: &[u8; 6], len: usize) {
+    let now = now_ms();
+    critical_section::with(|_| unsafe {
+        for station in STATIONS.iter_mut().flatten() {
+            if station.mac == *mac {
+                station.last_activity = now;
+                station.traffic.rx_frames = station.traffic.rx_frames.wrapping_add(1);
+                station.traffic.rx_bytes = station.traffic.rx_bytes.wrapping_add(len as u64);
+            }
+        }
+    });
+}
+
+/// Called for every frame the driver accepted for sending on the SoftAP interface
+pub(crate) fn station_sent(mac: &[u8; 6], len: usize) {
+    critical_section::with(|_| unsafe {
+        for station in STATIONS.iter_mut().flatten() {
+            if station.mac == *mac {
+                station.traffic.tx_frames = station.traffic.tx_frames.wrapping_add(1);
+                station.traffic.tx_bytes = station.traffic.tx_bytes.wrapping_add(len as u64);
+            }
+        }
+    });
+}
+
+/// The stations currently associated with the SoftAP
+pub fn ap_stations() -> Result<heapless::Vec<StationInfo, MAX_STATIONS>, WifiError> {
+    let mut list: wifi_sta_list_t = unsafe { core::mem::zeroed() };
+    unsafe { esp_result(esp_wifi_ap_get_sta_list(&mut list))? };
+
+    let now = now_ms();
+    let mut result = heapless::Vec::new();
+    for sta in list.sta.iter().take(list.num as usize) {
+        let station = critical_section::with(|_| unsafe {
+            STATIONS
+                .iter()
+                .flatten()
+                .find(|station| station.mac == sta.mac)
+                .copied()
+        });
+
+        result
+            .push(StationInfo {
+                mac: sta.mac,
+                rssi: sta.rssi,
+                phy: PhyModes {
+                    phy_11b: sta.phy_11b() != 0,
+                    phy_11g: sta.phy_11g() != 0,
+                    phy_11n: sta.phy_11n() != 0,
+                    long_range: sta.phy_lr() != 0,
+                },
+                idle_ms: now.saturating_sub(station.map_or(now, |station| station.last_activity)),
+                traffic: station.map_or(StationTraffic::default(), |station| station.traffic),
+            })
+            .ok();
+    }
+
+    Ok(result)
+}
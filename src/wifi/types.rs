@@ -0,0 +1,713 @@
+#![allow(non_upper_case_globals)]
+
+use crate::binary::include::*;
+
+/// Authentication method of an access point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AuthMethod {
+    None,
+    WEP,
+    WPA,
+    WPA2Personal,
+    WPAWPA2Personal,
+    WPA2Enterprise,
+    WPA3Personal,
+    WPA2WPA3Personal,
+    WAPIPersonal,
+}
+
+impl AuthMethod {
+    pub fn from_raw(authmode: wifi_auth_mode_t) -> AuthMethod {
+        match authmode {
+            wifi_auth_mode_t_WIFI_AUTH_WEP => AuthMethod::WEP,
+            wifi_auth_mode_t_WIFI_AUTH_WPA_PSK => AuthMethod::WPA,
+            wifi_auth_mode_t_WIFI_AUTH_WPA2_PSK => AuthMethod::WPA2Personal,
+            wifi_auth_mode_t_WIFI_AUTH_WPA_WPA2_PSK => AuthMethod::WPAWPA2Personal,
+            wifi_auth_mode_t_WIFI_AUTH_WPA2_ENTERPRISE => AuthMethod::WPA2Enterprise,
+            wifi_auth_mode_t_WIFI_AUTH_WPA3_PSK => AuthMethod::WPA3Personal,
+            wifi_auth_mode_t_WIFI_AUTH_WPA2_WPA3_PSK => AuthMethod::WPA2WPA3Personal,
+            wifi_auth_mode_t_WIFI_AUTH_WAPI_PSK => AuthMethod::WAPIPersonal,
+            _ => AuthMethod::None,
+        }
+    }
+
+    pub fn to_raw(self) -> wifi_auth_mode_t {
+        match self {
+            AuthMethod::None => wifi_auth_mode_t_WIFI_AUTH_OPEN,
+            AuthMethod::WEP => wifi_auth_mode_t_WIFI_AUTH_WEP,
+            AuthMethod::WPA => wifi_auth_mode_t_WIFI_AUTH_WPA_PSK,
+            AuthMethod::WPA2Personal => wifi_auth_mode_t_WIFI_AUTH_WPA2_PSK,
+            AuthMethod::WPAWPA2Personal => wifi_auth_mode_t_WIFI_AUTH_WPA_WPA2_PSK,
+            AuthMethod::WPA2Enterprise => wifi_auth_mode_t_WIFI_AUTH_WPA2_ENTERPRISE,
+            AuthMethod::WPA3Personal => wifi_auth_mode_t_WIFI_AUTH_WPA3_PSK,
+            AuthMethod::WPA2WPA3Personal => wifi_auth_mode_t_WIFI_AUTH_WPA2_WPA3_PSK,
+            AuthMethod::WAPIPersonal => wifi_auth_mode_t_WIFI_AUTH_WAPI_PSK,
+        }
+    }
+
+    /// Whether the SoftAP of the linked blobs can use it
+    ///
+    /// Their hostapd has no SAE, so WPA3 and the WPA2/WPA3 transition mode only work as
+    /// a station.
+    pub fn supported_by_ap(self) -> bool {
+        matches!(
+            self,
+            AuthMethod::None
+                | AuthMethod::WPA
+                | AuthMethod::WPA2Personal
+                | AuthMethod::WPAWPA2Personal
+        )
+    }
+}
+
+/// An access point found by a scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccessPointInfo {
+    pub ssid: heapless::String<32>,
+    pub bssid: [u8; 6],
+    pub channel: u8,
+    pub signal_strength: i8,
+    pub auth_method: AuthMethod,
+}
+
+impl AccessPointInfo {
+    pub fn from_record(record: &wifi_ap_record_t) -> AccessPointInfo {
+        let len = record.ssid[..32].iter().position(|&c| c == 0).unwrap_or(32);
+
+        // SSIDs are not guaranteed to be UTF-8 - keep the valid part
+        let ssid = match core::str::from_utf8(&record.ssid[..len]) {
+            Ok(ssid) => ssid,
+            Err(e) => core::str::from_utf8(&record.ssid[..e.valid_up_to()]).unwrap_or_default(),
+        };
+
+        let mut ssid_string = heapless::String::new();
+        ssid_string.push_str(ssid).ok();
+
+        AccessPointInfo {
+            ssid: ssid_string,
+            bssid: record.bssid,
+            channel: record.primary,
+            signal_strength: record.rssi,
+            auth_method: AuthMethod::from_raw(record.authmode),
+        }
+    }
+}
+
+/// Modem sleep of the station
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerSaveMode {
+    /// The radio is always on
+    None,
+    /// The radio sleeps between DTIM beacons
+    MinModem,
+    /// The radio sleeps for the listen interval - saves more but adds latency
+    MaxModem,
+}
+
+impl PowerSaveMode {
+    pub fn to_raw(self) -> wifi_ps_type_t {
+        match self {
+            PowerSaveMode::None => wifi_ps_type_t_WIFI_PS_NONE,
+            PowerSaveMode::MinModem => wifi_ps_type_t_WIFI_PS_MIN_MODEM,
+            PowerSaveMode::MaxModem => wifi_ps_type_t_WIFI_PS_MAX_MODEM,
+        }
+    }
+}
+
+/// Where the regulatory settings come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CountryPolicy {
+    /// Use the country advertised by the connected access point (802.11d) and fall back
+    /// to the configured one while not connected
+    Auto,
+    /// Always use the configured country
+    Manual,
+}
+
+/// Regulatory settings - limit the usable channels and the TX power
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CountryInfo {
+    /// Two letter country code
+    pub code: [u8; 2],
+    pub first_channel: u8,
+    pub channel_count: u8,
+    /// In dBm
+    pub max_tx_power: i8,
+    pub policy: CountryPolicy,
+}
+
+impl CountryInfo {
+    pub fn from_raw(country: &wifi_country_t) -> CountryInfo {
+        CountryInfo {
+            code: [country.cc[0], country.cc[1]],
+            first_channel: country.schan,
+            channel_count: country.nchan,
+            max_tx_power: country.max_tx_power,
+            policy: if country.policy == wifi_country_policy_t_WIFI_COUNTRY_POLICY_AUTO {
+                CountryPolicy::Auto
+            } else {
+                CountryPolicy::Manual
+            },
+        }
+    }
+
+    pub fn to_raw(&self) -> wifi_country_t {
+        wifi_country_t {
+            cc: [self.code[0], self.code[1], 0],
+            schan: self.first_channel,
+            nchan: self.channel_count,
+            max_tx_power: self.max_tx_power,
+            policy: match self.policy {
+                CountryPolicy::Auto => wifi_country_policy_t_WIFI_COUNTRY_POLICY_AUTO,
+                CountryPolicy::Manual => wifi_country_policy_t_WIFI_COUNTRY_POLICY_MANUAL,
+            },
+        }
+    }
+}
+
+impl Default for CountryInfo {
+    /// `ESP32C3_WIFI_COUNTRY` and `ESP32C3_WIFI_COUNTRY_POLICY` with channels 1 - 13
+    fn default() -> Self {
+        CountryInfo {
+            code: crate::config::COUNTRY,
+            first_channel: 1,
+            channel_count: 13,
+            max_tx_power: 20,
+            policy: if crate::config::COUNTRY_POLICY_AUTO {
+                CountryPolicy::Auto
+            } else {
+                CountryPolicy::Manual
+            },
+        }
+    }
+}
+
+/// Operating mode of the driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WifiMode {
+    Sta,
+    Ap,
+    /// Station and SoftAP at the same time - both share the channel of the station
+    ApSta,
+}
+
+impl WifiMode {
+    pub fn to_raw(self) -> wifi_mode_t {
+        match self {
+            WifiMode::Sta => wifi_mode_t_WIFI_MODE_STA,
+            WifiMode::Ap => wifi_mode_t_WIFI_MODE_AP,
+            WifiMode::ApSta => wifi_mode_t_WIFI_MODE_APSTA,
+        }
+    }
+
+    pub fn is_sta(self) -> bool {
+        matches!(self, WifiMode::Sta | WifiMode::ApSta)
+    }
+
+    pub fn is_ap(self) -> bool {
+        matches!(self, WifiMode::Ap | WifiMode::ApSta)
+    }
+}
+
+/// The interface a `WifiDevice` sends and receives frames on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WifiDeviceMode {
+    Sta,
+    Ap,
+}
+
+/// Channel of the SoftAP
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ApChannel {
+    /// 1 - 13
+    Fixed(u8),
+    /// Scan and use the least occupied channel - the driver has to be started
+    Auto,
+}
+
+/// Picks the channel least disturbed by the access points counted per channel in
+/// `occupied`
+///
+/// Access points on overlapping channels (up to 4 apart) count less the farther away
+/// they are. Ties go to the lower channel.
+#[cfg(not(feature = "mock"))]
+pub(crate) fn least_congested_channel(occupied: &[u16; 14]) -> u8 {
+    (1..=13u8)
+        .min_by_key(|&channel| {
+            (1..=13u8)
+                .map(|other| {
+                    let distance = (channel as i32 - other as i32).unsigned_abs();
+                    if distance < 5 {
+                        occupied[other as usize] as u32 * (5 - distance)
+                    } else {
+                        0
+                    }
+                })
+                .sum::<u32>()
+        })
+        .unwrap_or(1)
+}
+
+/// The PMK as the 64 hex digits the driver takes instead of a passphrase
+#[cfg(not(feature = "mock"))]
+pub(crate) fn pmk_hex(pmk: &[u8; 32]) -> [u8; 64] {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = [0u8; 64];
+    for (i, byte) in pmk.iter().enumerate() {
+        hex[i * 2] = DIGITS[(byte >> 4) as usize];
+        hex[i * 2 + 1] = DIGITS[(byte & 0xf) as usize];
+    }
+    hex
+}
+
+/// Configuration of the SoftAP
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AccessPointConfig {
+    pub ssid: heapless::String<32>,
+    /// At least 8 characters unless `auth_method` is `AuthMethod::None`
+    pub password: heapless::String<64>,
+    /// Ignored in AP+STA mode while the station is connected - the AP follows its channel
+    pub channel: ApChannel,
+    /// See [AuthMethod::supported_by_ap]
+    pub auth_method: AuthMethod,
+    /// Don't broadcast the SSID
+    pub hidden: bool,
+    pub max_connections: u8,
+}
+
+impl Default for AccessPointConfig {
+    fn default() -> Self {
+        let mut ssid = heapless::String::new();
+        ssid.push_str("esp32c3").ok();
+
+        AccessPointConfig {
+            ssid,
+            password: heapless::String::new(),
+            channel: ApChannel::Fixed(1),
+            auth_method: AuthMethod::None,
+            hidden: false,
+            max_connections: crate::config::MAX_CONNECTIONS as u8,
+        }
+    }
+}
+
+/// PHY modes of a peer - a station associated with the SoftAP or the access point the
+/// station is connected to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhyModes {
+    pub phy_11b: bool,
+    pub phy_11g: bool,
+    pub phy_11n: bool,
+    /// Espressif's long range mode
+    pub long_range: bool,
+}
+
+/// Cipher suite protecting unicast or group addressed frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Cipher {
+    None,
+    Wep40,
+    Wep104,
+    Tkip,
+    Ccmp,
+    TkipCcmp,
+    AesCmac128,
+    Sms4,
+    Unknown,
+}
+
+impl Cipher {
+    pub fn from_raw(cipher: wifi_cipher_type_t) -> Cipher {
+        match cipher {
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_NONE => Cipher::None,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_WEP40 => Cipher::Wep40,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_WEP104 => Cipher::Wep104,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_TKIP => Cipher::Tkip,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_CCMP => Cipher::Ccmp,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_TKIP_CCMP => Cipher::TkipCcmp,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_AES_CMAC128 => Cipher::AesCmac128,
+            wifi_cipher_type_t_WIFI_CIPHER_TYPE_SMS4 => Cipher::Sms4,
+            _ => Cipher::Unknown,
+        }
+    }
+}
+
+/// What the station negotiated with the access point it is connected to
+///
+/// The linked blobs don't report the association ID or whether QoS (WMM) is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AssociationInfo {
+    pub bssid: [u8; 6],
+    pub channel: u8,
+    /// A secondary channel is used (HT40)
+    pub ht40: bool,
+    pub phy: PhyModes,
+    pub auth_method: AuthMethod,
+    pub pairwise_cipher: Cipher,
+    pub group_cipher: Cipher,
+    /// Management frames are protected (802.11w)
+    pub pmf: bool,
+}
+
+impl AssociationInfo {
+    pub fn from_record(record: &wifi_ap_record_t, pmf: bool) -> AssociationInfo {
+        AssociationInfo {
+            bssid: record.bssid,
+            channel: record.primary,
+            ht40: record.second != wifi_second_chan_t_WIFI_SECOND_CHAN_NONE,
+            phy: PhyModes {
+                phy_11b: record.phy_11b() != 0,
+                phy_11g: record.phy_11g() != 0,
+                phy_11n: record.phy_11n() != 0,
+                long_range: record.phy_lr() != 0,
+            },
+            auth_method: AuthMethod::from_raw(record.authmode),
+            pairwise_cipher: Cipher::from_raw(record.pairwise_cipher),
+            group_cipher: Cipher::from_raw(record.group_cipher),
+            pmf,
+        }
+    }
+}
+
+/// A station associated with the SoftAP
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StationInfo {
+    pub mac: [u8; 6],
+    /// Average signal strength of the frames received from the station
+    pub rssi: i8,
+    pub phy: PhyModes,
+    /// Time since the last frame was received from the station (or since it associated)
+    pub idle_ms: u64,
+    pub traffic: StationTraffic,
+}
+
+/// Data frames exchanged with a station since it associated
+///
+/// Counted at the driver interface: received frames the MAC filter let through, unicast
+/// frames the driver accepted for sending. Broadcasts and multicasts aren't attributed to
+/// a station.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StationTraffic {
+    pub rx_frames: u32,
+    pub rx_bytes: u64,
+    pub tx_frames: u32,
+    pub tx_bytes: u64,
+    /// Frames dropped because the station exceeded `set_station_rate_limit`
+    pub tx_rate_limited: u32,
+}
+
+/// Events posted by the driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WifiEvent {
+    WifiReady,
+    ScanDone,
+    StaStart,
+    StaStop,
+    StaConnected,
+    /// `reason` is the `wifi_err_reason_t`, see [super::WifiError::from_disconnect_reason]
+    StaDisconnected {
+        reason: u8,
+        bssid: [u8; 6],
+    },
+    StaAuthmodeChange,
+    ApStart,
+    ApStop,
+    /// A station associated with the SoftAP
+    ApStaConnected {
+        mac: [u8; 6],
+        aid: u8,
+    },
+    ApStaDisconnected {
+        mac: [u8; 6],
+        aid: u8,
+    },
+    ApProbeRequestReceived,
+    StaBssRssiLow,
+    /// No beacon arrived within the beacon timeout (see `set_beacon_timeout`) - the driver
+    /// probes the access point and disconnects if it doesn't answer
+    StaBeaconTimeout,
+    /// An event not known to this crate - contains the `wifi_event_t`
+    Other(i32),
+}
+
+impl WifiEvent {
+    /// Builds the event from what the driver posted
+    ///
+    /// # Safety
+    /// `event_data` has to be null or point to the argument structure of the event. With
+    /// null the payload is zeroed.
+    pub unsafe fn from_raw(
+        event_id: i32,
+        event_data: *const crate::binary::c_types::c_void,
+    ) -> WifiEvent {
+        unsafe fn arg<T: Copy>(event_data: *const crate::binary::c_types::c_void) -> T {
+            if event_data.is_null() {
+                core::mem::zeroed()
+            } else {
+                *(event_data as *const T)
+            }
+        }
+
+        match event_id as wifi_event_t {
+            wifi_event_t_WIFI_EVENT_WIFI_READY => WifiEvent::WifiReady,
+            wifi_event_t_WIFI_EVENT_SCAN_DONE => WifiEvent::ScanDone,
+            wifi_event_t_WIFI_EVENT_STA_START => WifiEvent::StaStart,
+            wifi_event_t_WIFI_EVENT_STA_STOP => WifiEvent::StaStop,
+            wifi_event_t_WIFI_EVENT_STA_CONNECTED => WifiEvent::StaConnected,
+            wifi_event_t_WIFI_EVENT_STA_DISCONNECTED => {
+                let event: wifi_event_sta_disconnected_t = arg(event_data);
+                WifiEvent::StaDisconnected {
+                    reason: event.reason,
+                    bssid: event.bssid,
+                }
+            }
+            wifi_event_t_WIFI_EVENT_STA_AUTHMODE_CHANGE => WifiEvent::StaAuthmodeChange,
+            wifi_event_t_WIFI_EVENT_AP_START => WifiEvent::ApStart,
+            wifi_event_t_WIFI_EVENT_AP_STOP => WifiEvent::ApStop,
+            wifi_event_t_WIFI_EVENT_AP_STACONNECTED => {
+                let event: wifi_event_ap_staconnected_t = arg(event_data);
+                WifiEvent::ApStaConnected {
+                    mac: event.mac,
+                    aid: event.aid,
+                }
+            }
+            wifi_event_t_WIFI_EVENT_AP_STADISCONNECTED => {
+                let event: wifi_event_ap_stadisconnected_t = arg(event_data);
+                WifiEvent::ApStaDisconnected {
+                    mac: event.mac,
+                    aid: event.aid,
+                }
+            }
+            wifi_event_t_WIFI_EVENT_AP_PROBEREQRECVED => WifiEvent::ApProbeRequestReceived,
+            wifi_event_t_WIFI_EVENT_STA_BSS_RSSI_LOW => WifiEvent::StaBssRssiLow,
+            wifi_event_t_WIFI_EVENT_STA_BEACON_TIMEOUT => WifiEvent::StaBeaconTimeout,
+            _ => WifiEvent::Other(event_id),
+        }
+    }
+}
+
+/// Something security relevant happened to the station's connection
+///
+/// Reported via [super::DriverHooks::on_security_event]. The supplicant inside the blob
+/// renews the group key on its own without telling, so only a failed rekey is seen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SecurityEvent {
+    /// A Michael MIC failure (TKIP) - two within a minute make the AP block all stations
+    MicFailure,
+    /// The 4-way handshake didn't complete
+    HandshakeTimeout,
+    /// Renewing the group key (GTK rekey) failed
+    GroupKeyUpdateTimeout,
+    /// The RSN element in the 4-way handshake differs from the one advertised - a possible
+    /// downgrade attack
+    RsnMismatch,
+    /// The cipher, key management or RSN capabilities were rejected
+    CipherRejected,
+    /// 802.1X authentication failed
+    EapFailed,
+    /// The PMKID sent when reassociating was invalid
+    InvalidPmkid,
+    /// The access point changed its authentication method while connected
+    AuthmodeChanged { old: AuthMethod, new: AuthMethod },
+}
+
+impl SecurityEvent {
+    /// The event behind a disconnect with the given reason (`wifi_err_reason_t`), if any
+    pub fn from_disconnect_reason(reason: u8) -> Option<SecurityEvent> {
+        match reason as wifi_err_reason_t {
+            wifi_err_reason_t_WIFI_REASON_MIC_FAILURE => Some(SecurityEvent::MicFailure),
+            wifi_err_reason_t_WIFI_REASON_4WAY_HANDSHAKE_TIMEOUT
+            | wifi_err_reason_t_WIFI_REASON_HANDSHAKE_TIMEOUT => {
+                Some(SecurityEvent::HandshakeTimeout)
+            }
+            wifi_err_reason_t_WIFI_REASON_GROUP_KEY_UPDATE_TIMEOUT => {
+                Some(SecurityEvent::GroupKeyUpdateTimeout)
+            }
+            wifi_err_reason_t_WIFI_REASON_IE_IN_4WAY_DIFFERS => Some(SecurityEvent::RsnMismatch),
+            wifi_err_reason_t_WIFI_REASON_GROUP_CIPHER_INVALID
+            | wifi_err_reason_t_WIFI_REASON_PAIRWISE_CIPHER_INVALID
+            | wifi_err_reason_t_WIFI_REASON_AKMP_INVALID
+            | wifi_err_reason_t_WIFI_REASON_UNSUPP_RSN_IE_VERSION
+            | wifi_err_reason_t_WIFI_REASON_INVALID_RSN_IE_CAP
+            | wifi_err_reason_t_WIFI_REASON_CIPHER_SUITE_REJECTED => {
+                Some(SecurityEvent::CipherRejected)
+            }
+            wifi_err_reason_t_WIFI_REASON_802_1X_AUTH_FAILED => Some(SecurityEvent::EapFailed),
+            wifi_err_reason_t_WIFI_REASON_INVALID_PMKID => Some(SecurityEvent::InvalidPmkid),
+            _ => None,
+        }
+    }
+}
+
+/// Something the driver can do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Capability {
+    /// Station mode
+    Client,
+    /// SoftAP mode
+    AccessPoint,
+    /// Station and SoftAP at the same time
+    Mixed,
+    /// WPA2-Enterprise (802.1X) authentication
+    Enterprise,
+    /// WPA3-SAE authentication
+    WPA3,
+}
+
+impl Capability {
+    const ALL: [Capability; 5] = [
+        Capability::Client,
+        Capability::AccessPoint,
+        Capability::Mixed,
+        Capability::Enterprise,
+        Capability::WPA3,
+    ];
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// The `g_wifi_feature_caps` bit enabling WPA3-SAE in the driver
+pub(crate) const CONFIG_FEATURE_WPA3_SAE_BIT: u64 = 1 << 0;
+
+/// A set of [Capability]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Capabilities(u8);
+
+impl Capabilities {
+    pub fn empty() -> Capabilities {
+        Capabilities(0)
+    }
+
+    /// What the driver supports with the given feature caps
+    pub(crate) fn from_feature_caps(feature_caps: u64) -> Capabilities {
+        let capabilities = Capabilities::empty()
+            .with(Capability::Client)
+            .with(Capability::AccessPoint)
+            .with(Capability::Mixed);
+
+        if feature_caps & CONFIG_FEATURE_WPA3_SAE_BIT != 0 {
+            capabilities.with(Capability::WPA3)
+        } else {
+            capabilities
+        }
+    }
+
+    pub fn with(self, capability: Capability) -> Capabilities {
+        Capabilities(self.0 | capability.bit())
+    }
+
+    pub fn contains(&self, capability: Capability) -> bool {
+        self.0 & capability.bit() != 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        Capability::ALL
+            .iter()
+            .copied()
+            .filter(move |capability| self.contains(*capability))
+    }
+}
+
+/// What kind of frame promiscuous mode captured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SnifferPacketType {
+    Management,
+    Control,
+    Data,
+    /// E.g. MIMO frames - only the metadata, the payload is empty
+    Misc,
+}
+
+impl SnifferPacketType {
+    pub fn from_raw(packet_type: wifi_promiscuous_pkt_type_t) -> SnifferPacketType {
+        match packet_type {
+            wifi_promiscuous_pkt_type_t_WIFI_PKT_MGMT => SnifferPacketType::Management,
+            wifi_promiscuous_pkt_type_t_WIFI_PKT_CTRL => SnifferPacketType::Control,
+            wifi_promiscuous_pkt_type_t_WIFI_PKT_DATA => SnifferPacketType::Data,
+            _ => SnifferPacketType::Misc,
+        }
+    }
+}
+
+/// Which frames promiscuous mode captures - everything by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SnifferFilter {
+    pub management: bool,
+    pub control: bool,
+    pub data: bool,
+    pub misc: bool,
+}
+
+impl Default for SnifferFilter {
+    fn default() -> Self {
+        SnifferFilter {
+            management: true,
+            control: true,
+            data: true,
+            misc: true,
+        }
+    }
+}
+
+impl SnifferFilter {
+    pub fn accepts(&self, packet_type: SnifferPacketType) -> bool {
+        match packet_type {
+            SnifferPacketType::Management => self.management,
+            SnifferPacketType::Control => self.control,
+            SnifferPacketType::Data => self.data,
+            SnifferPacketType::Misc => self.misc,
+        }
+    }
+
+    pub fn to_raw(&self) -> wifi_promiscuous_filter_t {
+        let mut filter_mask = 0;
+        if self.management {
+            filter_mask |= WIFI_PROMIS_FILTER_MASK_MGMT;
+        }
+        if self.control {
+            filter_mask |= WIFI_PROMIS_FILTER_MASK_CTRL;
+        }
+        if self.data {
+            filter_mask |= WIFI_PROMIS_FILTER_MASK_DATA;
+        }
+        if self.misc {
+            filter_mask |= WIFI_PROMIS_FILTER_MASK_MISC;
+        }
+        wifi_promiscuous_filter_t { filter_mask }
+    }
+}
+
+/// A frame captured in promiscuous mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnifferFrame<'a> {
+    pub packet_type: SnifferPacketType,
+    /// In dBm
+    pub rssi: i8,
+    pub channel: u8,
+    /// Receive time in microseconds, from the MAC's timer
+    pub timestamp_us: u32,
+    /// The 802.11 frame without FCS
+    pub data: &'a [u8],
+}
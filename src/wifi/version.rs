@@ -0,0 +1,63 @@
+//! Versions of the linked driver blobs
+
+use crate::binary::{
+    c_types::c_char,
+    include::{get_phy_version_str, phy_get_rf_cal_version},
+};
+
+extern "C" {
+    // git revisions embedded in the blobs
+    static libnet80211_reversion_git: *const c_char;
+    static libpp_reversion_git: *const c_char;
+    static libcore_reversion_git: *const c_char;
+    static libcoexist_reversion_git: *const c_char;
+
+    // the parts of the driver in the chip's ROM
+    fn esp_net80211_rom_version_get() -> *const c_char;
+    fn esp_pp_rom_version_get() -> *const c_char;
+}
+
+/// Versions of the WiFi and PHY blobs the firmware is linked with and of the driver code in
+/// ROM
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BlobVersions {
+    pub net80211: &'static str,
+    pub pp: &'static str,
+    pub core: &'static str,
+    pub coexist: &'static str,
+    pub net80211_rom: &'static str,
+    pub pp_rom: &'static str,
+    pub phy: &'static str,
+    /// Format version of the PHY calibration data
+    pub phy_rf_cal: u32,
+}
+
+pub fn blob_versions() -> BlobVersions {
+    unsafe {
+        BlobVersions {
+            net80211: c_str(libnet80211_reversion_git),
+            pp: c_str(libpp_reversion_git),
+            core: c_str(libcore_reversion_git),
+            coexist: c_str(libcoexist_reversion_git),
+            net80211_rom: c_str(esp_net80211_rom_version_get()),
+            pp_rom: c_str(esp_pp_rom_version_get()),
+            phy: c_str(get_phy_version_str()),
+            phy_rf_cal: phy_get_rf_cal_version(),
+        }
+    }
+}
+
+/// The strings are constants in the blobs so they live forever
+unsafe fn c_str(ptr: *const c_char) -> &'static str {
+    if ptr.is_null() {
+        return "";
+    }
+
+    let mut len = 0;
+    while *ptr.add(len) != 0 {
+        len += 1;
+    }
+
+    core::str::from_utf8(core::slice::from_raw_parts(ptr, len)).unwrap_or("")
+}
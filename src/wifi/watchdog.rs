@@ -0,0 +1,50 @@
+//! Watchdog cooperation
+//!
+//! The callback registered via [set_watchdog_callback] is called from the driver's timer
+//! task as long as the WiFi task keeps returning to waiting for new messages. Feed a
+//! watchdog from it to detect a wedged WiFi task. Before the WiFi task ran for the first
+//! time the callback is called unconditionally.
+
+static mut WATCHDOG_CALLBACK: Option<fn()> = None;
+
+static mut WIFI_TASK_STARTED: bool = false;
+static mut WIFI_TASK_CHECKED_IN: bool = false;
+
+/// Registers a callback to feed a watchdog
+///
+/// It is called very often so it should be cheap.
+pub fn set_watchdog_callback(callback: fn()) {
+    unsafe {
+        WATCHDOG_CALLBACK = Some(callback);
+    }
+}
+
+pub fn clear_watchdog_callback() {
+    unsafe {
+        WATCHDOG_CALLBACK = None;
+    }
+}
+
+/// Called by the WiFi task while waiting for messages
+pub(crate) fn wifi_task_check_in() {
+    unsafe {
+        WIFI_TASK_STARTED = true;
+        WIFI_TASK_CHECKED_IN = true;
+    }
+}
+
+/// Called from the timer task - feeds the watchdog if the WiFi task made progress
+pub(crate) fn feed_watchdog() {
+    let callback = critical_section::with(|_| unsafe {
+        if WIFI_TASK_STARTED && !WIFI_TASK_CHECKED_IN {
+            return None;
+        }
+
+        WIFI_TASK_CHECKED_IN = false;
+        WATCHDOG_CALLBACK
+    });
+
+    if let Some(callback) = callback {
+        callback();
+    }
+}
@@ -0,0 +1,303 @@
+//! A ready to use network interface for polling-loop users
+//!
+//! Bundles the [WifiDevice], a smoltcp interface, a socket set and a DHCP client. All the
+//! memory needed is held by [WifiInterfaceStorage].
+//!
+//! ```ignore
+//! let mut storage = WifiInterfaceStorage::new();
+//! let mut iface = create_network_interface(&mut storage);
+//! wifi_connect(SSID, PASSWORD)?;
+//! loop {
+//!     iface.poll();
+//! }
+//! ```
+//!
+//! To wait until an address got assigned use [WifiInterface::wait_for_dhcp]. With
+//! [WifiInterface::set_auto_power_save] the modem sleeps whenever the sockets are idle.
+//!
+//! The address is dropped when the station loses the connection and DHCP starts over
+//! after it reconnected, so a changed network never keeps a stale configuration.
+
+use smoltcp::{
+    dhcp::Dhcpv4Client,
+    iface::{EthernetInterface, EthernetInterfaceBuilder, Neighbor, NeighborCache, Route, Routes},
+    socket::{RawPacketMetadata, RawSocketBuffer, SocketSet, SocketSetItem},
+    time::Instant,
+    wire::{EthernetAddress, IpAddress, IpCidr, Ipv4Address, Ipv4Cidr},
+};
+
+#[cfg(not(feature = "mock"))]
+use smoltcp::time::Duration;
+
+use crate::wifi::{get_sta_mac, is_connected, set_power_saving, PowerSaveMode, WifiDevice};
+
+/// Number of sockets which can be added - one more is used by the DHCP client
+pub const SOCKET_COUNT: usize = 4;
+
+/// With automatic power saving the modem sleeps if no socket needs attention within this
+/// time
+pub const AUTO_POWER_SAVE_IDLE_MS: u64 = 100;
+
+/// Memory used by a [WifiInterface]
+pub struct WifiInterfaceStorage<'a> {
+    socket_set_entries: [Option<SocketSetItem<'a>>; SOCKET_COUNT + 1],
+    neighbor_cache: [Option<(IpAddress, Neighbor)>; 8],
+    routes: [Option<(IpCidr, Route)>; 1],
+    ip_addrs: [IpCidr; 1],
+    dhcp_rx_buffer: [u8; 900],
+    dhcp_rx_metadata: [RawPacketMetadata; 1],
+    dhcp_tx_buffer: [u8; 600],
+    dhcp_tx_metadata: [RawPacketMetadata; 1],
+}
+
+impl<'a> WifiInterfaceStorage<'a> {
+    pub fn new() -> WifiInterfaceStorage<'a> {
+        WifiInterfaceStorage {
+            socket_set_entries: Default::default(),
+            neighbor_cache: [None; 8],
+            routes: [None; 1],
+            ip_addrs: [IpCidr::new(Ipv4Address::UNSPECIFIED.into(), 0)],
+            dhcp_rx_buffer: [0u8; 900],
+            dhcp_rx_metadata: [RawPacketMetadata::EMPTY; 1],
+            dhcp_tx_buffer: [0u8; 600],
+            dhcp_tx_metadata: [RawPacketMetadata::EMPTY; 1],
+        }
+    }
+}
+
+impl<'a> Default for WifiInterfaceStorage<'a> {
+    fn default() -> Self {
+        WifiInterfaceStorage::new()
+    }
+}
+
+/// The configuration acquired via DHCP
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpConfig {
+    pub address: Ipv4Cidr,
+    pub router: Option<Ipv4Address>,
+    pub dns_servers: [Option<Ipv4Address>; 3],
+}
+
+/// The network interface of the station, configured via DHCP
+pub struct WifiInterface<'a> {
+    interface: EthernetInterface<'a, WifiDevice>,
+    sockets: SocketSet<'a>,
+    dhcp: Dhcpv4Client,
+    address: Option<Ipv4Cidr>,
+    router: Option<Ipv4Address>,
+    dns_servers: [Option<Ipv4Address>; 3],
+    auto_power_save: bool,
+    link_up: bool,
+}
+
+/// Creates the interface - the WiFi driver is expected to be initialized and started
+pub fn create_network_interface<'a>(
+    storage: &'a mut WifiInterfaceStorage<'a>,
+) -> WifiInterface<'a> {
+    let mut sockets = SocketSet::new(&mut storage.socket_set_entries[..]);
+
+    let dhcp_rx_buffer = RawSocketBuffer::new(
+        &mut storage.dhcp_rx_metadata[..],
+        &mut storage.dhcp_rx_buffer[..],
+    );
+    let dhcp_tx_buffer = RawSocketBuffer::new(
+        &mut storage.dhcp_tx_metadata[..],
+        &mut storage.dhcp_tx_buffer[..],
+    );
+    let dhcp = Dhcpv4Client::new(
+        &mut sockets,
+        dhcp_rx_buffer,
+        dhcp_tx_buffer,
+        Instant::from_millis(0),
+    );
+
+    let mut mac = [0u8; 6];
+    get_sta_mac(&mut mac);
+
+    let interface = EthernetInterfaceBuilder::new(WifiDevice::new())
+        .ethernet_addr(EthernetAddress::from_bytes(&mac))
+        .neighbor_cache(NeighborCache::new(&mut storage.neighbor_cache[..]))
+        .ip_addrs(&mut storage.ip_addrs[..])
+        .routes(Routes::new(&mut storage.routes[..]))
+        .finalize();
+
+    WifiInterface {
+        interface,
+        sockets,
+        dhcp,
+        address: None,
+        router: None,
+        dns_servers: [None; 3],
+        auto_power_save: false,
+        link_up: false,
+    }
+}
+
+impl<'a> WifiInterface<'a> {
+    /// Processes received frames, sockets and DHCP - call it as often as possible
+    #[cfg(not(feature = "mock"))]
+    pub fn poll(&mut self) {
+        self.poll_at(timestamp());
+    }
+
+    pub fn poll_at(&mut self, timestamp: Instant) {
+        let link_up = is_connected();
+        if link_up != self.link_up {
+            self.link_up = link_up;
+            if link_up {
+                self.dhcp.reset(timestamp);
+            } else {
+                self.clear_ip_config();
+            }
+        }
+
+        self.poll_interface(timestamp);
+
+        if !self.link_up {
+            return;
+        }
+
+        // errors are transient (e.g. no frame could be sent) - the client retries by itself
+        let config = self
+            .dhcp
+            .poll(&mut self.interface, &mut self.sockets, timestamp)
+            .ok()
+            .flatten();
+
+        if let Some(config) = config {
+            if let Some(cidr) = config.address {
+                if Some(cidr) != self.address {
+                    self.interface.update_ip_addrs(|addrs| {
+                        if let Some(addr) = addrs.iter_mut().next() {
+                            *addr = IpCidr::Ipv4(cidr);
+                        }
+                    });
+                    self.address = Some(cidr);
+                }
+            }
+
+            if let Some(router) = config.router {
+                self.interface
+                    .routes_mut()
+                    .add_default_ipv4_route(router)
+                    .ok();
+                self.router = Some(router);
+            }
+
+            if config.dns_servers.iter().any(|s| s.is_some()) {
+                self.dns_servers = config.dns_servers;
+            }
+        }
+
+        if self.auto_power_save {
+            let idle = self
+                .interface
+                .poll_delay(&self.sockets, timestamp)
+                .map(|delay| delay.total_millis() >= AUTO_POWER_SAVE_IDLE_MS)
+                .unwrap_or(true);
+
+            let mode = if idle {
+                PowerSaveMode::MinModem
+            } else {
+                PowerSaveMode::None
+            };
+            set_power_saving(mode).ok();
+        }
+    }
+
+    fn clear_ip_config(&mut self) {
+        self.interface.update_ip_addrs(|addrs| {
+            if let Some(addr) = addrs.iter_mut().next() {
+                *addr = IpCidr::new(Ipv4Address::UNSPECIFIED.into(), 0);
+            }
+        });
+        self.interface.routes_mut().update(|routes| routes.clear());
+
+        self.address = None;
+        self.router = None;
+        self.dns_servers = [None; 3];
+    }
+
+    /// Whether the station is connected - the address is dropped while it isn't
+    pub fn link_up(&self) -> bool {
+        self.link_up
+    }
+
+    /// Switches between `PowerSaveMode::MinModem` while the sockets are idle and
+    /// `PowerSaveMode::None` while they have work pending on every poll
+    ///
+    /// Disabling it leaves the current mode as is.
+    pub fn set_auto_power_save(&mut self, enabled: bool) {
+        self.auto_power_save = enabled;
+    }
+
+    #[cfg(not(feature = "mock"))]
+    fn poll_interface(&mut self, timestamp: Instant) {
+        let interface = &mut self.interface;
+        let sockets = &mut self.sockets;
+        critical_section::with(|_| {
+            interface.poll(sockets, timestamp).ok();
+        });
+    }
+
+    #[cfg(feature = "mock")]
+    fn poll_interface(&mut self, timestamp: Instant) {
+        self.interface.poll(&mut self.sockets, timestamp).ok();
+    }
+
+    /// Polls until DHCP assigned an address - `None` if that didn't happen within `timeout`
+    #[cfg(not(feature = "mock"))]
+    pub fn wait_for_dhcp(&mut self, timeout: Duration) -> Option<IpConfig> {
+        let start = timestamp();
+
+        loop {
+            self.poll();
+
+            if let Some(config) = self.ip_config() {
+                return Some(config);
+            }
+
+            if timestamp() - start > timeout {
+                return None;
+            }
+        }
+    }
+
+    /// The current configuration - `None` until DHCP assigned an address
+    pub fn ip_config(&self) -> Option<IpConfig> {
+        Some(IpConfig {
+            address: self.address?,
+            router: self.router,
+            dns_servers: self.dns_servers,
+        })
+    }
+
+    /// The sockets - add own sockets here (up to [SOCKET_COUNT])
+    pub fn sockets(&mut self) -> &mut SocketSet<'a> {
+        &mut self.sockets
+    }
+
+    pub fn interface(&mut self) -> &mut EthernetInterface<'a, WifiDevice> {
+        &mut self.interface
+    }
+
+    /// The address assigned via DHCP
+    pub fn ipv4_address(&self) -> Option<Ipv4Cidr> {
+        self.address
+    }
+
+    pub fn router(&self) -> Option<Ipv4Address> {
+        self.router
+    }
+
+    pub fn dns_servers(&self) -> impl Iterator<Item = Ipv4Address> + '_ {
+        self.dns_servers.iter().filter_map(|s| *s)
+    }
+}
+
+/// The current time as needed by smoltcp
+#[cfg(not(feature = "mock"))]
+pub fn timestamp() -> Instant {
+    Instant::from_millis((crate::timer::get_systimer_count() / 16_000) as i64)
+}